@@ -0,0 +1,156 @@
+use digest::{Input, BlockInput, FixedOutput, Reset};
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::{U28, U32, U64};
+use block_buffer::BlockBuffer;
+use block_buffer::block_padding::ZeroPadding;
+use block_buffer::byteorder::{BE, ByteOrder};
+
+use consts::{STATE_LEN, SIGMA, C32, H224, H256};
+
+type BlockSize = U64;
+type Block = GenericArray<u8, BlockSize>;
+
+const ROUNDS: usize = 14;
+
+/// Compress `block` into `h`, `t` is the number of message bits up to and
+/// including this block or zero for a block holding padding only
+fn compress256(h: &mut [u32; STATE_LEN], block: &Block, t: u64) {
+    let mut m = [0u32; 16];
+    BE::read_u32_into(block, &mut m);
+
+    let mut v = [0u32; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&C32[..8]);
+    v[12] ^= t as u32;
+    v[13] ^= t as u32;
+    v[14] ^= (t >> 32) as u32;
+    v[15] ^= (t >> 32) as u32;
+
+    macro_rules! g {
+        ($s:expr, $i:expr, $a:expr, $b:expr, $c:expr, $d:expr) => {
+            let (x, y) = ($s[2 * $i], $s[2 * $i + 1]);
+            v[$a] = v[$a].wrapping_add(v[$b]).wrapping_add(m[x] ^ C32[y]);
+            v[$d] = (v[$d] ^ v[$a]).rotate_right(16);
+            v[$c] = v[$c].wrapping_add(v[$d]);
+            v[$b] = (v[$b] ^ v[$c]).rotate_right(12);
+            v[$a] = v[$a].wrapping_add(v[$b]).wrapping_add(m[y] ^ C32[x]);
+            v[$d] = (v[$d] ^ v[$a]).rotate_right(8);
+            v[$c] = v[$c].wrapping_add(v[$d]);
+            v[$b] = (v[$b] ^ v[$c]).rotate_right(7);
+        };
+    }
+
+    for r in 0..ROUNDS {
+        let s = &SIGMA[r % 10];
+        g!(s, 0, 0, 4, 8, 12);
+        g!(s, 1, 1, 5, 9, 13);
+        g!(s, 2, 2, 6, 10, 14);
+        g!(s, 3, 3, 7, 11, 15);
+        g!(s, 4, 0, 5, 10, 15);
+        g!(s, 5, 1, 6, 11, 12);
+        g!(s, 6, 2, 7, 8, 13);
+        g!(s, 7, 3, 4, 9, 14);
+    }
+
+    for i in 0..STATE_LEN {
+        h[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
+#[derive(Clone)]
+struct Engine256 {
+    // number of compressed message blocks
+    blocks: u64,
+    buffer: BlockBuffer<BlockSize>,
+    h: [u32; STATE_LEN],
+}
+
+impl Engine256 {
+    fn new(h: &[u32; STATE_LEN]) -> Engine256 {
+        Engine256 { blocks: 0, buffer: Default::default(), h: *h }
+    }
+
+    fn input(&mut self, input: &[u8]) {
+        let (h, blocks) = (&mut self.h, &mut self.blocks);
+        self.buffer.input(input, |b| {
+            *blocks += 1;
+            compress256(h, b, *blocks << 9);
+        });
+    }
+
+    /// Pad the message, `last` is the bit set before the length: one for
+    /// BLAKE-256 and zero for BLAKE-224
+    fn finish(&mut self, last: u8) {
+        let pos = self.buffer.position();
+        let len = (self.blocks << 9) + ((pos as u64) << 3);
+        let block = self.buffer.pad_with::<ZeroPadding>().unwrap();
+        block[pos] = 0x80;
+        // the counter is zero for a block without message bits
+        let t = if pos == 0 { 0 } else { len };
+        if pos > 55 {
+            compress256(&mut self.h, block, t);
+            let block = &mut GenericArray::default();
+            block[55] = last;
+            BE::write_u64(&mut block[56..], len);
+            compress256(&mut self.h, block, 0);
+        } else {
+            block[55] |= last;
+            BE::write_u64(&mut block[56..], len);
+            compress256(&mut self.h, block, t);
+        }
+    }
+
+    fn reset(&mut self, h: &[u32; STATE_LEN]) {
+        self.blocks = 0;
+        self.buffer.reset();
+        self.h = *h;
+    }
+}
+
+macro_rules! impl_blake256 {
+    ($name:ident, $output:ty, $iv:ident, $last:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            engine: Engine256,
+        }
+
+        impl Default for $name {
+            fn default() -> Self { $name { engine: Engine256::new(&$iv) } }
+        }
+
+        impl BlockInput for $name {
+            type BlockSize = BlockSize;
+        }
+
+        impl Input for $name {
+            fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+                self.engine.input(input.as_ref());
+            }
+        }
+
+        impl FixedOutput for $name {
+            type OutputSize = $output;
+
+            fn fixed_result(mut self) -> GenericArray<u8, Self::OutputSize> {
+                self.engine.finish($last);
+                let mut out = GenericArray::default();
+                let n = out.len() / 4;
+                BE::write_u32_into(&self.engine.h[..n], out.as_mut_slice());
+                out
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                self.engine.reset(&$iv);
+            }
+        }
+
+        impl_opaque_debug!($name);
+        impl_write!($name);
+    };
+}
+
+impl_blake256!(Blake224, U28, H224, 0x00, "The BLAKE-224 hash function");
+impl_blake256!(Blake256, U32, H256, 0x01, "The BLAKE-256 hash function");
@@ -0,0 +1,158 @@
+use digest::{Input, BlockInput, FixedOutput, Reset};
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::{U48, U64, U128};
+use block_buffer::BlockBuffer;
+use block_buffer::block_padding::ZeroPadding;
+use block_buffer::byteorder::{BE, ByteOrder};
+
+use consts::{STATE_LEN, SIGMA, C64, H384, H512};
+
+type BlockSize = U128;
+type Block = GenericArray<u8, BlockSize>;
+
+const ROUNDS: usize = 16;
+
+/// Compress `block` into `h`, `t` is the number of message bits up to and
+/// including this block or zero for a block holding padding only
+fn compress512(h: &mut [u64; STATE_LEN], block: &Block, t: (u64, u64)) {
+    let mut m = [0u64; 16];
+    BE::read_u64_into(block, &mut m);
+
+    let mut v = [0u64; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&C64[..8]);
+    v[12] ^= t.0;
+    v[13] ^= t.0;
+    v[14] ^= t.1;
+    v[15] ^= t.1;
+
+    macro_rules! g {
+        ($s:expr, $i:expr, $a:expr, $b:expr, $c:expr, $d:expr) => {
+            let (x, y) = ($s[2 * $i], $s[2 * $i + 1]);
+            v[$a] = v[$a].wrapping_add(v[$b]).wrapping_add(m[x] ^ C64[y]);
+            v[$d] = (v[$d] ^ v[$a]).rotate_right(32);
+            v[$c] = v[$c].wrapping_add(v[$d]);
+            v[$b] = (v[$b] ^ v[$c]).rotate_right(25);
+            v[$a] = v[$a].wrapping_add(v[$b]).wrapping_add(m[y] ^ C64[x]);
+            v[$d] = (v[$d] ^ v[$a]).rotate_right(16);
+            v[$c] = v[$c].wrapping_add(v[$d]);
+            v[$b] = (v[$b] ^ v[$c]).rotate_right(11);
+        };
+    }
+
+    for r in 0..ROUNDS {
+        let s = &SIGMA[r % 10];
+        g!(s, 0, 0, 4, 8, 12);
+        g!(s, 1, 1, 5, 9, 13);
+        g!(s, 2, 2, 6, 10, 14);
+        g!(s, 3, 3, 7, 11, 15);
+        g!(s, 4, 0, 5, 10, 15);
+        g!(s, 5, 1, 6, 11, 12);
+        g!(s, 6, 2, 7, 8, 13);
+        g!(s, 7, 3, 4, 9, 14);
+    }
+
+    for i in 0..STATE_LEN {
+        h[i] ^= v[i] ^ v[i + 8];
+    }
+}
+
+#[derive(Clone)]
+struct Engine512 {
+    // number of compressed message blocks
+    blocks: u64,
+    buffer: BlockBuffer<BlockSize>,
+    h: [u64; STATE_LEN],
+}
+
+impl Engine512 {
+    fn new(h: &[u64; STATE_LEN]) -> Engine512 {
+        Engine512 { blocks: 0, buffer: Default::default(), h: *h }
+    }
+
+    fn input(&mut self, input: &[u8]) {
+        let (h, blocks) = (&mut self.h, &mut self.blocks);
+        self.buffer.input(input, |b| {
+            *blocks += 1;
+            compress512(h, b, (*blocks << 10, *blocks >> 54));
+        });
+    }
+
+    /// Pad the message, `last` is the bit set before the length: one for
+    /// BLAKE-512 and zero for BLAKE-384
+    fn finish(&mut self, last: u8) {
+        let pos = self.buffer.position();
+        let len = ((self.blocks << 10) + ((pos as u64) << 3), self.blocks >> 54);
+        let block = self.buffer.pad_with::<ZeroPadding>().unwrap();
+        block[pos] = 0x80;
+        // the counter is zero for a block without message bits
+        let t = if pos == 0 { (0, 0) } else { len };
+        if pos > 111 {
+            compress512(&mut self.h, block, t);
+            let block = &mut GenericArray::default();
+            block[111] = last;
+            BE::write_u64(&mut block[112..120], len.1);
+            BE::write_u64(&mut block[120..], len.0);
+            compress512(&mut self.h, block, (0, 0));
+        } else {
+            block[111] |= last;
+            BE::write_u64(&mut block[112..120], len.1);
+            BE::write_u64(&mut block[120..], len.0);
+            compress512(&mut self.h, block, t);
+        }
+    }
+
+    fn reset(&mut self, h: &[u64; STATE_LEN]) {
+        self.blocks = 0;
+        self.buffer.reset();
+        self.h = *h;
+    }
+}
+
+macro_rules! impl_blake512 {
+    ($name:ident, $output:ty, $iv:ident, $last:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            engine: Engine512,
+        }
+
+        impl Default for $name {
+            fn default() -> Self { $name { engine: Engine512::new(&$iv) } }
+        }
+
+        impl BlockInput for $name {
+            type BlockSize = BlockSize;
+        }
+
+        impl Input for $name {
+            fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+                self.engine.input(input.as_ref());
+            }
+        }
+
+        impl FixedOutput for $name {
+            type OutputSize = $output;
+
+            fn fixed_result(mut self) -> GenericArray<u8, Self::OutputSize> {
+                self.engine.finish($last);
+                let mut out = GenericArray::default();
+                let n = out.len() / 8;
+                BE::write_u64_into(&self.engine.h[..n], out.as_mut_slice());
+                out
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                self.engine.reset(&$iv);
+            }
+        }
+
+        impl_opaque_debug!($name);
+        impl_write!($name);
+    };
+}
+
+impl_blake512!(Blake384, U48, H384, 0x00, "The BLAKE-384 hash function");
+impl_blake512!(Blake512, U64, H512, 0x01, "The BLAKE-512 hash function");
@@ -0,0 +1,51 @@
+//! An implementation of the [BLAKE][1] cryptographic hash functions, the
+//! SHA-3 finalist preceding BLAKE2.
+//!
+//! This is the final round version of BLAKE with 14 rounds for BLAKE-224 and
+//! BLAKE-256 and 16 rounds for BLAKE-384 and BLAKE-512. Salts are not
+//! supported, i.e. the salt is always zero. For new applications use BLAKE2
+//! from the `blake2` crate instead.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate blake;
+//! # fn main() {
+//! use blake::{Blake256, Digest};
+//!
+//! // create a BLAKE-256 hasher instance
+//! let mut hasher = Blake256::new();
+//!
+//! // process input message
+//! hasher.input(b"abc");
+//!
+//! // acquire hash digest in the form of GenericArray,
+//! // which in this case is equivalent to [u8; 32]
+//! let result = hasher.result();
+//! assert_eq!(result[..], hex!("
+//!     1833a9fa7cf4086bd5fda73da32e5a1d75b4c3f89d5c436369f9d78bb2da5c28
+//! ")[..]);
+//! # }
+//! ```
+//!
+//! Also see [RustCrypto/hashes][2] readme.
+//!
+//! [1]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)
+//! [2]: https://github.com/RustCrypto/hashes
+#![no_std]
+#![doc(html_logo_url =
+    "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
+extern crate block_buffer;
+#[macro_use] extern crate opaque_debug;
+#[macro_use] pub extern crate digest;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod consts;
+mod blake256;
+mod blake512;
+
+pub use digest::Digest;
+pub use blake256::{Blake224, Blake256};
+pub use blake512::{Blake384, Blake512};
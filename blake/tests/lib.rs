@@ -0,0 +1,39 @@
+//! Test messages including the one and two block examples from the BLAKE
+//! specification, the other digests were computed with the `blake-hash`
+//! crate
+#![no_std]
+
+#[macro_use]
+extern crate digest;
+extern crate blake;
+
+use digest::dev::{digest_test, one_million_a};
+
+new_test!(blake224_main, "blake224", blake::Blake224, digest_test);
+new_test!(blake256_main, "blake256", blake::Blake256, digest_test);
+new_test!(blake384_main, "blake384", blake::Blake384, digest_test);
+new_test!(blake512_main, "blake512", blake::Blake512, digest_test);
+
+#[test]
+fn blake224_1million_a() {
+    let output = include_bytes!("data/blake224_one_million_a.bin");
+    one_million_a::<blake::Blake224>(&output[..]);
+}
+
+#[test]
+fn blake256_1million_a() {
+    let output = include_bytes!("data/blake256_one_million_a.bin");
+    one_million_a::<blake::Blake256>(&output[..]);
+}
+
+#[test]
+fn blake384_1million_a() {
+    let output = include_bytes!("data/blake384_one_million_a.bin");
+    one_million_a::<blake::Blake384>(&output[..]);
+}
+
+#[test]
+fn blake512_1million_a() {
+    let output = include_bytes!("data/blake512_one_million_a.bin");
+    one_million_a::<blake::Blake512>(&output[..]);
+}
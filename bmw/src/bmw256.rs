@@ -0,0 +1,11 @@
+use block_buffer::BlockBuffer;
+use block_buffer::byteorder::{ByteOrder, LE};
+use digest::{BlockInput, FixedOutput, Input, Reset};
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::{Unsigned, U64, U32};
+use consts::{SUB, W_TERMS, FOLD_LO, FOLD_HI, IV256, FINAL256};
+
+define_bmw!(Bmw256, u32, U64, U32,
+    [(1, 3, 4, 19), (1, 2, 8, 23), (2, 1, 12, 25), (2, 2, 15, 29)],
+    [3, 7, 13, 16, 19, 23, 27], 0x0555_5555, IV256, FINAL256, read_u32_into, write_u32_into,
+    "BMW-256 hasher state");
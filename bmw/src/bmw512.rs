@@ -0,0 +1,11 @@
+use block_buffer::BlockBuffer;
+use block_buffer::byteorder::{ByteOrder, LE};
+use digest::{BlockInput, FixedOutput, Input, Reset};
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::{Unsigned, U128, U64};
+use consts::{SUB, W_TERMS, FOLD_LO, FOLD_HI, IV512, FINAL512};
+
+define_bmw!(Bmw512, u64, U128, U64,
+    [(1, 3, 4, 37), (1, 2, 13, 43), (2, 1, 19, 53), (2, 2, 28, 59)],
+    [5, 11, 27, 32, 37, 43, 53], 0x0555_5555_5555_5555, IV512, FINAL512, read_u64_into, write_u64_into,
+    "BMW-512 hasher state");
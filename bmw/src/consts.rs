@@ -0,0 +1,78 @@
+//! Constants of Blue Midnight Wish
+
+/// Add the term in `W_TERMS`
+pub const ADD: bool = false;
+/// Subtract the term in `W_TERMS`
+pub const SUB: bool = true;
+
+/// The words `W[i]` are sums and differences of five of the words
+/// `M[j] ^ H[j]`, listed as `(sign, j)`
+pub const W_TERMS: [[(bool, usize); 5]; 16] = [
+    [(ADD, 5), (SUB, 7), (ADD, 10), (ADD, 13), (ADD, 14)],
+    [(ADD, 6), (SUB, 8), (ADD, 11), (ADD, 14), (SUB, 15)],
+    [(ADD, 0), (ADD, 7), (ADD, 9), (SUB, 12), (ADD, 15)],
+    [(ADD, 0), (SUB, 1), (ADD, 8), (SUB, 10), (ADD, 13)],
+    [(ADD, 1), (ADD, 2), (ADD, 9), (SUB, 11), (SUB, 14)],
+    [(ADD, 3), (SUB, 2), (ADD, 10), (SUB, 12), (ADD, 15)],
+    [(ADD, 4), (SUB, 0), (SUB, 3), (SUB, 11), (ADD, 13)],
+    [(ADD, 1), (SUB, 4), (SUB, 5), (SUB, 12), (SUB, 14)],
+    [(ADD, 2), (SUB, 5), (SUB, 6), (ADD, 13), (SUB, 15)],
+    [(ADD, 0), (SUB, 3), (ADD, 6), (SUB, 7), (ADD, 14)],
+    [(ADD, 8), (SUB, 1), (SUB, 4), (SUB, 7), (ADD, 15)],
+    [(ADD, 8), (SUB, 0), (SUB, 2), (SUB, 5), (ADD, 9)],
+    [(ADD, 1), (ADD, 3), (SUB, 6), (SUB, 9), (ADD, 10)],
+    [(ADD, 2), (ADD, 4), (ADD, 7), (ADD, 10), (ADD, 11)],
+    [(ADD, 3), (SUB, 5), (ADD, 8), (SUB, 11), (SUB, 12)],
+    [(ADD, 12), (SUB, 4), (SUB, 6), (SUB, 9), (ADD, 13)],
+];
+
+/// Shifts of `XH` and of `Q[16 + i]` folded into `H[i]` for `i < 8`, positive
+/// values shift left
+pub const FOLD_LO: [(i32, i32); 8] = [
+    (5, -5), (-7, 8), (-5, 5), (-1, 5), (-3, 0), (6, -6), (-4, 6), (-11, 2),
+];
+
+/// Shifts of `XL` and index of the word of `Q` folded into `H[8 + i]`
+pub const FOLD_HI: [(i32, usize); 8] = [
+    (8, 23), (-6, 16), (6, 17), (4, 18), (-3, 19), (-4, 20), (-7, 21), (-2, 22),
+];
+
+/// Initial value of BMW-256
+pub const IV256: [u32; 16] = [
+    0x40414243, 0x44454647, 0x48494a4b, 0x4c4d4e4f,
+    0x50515253, 0x54555657, 0x58595a5b, 0x5c5d5e5f,
+    0x60616263, 0x64656667, 0x68696a6b, 0x6c6d6e6f,
+    0x70717273, 0x74757677, 0x78797a7b, 0x7c7d7e7f,
+];
+
+/// Chaining value of the final compression of BMW-256
+pub const FINAL256: [u32; 16] = [
+    0xaaaaaaa0, 0xaaaaaaa1, 0xaaaaaaa2, 0xaaaaaaa3,
+    0xaaaaaaa4, 0xaaaaaaa5, 0xaaaaaaa6, 0xaaaaaaa7,
+    0xaaaaaaa8, 0xaaaaaaa9, 0xaaaaaaaa, 0xaaaaaaab,
+    0xaaaaaaac, 0xaaaaaaad, 0xaaaaaaae, 0xaaaaaaaf,
+];
+
+/// Initial value of BMW-512
+pub const IV512: [u64; 16] = [
+    0x8081828384858687, 0x88898a8b8c8d8e8f,
+    0x9091929394959697, 0x98999a9b9c9d9e9f,
+    0xa0a1a2a3a4a5a6a7, 0xa8a9aaabacadaeaf,
+    0xb0b1b2b3b4b5b6b7, 0xb8b9babbbcbdbebf,
+    0xc0c1c2c3c4c5c6c7, 0xc8c9cacbcccdcecf,
+    0xd0d1d2d3d4d5d6d7, 0xd8d9dadbdcdddedf,
+    0xe0e1e2e3e4e5e6e7, 0xe8e9eaebecedeeef,
+    0xf0f1f2f3f4f5f6f7, 0xf8f9fafbfcfdfeff,
+];
+
+/// Chaining value of the final compression of BMW-512
+pub const FINAL512: [u64; 16] = [
+    0xaaaaaaaaaaaaaaa0, 0xaaaaaaaaaaaaaaa1,
+    0xaaaaaaaaaaaaaaa2, 0xaaaaaaaaaaaaaaa3,
+    0xaaaaaaaaaaaaaaa4, 0xaaaaaaaaaaaaaaa5,
+    0xaaaaaaaaaaaaaaa6, 0xaaaaaaaaaaaaaaa7,
+    0xaaaaaaaaaaaaaaa8, 0xaaaaaaaaaaaaaaa9,
+    0xaaaaaaaaaaaaaaaa, 0xaaaaaaaaaaaaaaab,
+    0xaaaaaaaaaaaaaaac, 0xaaaaaaaaaaaaaaad,
+    0xaaaaaaaaaaaaaaae, 0xaaaaaaaaaaaaaaaf,
+];
@@ -0,0 +1,55 @@
+//! An implementation of the [Blue Midnight Wish][1] cryptographic hash
+//! functions, a second round candidate of the SHA-3 competition.
+//!
+//! BMW-256 works on 32-bit words and 512-bit blocks, BMW-512 on 64-bit
+//! words and 1024-bit blocks. The chaining value is twice as large as the
+//! digest, which is the last half of the final chaining value.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate bmw;
+//! # fn main() {
+//! use bmw::{Bmw256, Digest};
+//!
+//! // create a BMW-256 hasher instance
+//! let mut hasher = Bmw256::new();
+//!
+//! // process input message
+//! hasher.input(b"abc");
+//!
+//! // acquire hash digest in the form of GenericArray,
+//! // which in this case is equivalent to [u8; 32]
+//! let result = hasher.result();
+//! assert_eq!(result[..], hex!("
+//!     57d11fc94bdf98e6a0d0bf1d4ddda3f4205e873666a644b5bb585e171ad87d34
+//! ")[..]);
+//! # }
+//! ```
+//!
+//! Also see [RustCrypto/hashes][2] readme.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Blue_Midnight_Wish
+//! [2]: https://github.com/RustCrypto/hashes
+#![no_std]
+#![doc(html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
+extern crate block_buffer;
+#[macro_use]
+extern crate opaque_debug;
+#[macro_use]
+pub extern crate digest;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use digest::Digest;
+
+mod consts;
+#[macro_use]
+mod macros;
+
+mod bmw256;
+mod bmw512;
+
+pub use bmw256::Bmw256;
+pub use bmw512::Bmw512;
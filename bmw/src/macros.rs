@@ -0,0 +1,152 @@
+macro_rules! define_bmw {
+    (
+        $name:ident, $word:ty, $block:ty, $output:ty,
+        $s:expr, $r:expr, $k:expr, $iv:expr, $fin:expr,
+        $read:ident, $write:ident, $doc:expr
+    ) => {
+        const S: [(u32, u32, u32, u32); 4] = $s;
+        const R: [u32; 7] = $r;
+
+        /// The bijective functions `s0` to `s5`
+        #[inline(always)]
+        fn s(i: usize, x: $word) -> $word {
+            if i < 4 {
+                let (a, b, c, d) = S[i];
+                (x >> a) ^ (x << b) ^ x.rotate_left(c) ^ x.rotate_left(d)
+            } else if i == 4 {
+                (x >> 1) ^ x
+            } else {
+                (x >> 2) ^ x
+            }
+        }
+
+        /// Shift left by `n` bits, right for negative `n`
+        #[inline(always)]
+        fn shift(x: $word, n: i32) -> $word {
+            if n >= 0 { x << n } else { x >> -n }
+        }
+
+        fn compress(h: &mut [$word; 16], m: &[$word; 16]) {
+            let mut t = [0; 16];
+            for j in 0..16 {
+                t[j] = m[j] ^ h[j];
+            }
+
+            // f0: the first 16 words of the quadruple pipe
+            let mut q = [0; 32];
+            for (i, terms) in W_TERMS.iter().enumerate() {
+                let w = terms.iter().fold(0 as $word, |w, &(sub, j)| match sub {
+                    SUB => w.wrapping_sub(t[j]),
+                    _ => w.wrapping_add(t[j]),
+                });
+                q[i] = s(i % 5, w).wrapping_add(h[(i + 1) % 16]);
+            }
+
+            // f1: the expansion to 32 words, two rounds of expand1 and
+            // fourteen rounds of expand2
+            for i in 16..32 {
+                let j = i - 16;
+                let rotm = |k: usize| m[k % 16].rotate_left((k % 16) as u32 + 1);
+                let add = rotm(j).wrapping_add(rotm(j + 3))
+                    .wrapping_sub(rotm(j + 10))
+                    .wrapping_add((i as $word).wrapping_mul($k))
+                    ^ h[(j + 7) % 16];
+                let mut x: $word = 0;
+                if i < 18 {
+                    for k in 0..16 {
+                        x = x.wrapping_add(s((k + 1) % 4, q[j + k]));
+                    }
+                } else {
+                    for k in 0..14 {
+                        let y = match k % 2 {
+                            0 => q[j + k],
+                            _ => q[j + k].rotate_left(R[k / 2]),
+                        };
+                        x = x.wrapping_add(y);
+                    }
+                    x = x.wrapping_add(s(4, q[i - 2])).wrapping_add(s(5, q[i - 1]));
+                }
+                q[i] = x.wrapping_add(add);
+            }
+
+            // f2: folding the pipe into the new chaining value
+            let xl = q[16..24].iter().fold(0, |x, &y| x ^ y);
+            let xh = q[24..32].iter().fold(xl, |x, &y| x ^ y);
+            for (i, &(a, b)) in FOLD_LO.iter().enumerate() {
+                h[i] = (shift(xh, a) ^ shift(q[16 + i], b) ^ m[i])
+                    .wrapping_add(xl ^ q[24 + i] ^ q[i]);
+            }
+            for (i, &(a, j)) in FOLD_HI.iter().enumerate() {
+                h[8 + i] = h[(i + 4) % 8].rotate_left(9 + i as u32)
+                    .wrapping_add(xh ^ q[24 + i] ^ m[8 + i])
+                    .wrapping_add(shift(xl, a) ^ q[j] ^ q[8 + i]);
+            }
+        }
+
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            h: [$word; 16],
+            len: u64,
+            buffer: BlockBuffer<$block>,
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name { h: $iv, len: 0, buffer: Default::default() }
+            }
+        }
+
+        impl BlockInput for $name {
+            type BlockSize = $block;
+        }
+
+        impl Input for $name {
+            fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+                let input = input.as_ref();
+                // Assumes that input.len() can be converted to u64 without overflow
+                self.len += input.len() as u64;
+                let h = &mut self.h;
+                self.buffer.input(input, |b| {
+                    let mut m = [0; 16];
+                    LE::$read(b, &mut m);
+                    compress(h, &m);
+                });
+            }
+        }
+
+        impl FixedOutput for $name {
+            type OutputSize = $output;
+
+            fn fixed_result(mut self) -> GenericArray<u8, $output> {
+                {
+                    let h = &mut self.h;
+                    let l = self.len << 3;
+                    self.buffer.len64_padding::<LE, _>(l, |b| {
+                        let mut m = [0; 16];
+                        LE::$read(b, &mut m);
+                        compress(h, &m);
+                    });
+                }
+                // the chaining value is compressed once more as message
+                let mut h = $fin;
+                compress(&mut h, &self.h);
+                // the 16 words of the chaining value fill exactly one block
+                let mut out = GenericArray::<u8, $block>::default();
+                LE::$write(&h, &mut out);
+                GenericArray::clone_from_slice(&out[out.len() - <$output>::to_usize()..])
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                self.buffer.reset();
+                self.len = 0;
+                self.h = $iv;
+            }
+        }
+
+        impl_opaque_debug!($name);
+        impl_write!($name);
+    };
+}
@@ -0,0 +1,25 @@
+//! Test messages around the block sizes, the BMW-512 digests agree with the
+//! `bmw-hash` crate and the BMW-256 digest of the empty string with the
+//! published one
+#![no_std]
+
+#[macro_use]
+extern crate digest;
+extern crate bmw;
+
+use digest::dev::{digest_test, one_million_a};
+
+new_test!(bmw256, "bmw256", bmw::Bmw256, digest_test);
+new_test!(bmw512, "bmw512", bmw::Bmw512, digest_test);
+
+#[test]
+fn bmw256_1million_a() {
+    let output = include_bytes!("data/bmw256_one_million_a.bin");
+    one_million_a::<bmw::Bmw256>(&output[..]);
+}
+
+#[test]
+fn bmw512_1million_a() {
+    let output = include_bytes!("data/bmw512_one_million_a.bin");
+    one_million_a::<bmw::Bmw512>(&output[..]);
+}
@@ -6,7 +6,8 @@ DIRS=`ls -d */`
 cargo clean
 
 for DIR in $DIRS; do
-    if [ $DIR = "target/" ]
+    # these crates require std
+    if [ $DIR = "target/" -o $DIR = "tigersum/" -o $DIR = "tthsum/" -o $DIR = "tiger-capi/" ]
     then
         continue
     fi
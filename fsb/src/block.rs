@@ -0,0 +1,82 @@
+//! The FSB compression function
+//!
+//! The parity check matrix of `n` columns and `r` rows is quasi-cyclic:
+//! every group of `r` consecutive columns consists of the rotations of one
+//! `p`-bit vector, truncated to `r` bits, where `p` is a prime slightly
+//! larger than `r`. The vectors of the reference implementation are stored
+//! one after another in `pi.bin`, each starting on a byte boundary.
+
+/// Largest `r / 8` of the defined variants
+const MAX_STATE_LEN: usize = 248;
+
+static PI: &[u8; 272_384] = include_bytes!("pi.bin");
+
+/// Parameters of an FSB instance
+pub struct Params {
+    /// Number of columns of the matrix
+    pub n: usize,
+    /// Number of columns added for every block
+    pub w: usize,
+    /// Size of the state in bits
+    pub r: usize,
+    /// Size of the vectors generating the matrix in bits
+    pub p: usize,
+}
+
+/// Byte `i` of the `p`-bit vector `v`, the bits from `p` on are zero
+#[inline(always)]
+fn byte(v: &[u8], p: usize, i: usize) -> u8 {
+    if 8 * i >= p {
+        0
+    } else if 8 * i + 8 > p {
+        v[i] & (0xff << (8 - p % 8))
+    } else {
+        v[i]
+    }
+}
+
+/// The 8 bits of the `p`-bit vector `v` starting at bit `off`
+#[inline(always)]
+fn window(v: &[u8], p: usize, off: usize) -> u8 {
+    let (i, s) = (off / 8, off % 8);
+    let x = (u16::from(byte(v, p, i)) << 8) | u16::from(byte(v, p, i + 1));
+    (x << s >> 8) as u8
+}
+
+/// Add column `index` of the matrix, the vector rotated right by
+/// `index % r` bits and truncated to `r` bits, to `acc`
+fn add_column(acc: &mut [u8], index: usize, params: &Params) {
+    let (r, p) = (params.r, params.p);
+    let len = p / 8 + 1;
+    let v = &PI[index / r * len..][..len];
+    let shift = index % r;
+    for (k, a) in acc.iter_mut().enumerate() {
+        let j = 8 * k;
+        *a ^= if j >= shift {
+            window(v, p, j - shift)
+        } else if j + 8 <= shift {
+            window(v, p, p - shift + j)
+        } else {
+            // the byte wraps around the end of the vector
+            window(v, p, p - shift + j) | (window(v, p, 0) >> (shift - j))
+        };
+    }
+}
+
+/// Process one block of `(s - r) / 8` bytes
+///
+/// Every byte of the state and the next `(s - r) / w` bits of the block
+/// select one column out of each of the `w` regions of `n / w` columns,
+/// the new state is the sum of the selected columns.
+pub fn compress(state: &mut [u8], block: &[u8], params: &Params) {
+    let bits = 8 * block.len() / params.w;
+    let mut acc = [0u8; MAX_STATE_LEN];
+    let acc = &mut acc[..state.len()];
+    for (i, &s) in state.iter().enumerate().take(params.w) {
+        let m = window(block, 8 * block.len(), i * bits) >> (8 - bits);
+        let index = i * (params.n / params.w) + usize::from(s)
+            + (usize::from(m) << (params.r / params.w));
+        add_column(acc, index, params);
+    }
+    state.copy_from_slice(acc);
+}
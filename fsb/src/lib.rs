@@ -0,0 +1,130 @@
+//! An implementation of the [FSB][1] (Fast Syndrome-Based) cryptographic
+//! hash function, as submitted to the SHA-3 competition.
+//!
+//! The compression function adds up columns of a large binary matrix
+//! selected by the state and the message, its security reduces to the
+//! hardness of decoding random linear codes. The final state is hashed
+//! with Whirlpool, the digest is the first part of that hash. Blocks are
+//! 60, 84, 96, 115 and 155 bytes long for `Fsb160`, `Fsb224`, `Fsb256`,
+//! `Fsb384` and `Fsb512` respectively.
+//!
+//! FSB is slow and mostly of interest for research on code-based hashing.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate fsb;
+//! # fn main() {
+//! use fsb::{Fsb256, Digest};
+//!
+//! // create a FSB-256 hasher instance
+//! let mut hasher = Fsb256::new();
+//!
+//! // process input message
+//! hasher.input(b"abc");
+//!
+//! // acquire hash digest in the form of GenericArray,
+//! // which in this case is equivalent to [u8; 32]
+//! let result = hasher.result();
+//! assert_eq!(result[..], hex!("
+//!     e13c678b7d557ae9b26b605c8f4e38ad582581629eb42198cbeb7e40046c7d69
+//! ")[..]);
+//! # }
+//! ```
+//!
+//! Also see [RustCrypto/hashes][2] readme.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Fast_syndrome-based_hash
+//! [2]: https://github.com/RustCrypto/hashes
+#![no_std]
+#![doc(html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
+extern crate block_buffer;
+#[macro_use]
+extern crate opaque_debug;
+#[macro_use]
+pub extern crate digest;
+extern crate whirlpool;
+#[cfg(feature = "std")]
+extern crate std;
+
+use block_buffer::byteorder::BE;
+use block_buffer::BlockBuffer;
+use digest::generic_array::typenum::{Unsigned, U115, U155, U20, U28, U32, U48, U60, U64, U84, U96};
+use digest::generic_array::GenericArray;
+pub use digest::Digest;
+use digest::{BlockInput, FixedOutput, Input, Reset};
+use whirlpool::Whirlpool;
+
+mod block;
+use block::{compress, Params};
+
+macro_rules! impl_fsb {
+    (
+        $name:ident, $block:ty, $output:ty,
+        $n:expr, $w:expr, $r:expr, $p:expr, $doc:expr
+    ) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            state: [u8; $r / 8],
+            len: u64,
+            buffer: BlockBuffer<$block>,
+        }
+
+        impl $name {
+            const PARAMS: Params = Params { n: $n, w: $w, r: $r, p: $p };
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name { state: [0; $r / 8], len: 0, buffer: Default::default() }
+            }
+        }
+
+        impl BlockInput for $name {
+            type BlockSize = $block;
+        }
+
+        impl Input for $name {
+            fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+                let input = input.as_ref();
+                // Assumes that input.len() can be converted to u64 without overflow
+                self.len += input.len() as u64;
+                let state = &mut self.state;
+                self.buffer.input(input, |b| compress(state, b, &Self::PARAMS));
+            }
+        }
+
+        impl FixedOutput for $name {
+            type OutputSize = $output;
+
+            fn fixed_result(mut self) -> GenericArray<u8, $output> {
+                {
+                    let state = &mut self.state;
+                    let l = self.len << 3;
+                    self.buffer.len64_padding::<BE, _>(l, |b| compress(state, b, &Self::PARAMS));
+                }
+                let res = Whirlpool::digest(&self.state);
+                GenericArray::clone_from_slice(&res[..<$output>::to_usize()])
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                self.buffer.reset();
+                self.len = 0;
+                self.state = [0; $r / 8];
+            }
+        }
+
+        impl_opaque_debug!($name);
+        impl_write!($name);
+    };
+}
+
+impl_fsb!(Fsb160, U60, U20, 5 << 18, 80, 640, 653, "FSB-160 hasher state");
+impl_fsb!(Fsb224, U84, U28, 7 << 18, 112, 896, 907, "FSB-224 hasher state");
+impl_fsb!(Fsb256, U96, U32, 1 << 21, 128, 1024, 1061, "FSB-256 hasher state");
+impl_fsb!(Fsb384, U115, U48, 23 << 16, 184, 1472, 1483, "FSB-384 hasher state");
+impl_fsb!(Fsb512, U155, U64, 31 << 16, 248, 1984, 1987, "FSB-512 hasher state");
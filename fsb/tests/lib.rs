@@ -0,0 +1,21 @@
+//! Test messages around the block sizes of all five variants, the digests
+//! agree with the `fsb` crate of RustCrypto
+#![no_std]
+
+#[macro_use]
+extern crate digest;
+extern crate fsb;
+
+use digest::dev::{digest_test, one_million_a};
+
+new_test!(fsb160, "fsb160", fsb::Fsb160, digest_test);
+new_test!(fsb224, "fsb224", fsb::Fsb224, digest_test);
+new_test!(fsb256, "fsb256", fsb::Fsb256, digest_test);
+new_test!(fsb384, "fsb384", fsb::Fsb384, digest_test);
+new_test!(fsb512, "fsb512", fsb::Fsb512, digest_test);
+
+#[test]
+fn fsb256_1million_a() {
+    let output = include_bytes!("data/fsb256_one_million_a.bin");
+    one_million_a::<fsb::Fsb256>(&output[..]);
+}
@@ -0,0 +1,137 @@
+//! The HAVAL compression function
+use block_buffer::byteorder::{ByteOrder, LE};
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::U128;
+use consts::{K, ORDER, PHI3, PHI4, PHI5};
+
+pub type Block = GenericArray<u8, U128>;
+
+// The boolean functions of the five passes, in the factored form of the
+// reference implementation
+
+#[inline(always)]
+fn f1(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x1 & (x0 ^ x4)) ^ (x2 & x5) ^ (x3 & x6) ^ x0
+}
+
+#[inline(always)]
+fn f2(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x2 & ((x1 & !x3) ^ (x4 & x5) ^ x6 ^ x0)) ^ (x4 & (x1 ^ x5)) ^ (x3 & x5) ^ x0
+}
+
+#[inline(always)]
+fn f3(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x3 & ((x1 & x2) ^ x6 ^ x0)) ^ (x1 & x4) ^ (x2 & x5) ^ x0
+}
+
+#[inline(always)]
+fn f4(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x4 & ((x5 & !x2) ^ (x3 & !x6) ^ x1 ^ x6 ^ x0))
+        ^ (x3 & ((x1 & x2) ^ x5 ^ x6)) ^ (x2 & x6) ^ x0
+}
+
+#[inline(always)]
+fn f5(x6: u32, x5: u32, x4: u32, x3: u32, x2: u32, x1: u32, x0: u32) -> u32 {
+    (x0 & ((x1 & x2 & x3) ^ !x5)) ^ (x1 & x4) ^ (x2 & x5) ^ (x3 & x6)
+}
+
+/// Process one 1024-bit block with `passes` passes (3, 4 or 5)
+pub fn compress(state: &mut [u32; 8], block: &Block, passes: usize) {
+    let mut m = [0u32; 32];
+    LE::read_u32_into(block, &mut m);
+
+    let mut t = *state;
+    for p in 0..passes {
+        let phi = match passes {
+            3 => &PHI3[p],
+            4 => &PHI4[p],
+            _ => &PHI5[p],
+        };
+        for i in 0..32 {
+            let (a6, a5, a4, a3, a2, a1, a0) = {
+                // the eight registers rotate by one position every step
+                let x = |k: usize| t[(k + 8 - i % 8) % 8];
+                (x(phi[0]), x(phi[1]), x(phi[2]), x(phi[3]), x(phi[4]),
+                    x(phi[5]), x(phi[6]))
+            };
+            let f = match p {
+                0 => f1(a6, a5, a4, a3, a2, a1, a0),
+                1 => f2(a6, a5, a4, a3, a2, a1, a0),
+                2 => f3(a6, a5, a4, a3, a2, a1, a0),
+                3 => f4(a6, a5, a4, a3, a2, a1, a0),
+                _ => f5(a6, a5, a4, a3, a2, a1, a0),
+            };
+            let (w, c) = match p {
+                0 => (m[i], 0),
+                _ => (m[ORDER[p - 1][i]], K[32 * (p - 1) + i]),
+            };
+            let d = (15 - i % 8) % 8;
+            t[d] = f.rotate_right(7)
+                .wrapping_add(t[d].rotate_right(11))
+                .wrapping_add(w)
+                .wrapping_add(c);
+        }
+    }
+    for (s, t) in state.iter_mut().zip(t.iter()) {
+        *s = s.wrapping_add(*t);
+    }
+}
+
+/// Fold the 256-bit fingerprint into `bits` bits, writing the result to the
+/// first `bits / 32` words of `state`
+pub fn tailor(state: &mut [u32; 8], bits: usize) {
+    let t = *state;
+    match bits {
+        128 => {
+            let a = (t[7] & 0x0000_00FF) | (t[6] & 0xFF00_0000)
+                | (t[5] & 0x00FF_0000) | (t[4] & 0x0000_FF00);
+            let b = (t[7] & 0x0000_FF00) | (t[6] & 0x0000_00FF)
+                | (t[5] & 0xFF00_0000) | (t[4] & 0x00FF_0000);
+            let c = (t[7] & 0x00FF_0000) | (t[6] & 0x0000_FF00)
+                | (t[5] & 0x0000_00FF) | (t[4] & 0xFF00_0000);
+            let d = (t[7] & 0xFF00_0000) | (t[6] & 0x00FF_0000)
+                | (t[5] & 0x0000_FF00) | (t[4] & 0x0000_00FF);
+            state[0] = t[0].wrapping_add(a.rotate_right(8));
+            state[1] = t[1].wrapping_add(b.rotate_right(16));
+            state[2] = t[2].wrapping_add(c.rotate_right(24));
+            state[3] = t[3].wrapping_add(d);
+        }
+        160 => {
+            let a = (t[7] & 0x3F) | (t[6] & (0x7F << 25)) | (t[5] & (0x3F << 19));
+            let b = (t[7] & (0x3F << 6)) | (t[6] & 0x3F) | (t[5] & (0x7F << 25));
+            let c = (t[7] & (0x7F << 12)) | (t[6] & (0x3F << 6)) | (t[5] & 0x3F);
+            let d = (t[7] & (0x3F << 19)) | (t[6] & (0x7F << 12)) | (t[5] & (0x3F << 6));
+            let e = (t[7] & (0x7F << 25)) | (t[6] & (0x3F << 19)) | (t[5] & (0x7F << 12));
+            state[0] = t[0].wrapping_add(a.rotate_right(19));
+            state[1] = t[1].wrapping_add(b.rotate_right(25));
+            state[2] = t[2].wrapping_add(c);
+            state[3] = t[3].wrapping_add(d >> 6);
+            state[4] = t[4].wrapping_add(e >> 12);
+        }
+        192 => {
+            let a = (t[7] & 0x1F) | (t[6] & (0x3F << 26));
+            let b = (t[7] & (0x1F << 5)) | (t[6] & 0x1F);
+            let c = (t[7] & (0x3F << 10)) | (t[6] & (0x1F << 5));
+            let d = (t[7] & (0x1F << 16)) | (t[6] & (0x3F << 10));
+            let e = (t[7] & (0x1F << 21)) | (t[6] & (0x1F << 16));
+            let f = (t[7] & (0x3F << 26)) | (t[6] & (0x1F << 21));
+            state[0] = t[0].wrapping_add(a.rotate_right(26));
+            state[1] = t[1].wrapping_add(b);
+            state[2] = t[2].wrapping_add(c >> 5);
+            state[3] = t[3].wrapping_add(d >> 10);
+            state[4] = t[4].wrapping_add(e >> 16);
+            state[5] = t[5].wrapping_add(f >> 21);
+        }
+        224 => {
+            let s = t[7];
+            state[0] = t[0].wrapping_add((s >> 27) & 0x1F);
+            state[1] = t[1].wrapping_add((s >> 22) & 0x1F);
+            state[2] = t[2].wrapping_add((s >> 18) & 0x0F);
+            state[3] = t[3].wrapping_add((s >> 13) & 0x1F);
+            state[4] = t[4].wrapping_add((s >> 9) & 0x0F);
+            state[5] = t[5].wrapping_add((s >> 4) & 0x1F);
+            state[6] = t[6].wrapping_add(s & 0x0F);
+        }
+        _ => (),
+    }
+}
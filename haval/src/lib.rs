@@ -0,0 +1,174 @@
+//! An implementation of the [HAVAL][1] cryptographic hash function.
+//!
+//! HAVAL processes 1024-bit blocks with 3, 4 or 5 passes and produces
+//! fingerprints of 128, 160, 192, 224 or 256 bits. Both parameters are
+//! type parameters of `Haval`, and the fifteen standard combinations are
+//! available as type aliases such as `Haval128_3` (128-bit output, 3
+//! passes) or `Haval256_5`. The parameters are part of the padding, so
+//! the variants are unrelated hash functions, i.e. a shorter output is not
+//! a truncation of a longer one.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate haval;
+//! # fn main() {
+//! use haval::{Haval128_3, Digest};
+//!
+//! // create a HAVAL-128/3 hasher instance
+//! let mut hasher = Haval128_3::new();
+//!
+//! // process input message
+//! hasher.input(b"abc");
+//!
+//! // acquire hash digest in the form of GenericArray,
+//! // which in this case is equivalent to [u8; 16]
+//! let result = hasher.result();
+//! assert_eq!(result[..], hex!("9e40ed883fb63e985d299b40cda2b8f2")[..]);
+//! # }
+//! ```
+//!
+//! Also see [RustCrypto/hashes][2] readme.
+//!
+//! [1]: https://en.wikipedia.org/wiki/HAVAL
+//! [2]: https://github.com/RustCrypto/hashes
+#![no_std]
+#![doc(html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
+extern crate block_buffer;
+#[macro_use]
+extern crate opaque_debug;
+#[macro_use]
+pub extern crate digest;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::marker::PhantomData;
+use block_buffer::byteorder::{ByteOrder, LE};
+use block_buffer::BlockBuffer;
+use digest::generic_array::typenum::{
+    Unsigned, U128, U16, U20, U24, U28, U3, U32, U4, U5,
+};
+use digest::generic_array::{ArrayLength, GenericArray};
+pub use digest::Digest;
+use digest::{BlockInput, FixedOutput, Input, Reset};
+
+mod block;
+mod consts;
+use block::{compress, tailor};
+use consts::H0;
+
+/// HAVAL version number encoded in the padding
+const VERSION: u8 = 1;
+
+/// Number of passes of HAVAL, implemented for `U3`, `U4` and `U5`
+pub trait Passes: Unsigned + Clone + Default {}
+
+impl Passes for U3 {}
+impl Passes for U4 {}
+impl Passes for U5 {}
+
+/// Output size of HAVAL in bytes, implemented for `U16`, `U20`, `U24`,
+/// `U28` and `U32`
+pub trait OutputSize: ArrayLength<u8> + Clone + Default {}
+
+impl OutputSize for U16 {}
+impl OutputSize for U20 {}
+impl OutputSize for U24 {}
+impl OutputSize for U28 {}
+impl OutputSize for U32 {}
+
+/// The HAVAL hasher with output size `N` bytes and `P` passes
+#[derive(Clone)]
+pub struct Haval<N: OutputSize, P: Passes> {
+    state: [u32; 8],
+    len: u64,
+    buffer: BlockBuffer<U128>,
+    params: PhantomData<(N, P)>,
+}
+
+impl<N: OutputSize, P: Passes> Default for Haval<N, P> {
+    fn default() -> Self {
+        Haval {
+            state: H0,
+            len: 0,
+            buffer: Default::default(),
+            params: PhantomData,
+        }
+    }
+}
+
+impl<N: OutputSize, P: Passes> BlockInput for Haval<N, P> {
+    type BlockSize = U128;
+}
+
+impl<N: OutputSize, P: Passes> Input for Haval<N, P> {
+    fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+        let input = input.as_ref();
+        // Assumes that input.len() can be converted to u64 without overflow
+        self.len += input.len() as u64;
+        let state = &mut self.state;
+        self.buffer.input(input, |b| compress(state, b, P::to_usize()));
+    }
+}
+
+impl<N: OutputSize, P: Passes> FixedOutput for Haval<N, P> {
+    type OutputSize = N;
+
+    fn fixed_result(mut self) -> GenericArray<u8, N> {
+        let bits = N::to_usize() * 8;
+        // a one bit, zeros up to 944 bits modulo 1024, the version, number
+        // of passes and output size in 16 bits and the message length in
+        // bits as 64-bit integer
+        let used = (self.len % 128) as usize;
+        let zeros = if used < 118 { 118 - used } else { 246 - used };
+        let mut tail = [0u8; 256];
+        tail[0] = 0x01;
+        tail[zeros] = ((bits as u8 & 0x03) << 6)
+            | ((P::to_u8() & 0x07) << 3) | VERSION;
+        tail[zeros + 1] = (bits >> 2) as u8;
+        LE::write_u64(&mut tail[zeros + 2..zeros + 10], self.len << 3);
+        Input::input(&mut self, &tail[..zeros + 10]);
+        debug_assert!(self.len % 128 == 0);
+
+        tailor(&mut self.state, bits);
+        let mut out = GenericArray::default();
+        LE::write_u32_into(&self.state[..bits / 32], &mut out);
+        out
+    }
+}
+
+impl<N: OutputSize, P: Passes> Reset for Haval<N, P> {
+    fn reset(&mut self) {
+        self.buffer.reset();
+        self.len = 0;
+        self.state = H0;
+    }
+}
+
+macro_rules! haval_alias {
+    ($name:ident, $size:ty, $passes:ty, $doc:expr) => {
+        #[doc = $doc]
+        #[allow(non_camel_case_types)]
+        pub type $name = Haval<$size, $passes>;
+
+        impl_opaque_debug!($name);
+        impl_write!($name);
+    };
+}
+
+haval_alias!(Haval128_3, U16, U3, "HAVAL with 128-bit output and 3 passes");
+haval_alias!(Haval128_4, U16, U4, "HAVAL with 128-bit output and 4 passes");
+haval_alias!(Haval128_5, U16, U5, "HAVAL with 128-bit output and 5 passes");
+haval_alias!(Haval160_3, U20, U3, "HAVAL with 160-bit output and 3 passes");
+haval_alias!(Haval160_4, U20, U4, "HAVAL with 160-bit output and 4 passes");
+haval_alias!(Haval160_5, U20, U5, "HAVAL with 160-bit output and 5 passes");
+haval_alias!(Haval192_3, U24, U3, "HAVAL with 192-bit output and 3 passes");
+haval_alias!(Haval192_4, U24, U4, "HAVAL with 192-bit output and 4 passes");
+haval_alias!(Haval192_5, U24, U5, "HAVAL with 192-bit output and 5 passes");
+haval_alias!(Haval224_3, U28, U3, "HAVAL with 224-bit output and 3 passes");
+haval_alias!(Haval224_4, U28, U4, "HAVAL with 224-bit output and 4 passes");
+haval_alias!(Haval224_5, U28, U5, "HAVAL with 224-bit output and 5 passes");
+haval_alias!(Haval256_3, U32, U3, "HAVAL with 256-bit output and 3 passes");
+haval_alias!(Haval256_4, U32, U4, "HAVAL with 256-bit output and 4 passes");
+haval_alias!(Haval256_5, U32, U5, "HAVAL with 256-bit output and 5 passes");
@@ -0,0 +1,31 @@
+//! Test messages for all fifteen variants, the digests of the empty string
+//! match the ones published with the reference implementation
+#![no_std]
+
+#[macro_use]
+extern crate digest;
+extern crate haval;
+
+use digest::dev::{digest_test, one_million_a};
+
+new_test!(haval128_3, "haval128_3", haval::Haval128_3, digest_test);
+new_test!(haval128_4, "haval128_4", haval::Haval128_4, digest_test);
+new_test!(haval128_5, "haval128_5", haval::Haval128_5, digest_test);
+new_test!(haval160_3, "haval160_3", haval::Haval160_3, digest_test);
+new_test!(haval160_4, "haval160_4", haval::Haval160_4, digest_test);
+new_test!(haval160_5, "haval160_5", haval::Haval160_5, digest_test);
+new_test!(haval192_3, "haval192_3", haval::Haval192_3, digest_test);
+new_test!(haval192_4, "haval192_4", haval::Haval192_4, digest_test);
+new_test!(haval192_5, "haval192_5", haval::Haval192_5, digest_test);
+new_test!(haval224_3, "haval224_3", haval::Haval224_3, digest_test);
+new_test!(haval224_4, "haval224_4", haval::Haval224_4, digest_test);
+new_test!(haval224_5, "haval224_5", haval::Haval224_5, digest_test);
+new_test!(haval256_3, "haval256_3", haval::Haval256_3, digest_test);
+new_test!(haval256_4, "haval256_4", haval::Haval256_4, digest_test);
+new_test!(haval256_5, "haval256_5", haval::Haval256_5, digest_test);
+
+#[test]
+fn haval256_5_1million_a() {
+    let output = include_bytes!("data/one_million_a.bin");
+    one_million_a::<haval::Haval256_5>(&output[..]);
+}
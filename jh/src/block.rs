@@ -0,0 +1,112 @@
+//! The compression function F8 of JH in the bitsliced form of the optimized
+//! reference implementation
+use block_buffer::byteorder::{ByteOrder, LE};
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::U64;
+use consts::ROUND_CONSTANTS;
+
+pub type Block = GenericArray<u8, U64>;
+
+/// The state: eight 128-bit words `x[i]`, each stored as two 64-bit halves
+/// at `2 * i` and `2 * i + 1`
+pub type State = [u64; 16];
+
+/// Two S-boxes computed in parallel on the even and the odd words, the bits
+/// of the round constant select S0 or S1
+macro_rules! ss {
+    ($m0:expr, $m1:expr, $m2:expr, $m3:expr,
+     $m4:expr, $m5:expr, $m6:expr, $m7:expr, $c0:expr, $c1:expr) => {
+        $m3 = !$m3;
+        $m7 = !$m7;
+        $m0 ^= !$m2 & $c0;
+        $m4 ^= !$m6 & $c1;
+        let t0 = $c0 ^ ($m0 & $m1);
+        let t1 = $c1 ^ ($m4 & $m5);
+        $m0 ^= $m2 & $m3;
+        $m4 ^= $m6 & $m7;
+        $m3 ^= !$m1 & $m2;
+        $m7 ^= !$m5 & $m6;
+        $m1 ^= $m0 & $m2;
+        $m5 ^= $m4 & $m6;
+        $m2 ^= $m0 & !$m3;
+        $m6 ^= $m4 & !$m7;
+        $m0 ^= $m1 | $m3;
+        $m4 ^= $m5 | $m7;
+        $m3 ^= $m1 & $m2;
+        $m7 ^= $m5 & $m6;
+        $m1 ^= t0 & $m0;
+        $m5 ^= t1 & $m4;
+        $m2 ^= t0;
+        $m6 ^= t1;
+    };
+}
+
+/// The linear transformation implementing the MDS code
+macro_rules! l {
+    ($m0:expr, $m1:expr, $m2:expr, $m3:expr,
+     $m4:expr, $m5:expr, $m6:expr, $m7:expr) => {
+        $m4 ^= $m1;
+        $m5 ^= $m2;
+        $m6 ^= $m0 ^ $m3;
+        $m7 ^= $m0;
+        $m0 ^= $m5;
+        $m1 ^= $m6;
+        $m2 ^= $m4 ^ $m7;
+        $m3 ^= $m4;
+    };
+}
+
+/// Swap adjacent groups of `2^n` bits, the permutation of the odd words
+#[inline(always)]
+fn swap(x: u64, round: usize) -> u64 {
+    const MASKS: [u64; 5] = [
+        0x5555_5555_5555_5555,
+        0x3333_3333_3333_3333,
+        0x0f0f_0f0f_0f0f_0f0f,
+        0x00ff_00ff_00ff_00ff,
+        0x0000_ffff_0000_ffff,
+    ];
+    match round {
+        5 => x.rotate_left(32),
+        _ => {
+            let s = 1 << round;
+            ((x & MASKS[round]) << s) | ((x >> s) & MASKS[round])
+        }
+    }
+}
+
+/// The bijective function E8, 42 rounds of S-boxes, linear transformation
+/// and permutation
+fn e8(x: &mut State) {
+    for (r, c) in ROUND_CONSTANTS.iter().enumerate() {
+        for i in 0..2 {
+            ss!(x[i], x[4 + i], x[8 + i], x[12 + i],
+                x[2 + i], x[6 + i], x[10 + i], x[14 + i], c[i], c[2 + i]);
+            l!(x[i], x[4 + i], x[8 + i], x[12 + i],
+               x[2 + i], x[6 + i], x[10 + i], x[14 + i]);
+        }
+        // the permutation of round 7k + 6 swaps the 64-bit halves
+        for j in 0..4 {
+            let o = 4 * j + 2;
+            if r % 7 == 6 {
+                x.swap(o, o + 1);
+            } else {
+                x[o] = swap(x[o], r % 7);
+                x[o + 1] = swap(x[o + 1], r % 7);
+            }
+        }
+    }
+}
+
+/// The compression function F8 processing one 512-bit block
+pub fn compress(x: &mut State, block: &Block) {
+    let mut m = [0u64; 8];
+    LE::read_u64_into(block, &mut m);
+    for (x, m) in x[..8].iter_mut().zip(m.iter()) {
+        *x ^= *m;
+    }
+    e8(x);
+    for (x, m) in x[8..].iter_mut().zip(m.iter()) {
+        *x ^= *m;
+    }
+}
@@ -0,0 +1,155 @@
+//! An implementation of the [JH][1] cryptographic hash functions, the SHA-3
+//! finalist.
+//!
+//! JH-224, JH-256, JH-384 and JH-512 of the final round submission with 42
+//! rounds are provided. All four share the same compression function and
+//! differ in the initial hash value and the length of the truncated output.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate jh;
+//! # fn main() {
+//! use jh::{Jh256, Digest};
+//!
+//! // create a JH-256 hasher instance
+//! let mut hasher = Jh256::new();
+//!
+//! // process input message
+//! hasher.input(b"abc");
+//!
+//! // acquire hash digest in the form of GenericArray,
+//! // which in this case is equivalent to [u8; 32]
+//! let result = hasher.result();
+//! assert_eq!(result[..], hex!("
+//!     924bc82f24a76d519d4f69493da7fa70dc88bdb6016b6d1cc1dcf7def15e9cdd
+//! ")[..]);
+//! # }
+//! ```
+//!
+//! Also see [RustCrypto/hashes][2] readme.
+//!
+//! [1]: https://en.wikipedia.org/wiki/JH_(hash_function)
+//! [2]: https://github.com/RustCrypto/hashes
+#![no_std]
+#![doc(html_logo_url =
+    "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
+extern crate block_buffer;
+#[macro_use] extern crate opaque_debug;
+#[macro_use] pub extern crate digest;
+#[cfg(feature = "std")]
+extern crate std;
+
+use block_buffer::BlockBuffer;
+use block_buffer::block_padding::ZeroPadding;
+use block_buffer::byteorder::{BE, LE, ByteOrder};
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::{U28, U32, U48, U64};
+pub use digest::Digest;
+use digest::{BlockInput, FixedOutput, Input, Reset};
+
+mod block;
+mod consts;
+use block::{compress, Block, State};
+use consts::{JH224_H0, JH256_H0, JH384_H0, JH512_H0};
+
+#[derive(Clone)]
+struct Engine {
+    x: State,
+    len: u64,
+    buffer: BlockBuffer<U64>,
+}
+
+impl Engine {
+    fn new(h0: &State) -> Self {
+        Engine { x: *h0, len: 0, buffer: Default::default() }
+    }
+
+    fn input(&mut self, input: &[u8]) {
+        // Assumes that input.len() can be converted to u64 without overflow
+        self.len += input.len() as u64;
+        let x = &mut self.x;
+        self.buffer.input(input, |b| compress(x, b));
+    }
+
+    /// Pad the message with a one bit, at least 383 zero bits and the
+    /// 128-bit message length, i.e. with one block if the message fills
+    /// complete blocks and with the rest of the last block and another one
+    /// otherwise, and return the state as bytes
+    fn finish(&mut self) -> [u8; 128] {
+        let pos = self.buffer.position();
+        let mut last = Block::default();
+        BE::write_u64(&mut last[56..], self.len << 3);
+        BE::write_u64(&mut last[48..56], self.len >> 61);
+        if pos == 0 {
+            last[0] = 0x80;
+        } else {
+            let block = self.buffer.pad_with::<ZeroPadding>().unwrap();
+            block[pos] = 0x80;
+            compress(&mut self.x, block);
+        }
+        compress(&mut self.x, &last);
+
+        let mut out = [0u8; 128];
+        LE::write_u64_into(&self.x, &mut out);
+        out
+    }
+
+    fn reset(&mut self, h0: &State) {
+        self.x = *h0;
+        self.len = 0;
+        self.buffer.reset();
+    }
+}
+
+macro_rules! impl_jh {
+    ($name:ident, $output:ty, $h0:ident, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            engine: Engine,
+        }
+
+        impl Default for $name {
+            fn default() -> Self { $name { engine: Engine::new(&$h0) } }
+        }
+
+        impl BlockInput for $name {
+            type BlockSize = U64;
+        }
+
+        impl Input for $name {
+            fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+                self.engine.input(input.as_ref());
+            }
+        }
+
+        impl FixedOutput for $name {
+            type OutputSize = $output;
+
+            fn fixed_result(mut self) -> GenericArray<u8, $output> {
+                // the digest is the end of the final state
+                let state = self.engine.finish();
+                let mut out = GenericArray::default();
+                let n = out.len();
+                out.copy_from_slice(&state[128 - n..]);
+                out
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                self.engine.reset(&$h0);
+            }
+        }
+
+        impl_opaque_debug!($name);
+        impl_write!($name);
+    };
+}
+
+impl_jh!(Jh224, U28, JH224_H0, "The JH-224 hash function");
+impl_jh!(Jh256, U32, JH256_H0, "The JH-256 hash function");
+impl_jh!(Jh384, U48, JH384_H0, "The JH-384 hash function");
+impl_jh!(Jh512, U64, JH512_H0, "The JH-512 hash function");
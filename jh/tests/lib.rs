@@ -0,0 +1,38 @@
+//! Test messages for JH with 42 rounds, the digests were computed with the
+//! `jh` crate of RustCrypto
+#![no_std]
+
+#[macro_use]
+extern crate digest;
+extern crate jh;
+
+use digest::dev::{digest_test, one_million_a};
+
+new_test!(jh224_main, "jh224", jh::Jh224, digest_test);
+new_test!(jh256_main, "jh256", jh::Jh256, digest_test);
+new_test!(jh384_main, "jh384", jh::Jh384, digest_test);
+new_test!(jh512_main, "jh512", jh::Jh512, digest_test);
+
+#[test]
+fn jh224_1million_a() {
+    let output = include_bytes!("data/jh224_one_million_a.bin");
+    one_million_a::<jh::Jh224>(&output[..]);
+}
+
+#[test]
+fn jh256_1million_a() {
+    let output = include_bytes!("data/jh256_one_million_a.bin");
+    one_million_a::<jh::Jh256>(&output[..]);
+}
+
+#[test]
+fn jh384_1million_a() {
+    let output = include_bytes!("data/jh384_one_million_a.bin");
+    one_million_a::<jh::Jh384>(&output[..]);
+}
+
+#[test]
+fn jh512_1million_a() {
+    let output = include_bytes!("data/jh512_one_million_a.bin");
+    one_million_a::<jh::Jh512>(&output[..]);
+}
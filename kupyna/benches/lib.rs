@@ -0,0 +1,7 @@
+#![no_std]
+#![feature(test)]
+#[macro_use]
+extern crate digest;
+extern crate kupyna;
+
+bench!(kupyna::Kupyna256);
@@ -0,0 +1,65 @@
+//! The permutations T⊕ and T+ and the compression function
+//!
+//! The state is a matrix of eight rows and 8 or 16 columns, stored as one
+//! big-endian `u64` per column.
+use block_buffer::byteorder::{ByteOrder, BE};
+use table::TABLE;
+
+const MAX_COLS: usize = 16;
+
+/// SubBytes, ShiftRows and MixColumns
+#[inline(always)]
+fn round(x: &mut [u64]) {
+    let cols = x.len();
+    let mut prev = [0u64; MAX_COLS];
+    prev[..cols].copy_from_slice(x);
+    for (col, out) in x.iter_mut().enumerate() {
+        let mut t = 0;
+        for (row, table) in TABLE.iter().enumerate() {
+            // in the 1024-bit state the last row is shifted by 11 columns
+            let shift = if row == 7 && cols == MAX_COLS { 11 } else { row };
+            let b = prev[(col + cols - shift) % cols] >> (56 - 8 * row);
+            t ^= table[b as usize & 0xff];
+        }
+        *out = t;
+    }
+}
+
+/// The permutation T⊕ with `rounds` rounds
+pub fn t_xor(x: &mut [u64], rounds: usize) {
+    for r in 0..rounds {
+        for (i, w) in x.iter_mut().enumerate() {
+            *w ^= (((i << 4) ^ r) as u64) << 56;
+        }
+        round(x);
+    }
+}
+
+/// The permutation T+ with `rounds` rounds
+fn t_plus(x: &mut [u64], rounds: usize) {
+    let cols = x.len();
+    for r in 0..rounds {
+        // the constant is added to the columns read as little-endian words
+        for (i, w) in x.iter_mut().enumerate() {
+            let c = 0x00F0_F0F0_F0F0_F0F3 ^ (((((cols - i - 1) << 4) ^ r) as u64) << 56);
+            *w = w.swap_bytes().wrapping_add(c).swap_bytes();
+        }
+        round(x);
+    }
+}
+
+/// Compress `block` into the chaining value `h`: `T⊕(h ^ m) ^ T+(m) ^ h`
+pub fn compress(h: &mut [u64], block: &[u8], rounds: usize) {
+    let cols = h.len();
+    let mut m = [0u64; MAX_COLS];
+    let mut x = [0u64; MAX_COLS];
+    BE::read_u64_into(block, &mut m[..cols]);
+    for i in 0..cols {
+        x[i] = h[i] ^ m[i];
+    }
+    t_xor(&mut x[..cols], rounds);
+    t_plus(&mut m[..cols], rounds);
+    for i in 0..cols {
+        h[i] ^= x[i] ^ m[i];
+    }
+}
@@ -0,0 +1,135 @@
+//! An implementation of the [Kupyna][1] cryptographic hash function, the
+//! Ukrainian national standard DSTU 7564:2014.
+//!
+//! Kupyna-256 works on a 512-bit state with 10 rounds, Kupyna-512 on a
+//! 1024-bit state with 14 rounds. The digest is the last part of the state
+//! after the output transformation.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate kupyna;
+//! # fn main() {
+//! use kupyna::{Kupyna256, Digest};
+//!
+//! // create a Kupyna-256 hasher instance
+//! let mut hasher = Kupyna256::new();
+//!
+//! // process input message
+//! hasher.input(b"abc");
+//!
+//! // acquire hash digest in the form of GenericArray,
+//! // which in this case is equivalent to [u8; 32]
+//! let result = hasher.result();
+//! assert_eq!(result[..], hex!("
+//!     0bd1b36109f1318411a0517315aa46b8839df06622a278676f5487996c9cfc04
+//! ")[..]);
+//! # }
+//! ```
+//!
+//! Also see [RustCrypto/hashes][2] readme.
+//!
+//! [1]: https://eprint.iacr.org/2015/885.pdf
+//! [2]: https://github.com/RustCrypto/hashes
+#![no_std]
+#![doc(html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
+extern crate block_buffer;
+#[macro_use]
+extern crate opaque_debug;
+#[macro_use]
+pub extern crate digest;
+#[cfg(feature = "std")]
+extern crate std;
+
+use block_buffer::byteorder::{ByteOrder, BE, LE};
+use block_buffer::BlockBuffer;
+use digest::generic_array::typenum::{Unsigned, U128, U32, U64};
+use digest::generic_array::GenericArray;
+pub use digest::Digest;
+use digest::{BlockInput, FixedOutput, Input, Reset};
+
+mod block;
+mod table;
+use block::{compress, t_xor};
+
+macro_rules! impl_kupyna {
+    ($name:ident, $output:ty, $block:ty, $cols:expr, $rounds:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            h: [u64; $cols],
+            len: u64,
+            buffer: BlockBuffer<$block>,
+        }
+
+        impl $name {
+            fn iv() -> [u64; $cols] {
+                // the first byte of the IV is the block size in bytes
+                let mut h = [0; $cols];
+                h[0] = (8 * $cols as u64) << 56;
+                h
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name { h: Self::iv(), len: 0, buffer: Default::default() }
+            }
+        }
+
+        impl BlockInput for $name {
+            type BlockSize = $block;
+        }
+
+        impl Input for $name {
+            fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+                let input = input.as_ref();
+                // Assumes that input.len() can be converted to u64 without overflow
+                self.len += input.len() as u64;
+                let h = &mut self.h;
+                self.buffer.input(input, |b| compress(h, b, $rounds));
+            }
+        }
+
+        impl FixedOutput for $name {
+            type OutputSize = $output;
+
+            fn fixed_result(mut self) -> GenericArray<u8, $output> {
+                // a one bit, zeros and the message length in bits as 96-bit
+                // little-endian integer
+                let size = 8 * $cols;
+                let used = (self.len % size as u64) as usize;
+                let zeros = if used < size - 12 { size - 12 - used } else { 2 * size - 12 - used };
+                let mut tail = [0u8; 2 * 8 * $cols];
+                tail[0] = 0x80;
+                LE::write_u64(&mut tail[zeros..zeros + 8], self.len << 3);
+                LE::write_u32(&mut tail[zeros + 8..zeros + 12], (self.len >> 61) as u32);
+                Input::input(&mut self, &tail[..zeros + 12]);
+                debug_assert!(self.buffer.position() == 0);
+
+                let mut x = self.h;
+                t_xor(&mut x, $rounds);
+                let mut out = [0u8; 8 * $cols];
+                for (i, w) in x.iter().enumerate() {
+                    BE::write_u64(&mut out[8 * i..8 * i + 8], w ^ self.h[i]);
+                }
+                GenericArray::clone_from_slice(&out[size - <$output>::to_usize()..])
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                self.buffer.reset();
+                self.len = 0;
+                self.h = Self::iv();
+            }
+        }
+
+        impl_opaque_debug!($name);
+        impl_write!($name);
+    };
+}
+
+impl_kupyna!(Kupyna256, U32, U64, 8, 10, "Kupyna-256 hasher state");
+impl_kupyna!(Kupyna512, U64, U128, 16, 14, "Kupyna-512 hasher state");
@@ -0,0 +1,539 @@
+//! Precomputed lookup tables combining SubBytes and MixColumns
+//!
+//! `TABLE[r][b]` is the column contributed by byte `b` in row `r` of the
+//! input of MixColumns after substitution, so that a round reduces to eight
+//! lookups per column. Byte `7 - o` of `TABLE[r][b]` is the product of
+//! `S[r % 4][b]` and the coefficient of row `r` in row `o` of the
+//! circulant MDS matrix (first row `01 01 05 01 08 06 07 04`) in GF(2^8)
+//! modulo x^8 + x^4 + x^3 + x^2 + 1, with `S` the four S-boxes of
+//! DSTU 7564:2014 (shared with the Kalyna block cipher).
+pub static TABLE: [[u64; 256]; 8] = [
+    [
+        0xA89A7FD729A832A8, 0x4311D49722435243, 0x5F6180DFC25F3E5F, 0x0618121430061E06,
+        0x6BB10C677F6BDA6B, 0x75C956238F75BC75, 0x6CAD1975476CC16C, 0x597992CBF2592059,
+        0x71D94A3BAF71A871, 0xDF5B27F8B6DF84DF, 0x8726B2354C87A187, 0x956ECC59DC95FB95,
+        0x175C6572B8174B17, 0xF0E7EA1AD3F017F0, 0xD84732EA8ED89FD8, 0x09243F3648092D09,
+        0x6DA91E734F6DC46D, 0xF3EBE310CBF318F3, 0x1D74534EE81D691D, 0xCB0B4B8016CBC0CB,
+        0xC903458C06C9CAC9, 0x4D29FEB3524D644D, 0x2CB0C4E87D2C9C2C, 0xAF866AC511AF29AF,
+        0x79F9720BEF798079, 0xE0A79A7A53E047E0, 0x9766C255CC97F197, 0xFDD3C934BBFD2EFD,
+        0x6FA1107F5F6FCE6F, 0x4B31ECA7624B7A4B, 0x4509C68312454C45, 0x39E4AF96D539DD39,
+        0x3EF8BA84ED3EC63E, 0xDD5329F4A6DD8EDD, 0xA3B64EED71A315A3, 0x4F21F0BF424F6E4F,
+        0xB4EA2B9FC9B45EB4, 0xB6E22593D9B654B6, 0x9A52E17BA49AC89A, 0x0E382A24700E360E,
+        0x1F7C5D42F81F631F, 0xBFC61AA591BF79BF, 0x15546B7EA8154115, 0xE1A39D7C5BE142E1,
+        0x4939E2AB72497049, 0xD26F04D6DED2BDD2, 0x9376DE4DEC93E593, 0xC63F68AE7EC6F9C6,
+        0x9272D94BE492E092, 0x72D54331B772A772, 0x9E42FD63849EDC9E, 0x61993A5B2F61F861,
+        0xD1630DDCC6D1B2D1, 0x639134573F63F263, 0xFACFDC2683FA35FA, 0xEE9FB05E23EE71EE,
+        0xF4F7F602F3F403F4, 0x19644F56C8197D19, 0xD57311C4E6D5A6D5, 0xAD8E64C901AD23AD,
+        0x587D95CDFA582558, 0xA4AA5BFF49A40EA4, 0xBBD606BDB1BB6DBB, 0xA1BE40E161A11FA1,
+        0xDC572EF2AEDC8BDC, 0xF2EFE416C3F21DF2, 0x8336AE2D6C83B583, 0x37DC85B2A537EB37,
+        0x4215D3912A425742, 0xE4B7866273E453E4, 0x7AF57B01F77A8F7A, 0x32C89EAC8D32FA32,
+        0x9C4AF36F949CD69C, 0xCC175E922ECCDBCC, 0xAB9676DD31AB3DAB, 0x4A35EBA16A4A7F4A,
+        0x8F068A050C8F898F, 0x6EA51779576ECB6E, 0x04101C1820041404, 0x279CF5D22527BB27,
+        0x2EB8CAE46D2E962E, 0xE7BB8F686BE75CE7, 0xE2AF947643E24DE2, 0x5A759BC1EA5A2F5A,
+        0x9662C553C496F496, 0x16586274B0164E16, 0x238CE9CA0523AF23, 0x2BACD1FA452B872B,
+        0xC22F74B65EC2EDC2, 0x658926430F65EC65, 0x66852F491766E366, 0x0F3C2D22780F330F,
+        0xBCCA13AF89BC76BC, 0xA99E78D121A937A9, 0x4701C88F02474647, 0x4119DA9B32415841,
+        0x34D08CB8BD34E434, 0x483DE5AD7A487548, 0xFCD7CE32B3FC2BFC, 0xB7E62295D1B751B7,
+        0x6AB50B61776ADF6A, 0x881A9F1734889288, 0xA5AE5CF941A50BA5, 0x5351A4F7A2530253,
+        0x8622B5334486A486, 0xF9C3D52C9BF93AF9, 0x5B719CC7E25B2A5B, 0xDB4B3BE096DB90DB,
+        0x38E0A890DD38D838, 0x7BF17C07FF7B8A7B, 0xC32B73B056C3E8C3, 0x1E785A44F01E661E,
+        0x2288EECC0D22AA22, 0x33CC99AA8533FF33, 0x2490FCD83D24B424, 0x28A0D8F05D288828,
+        0x36D882B4AD36EE36, 0xC73B6FA876C7FCC7, 0xB2F2398BF9B240B2, 0x3BECA19AC53BD73B,
+        0x8E028D03048E8C8E, 0x77C1582F9F77B677, 0xBAD201BBB9BA68BA, 0xF5F3F104FBF506F5,
+        0x14506C78A0144414, 0x9F46FA658C9FD99F, 0x0820383040082808, 0x5549B6E392551C55,
+        0x9B56E67DAC9BCD9B, 0x4C2DF9B55A4C614C, 0xFEDFC03EA3FE21FE, 0x609D3D5D2760FD60,
+        0x5C6D89D5DA5C315C, 0xDA4F3CE69EDA95DA, 0x18604850C0187818, 0x4605CF890A464346,
+        0xCD13599426CDDECD, 0x7DE96E13CF7D947D, 0x2184E7C61521A521, 0xB0FA3787E9B04AB0,
+        0x3FFCBD82E53FC33F, 0x1B6C415AD81B771B, 0x891E98113C899789, 0xFFDBC738ABFF24FF,
+        0xEB8BAB400BEB60EB, 0x842ABB3F5484AE84, 0x69B9026B6F69D069, 0x3AE8A69CCD3AD23A,
+        0x9D4EF4699C9DD39D, 0xD77B1FC8F6D7ACD7, 0xD36B03D0D6D3B8D3, 0x70DD4D3DA770AD70,
+        0x6781284F1F67E667, 0x401DDD9D3A405D40, 0xB5EE2C99C1B55BB5, 0xDE5F20FEBEDE81DE,
+        0x5D698ED3D25D345D, 0x30C090A09D30F030, 0x917ED041FC91EF91, 0xB1FE3081E1B14FB1,
+        0x78FD750DE7788578, 0x1144776688115511, 0x0104070608010501, 0xE5B381647BE556E5,
+        0x0000000000000000, 0x68BD056D6768D568, 0x985AEF77B498C298, 0xA0BA47E769A01AA0,
+        0xC53361A466C5F6C5, 0x02080E0C10020A02, 0xA6A255F359A604A6, 0x74CD51258774B974,
+        0x2DB4C3EE752D992D, 0x0B2C313A580B270B, 0xA2B249EB79A210A2, 0x76C55F299776B376,
+        0xB3F63E8DF1B345B3, 0xBEC21DA399BE7CBE, 0xCE1F509E3ECED1CE, 0xBDCE14A981BD73BD,
+        0xAE826DC319AE2CAE, 0xE983A54C1BE96AE9, 0x8A12911B248A988A, 0x31C497A69531F531,
+        0x1C705448E01C6C1C, 0xEC97BE5233EC7BEC, 0xF1E3ED1CDBF112F1, 0x995EE871BC99C799,
+        0x946ACB5FD494FE94, 0xAA9271DB39AA38AA, 0xF6FFF80EE3F609F6, 0x2698F2D42D26BE26,
+        0x2FBCCDE2652F932F, 0xEF9BB7582BEF74EF, 0xE887A24A13E86FE8, 0x8C0A830F148C868C,
+        0x35D48BBEB535E135, 0x030C090A18030F03, 0xD47716C2EED4A3D4, 0x7FE1601FDF7F9E7F,
+        0xFBCBDB208BFB30FB, 0x05141B1E28051105, 0xC1237DBC46C1E2C1, 0x5E6587D9CA5E3B5E,
+        0x907AD747F490EA90, 0x2080E0C01D20A020, 0x3DF4B38EF53DC93D, 0x8232A92B6482B082,
+        0xF7FBFF08EBF70CF7, 0xEA8FAC4603EA65EA, 0x0A28363C500A220A, 0x0D34232E680D390D,
+        0x7EE56719D77E9B7E, 0xF8C7D22A93F83FF8, 0x505DADFDBA500D50, 0x1A68465CD01A721A,
+        0xC43766A26EC4F3C4, 0x071C151238071B07, 0x5741B8EF82571657, 0xB8DA0FB7A9B862B8,
+        0x3CF0B488FD3CCC3C, 0x629533513762F762, 0xE3AB93704BE348E3, 0xC807428A0EC8CFC8,
+        0xAC8A63CF09AC26AC, 0x5255A3F1AA520752, 0x648D21450764E964, 0x1040706080105010,
+        0xD0670ADACED0B7D0, 0xD94335EC86D99AD9, 0x134C796A98135F13, 0x0C302428600C3C0C,
+        0x12487E6C90125A12, 0x29A4DFF655298D29, 0x5159AAFBB2510851, 0xB9DE08B1A1B967B9,
+        0xCF1B579836CFD4CF, 0xD67F18CEFED6A9D6, 0x73D14437BF73A273, 0x8D0E84091C8D838D,
+        0x813EA0217C81BF81, 0x544DB1E59A541954, 0xC0277ABA4EC0E7C0, 0xED93B9543BED7EED,
+        0x4E25F7B94A4E6B4E, 0x440DC1851A444944, 0xA7A652F551A701A7, 0x2AA8D6FC4D2A822A,
+        0x852EBC395C85AB85, 0x2594FBDE3525B125, 0xE6BF886E63E659E6, 0xCA0F4C861ECAC5CA,
+        0x7CED6915C77C917C, 0x8B16961D2C8B9D8B, 0x5645BFE98A561356, 0x803AA7277480BA80,
+    ],
+    [
+        0xCECE1F509E3ECED1, 0xBBBBD606BDB1BB6D, 0xEBEB8BAB400BEB60, 0x929272D94BE492E0,
+        0xEAEA8FAC4603EA65, 0xCBCB0B4B8016CBC0, 0x13134C796A98135F, 0xC1C1237DBC46C1E2,
+        0xE9E983A54C1BE96A, 0x3A3AE8A69CCD3AD2, 0xD6D67F18CEFED6A9, 0xB2B2F2398BF9B240,
+        0xD2D26F04D6DED2BD, 0x90907AD747F490EA, 0x17175C6572B8174B, 0xF8F8C7D22A93F83F,
+        0x424215D3912A4257, 0x1515546B7EA81541, 0x565645BFE98A5613, 0xB4B4EA2B9FC9B45E,
+        0x65658926430F65EC, 0x1C1C705448E01C6C, 0x88881A9F17348892, 0x434311D497224352,
+        0xC5C53361A466C5F6, 0x5C5C6D89D5DA5C31, 0x3636D882B4AD36EE, 0xBABAD201BBB9BA68,
+        0xF5F5F3F104FBF506, 0x575741B8EF825716, 0x676781284F1F67E6, 0x8D8D0E84091C8D83,
+        0x3131C497A69531F5, 0xF6F6FFF80EE3F609, 0x64648D21450764E9, 0x58587D95CDFA5825,
+        0x9E9E42FD63849EDC, 0xF4F4F7F602F3F403, 0x222288EECC0D22AA, 0xAAAA9271DB39AA38,
+        0x7575C956238F75BC, 0x0F0F3C2D22780F33, 0x0202080E0C10020A, 0xB1B1FE3081E1B14F,
+        0xDFDF5B27F8B6DF84, 0x6D6DA91E734F6DC4, 0x7373D14437BF73A2, 0x4D4D29FEB3524D64,
+        0x7C7CED6915C77C91, 0x262698F2D42D26BE, 0x2E2EB8CAE46D2E96, 0xF7F7FBFF08EBF70C,
+        0x0808203830400828, 0x5D5D698ED3D25D34, 0x44440DC1851A4449, 0x3E3EF8BA84ED3EC6,
+        0x9F9F46FA658C9FD9, 0x1414506C78A01444, 0xC8C807428A0EC8CF, 0xAEAE826DC319AE2C,
+        0x54544DB1E59A5419, 0x1010407060801050, 0xD8D84732EA8ED89F, 0xBCBCCA13AF89BC76,
+        0x1A1A68465CD01A72, 0x6B6BB10C677F6BDA, 0x6969B9026B6F69D0, 0xF3F3EBE310CBF318,
+        0xBDBDCE14A981BD73, 0x3333CC99AA8533FF, 0xABAB9676DD31AB3D, 0xFAFACFDC2683FA35,
+        0xD1D1630DDCC6D1B2, 0x9B9B56E67DAC9BCD, 0x6868BD056D6768D5, 0x4E4E25F7B94A4E6B,
+        0x1616586274B0164E, 0x95956ECC59DC95FB, 0x91917ED041FC91EF, 0xEEEE9FB05E23EE71,
+        0x4C4C2DF9B55A4C61, 0x63639134573F63F2, 0x8E8E028D03048E8C, 0x5B5B719CC7E25B2A,
+        0xCCCC175E922ECCDB, 0x3C3CF0B488FD3CCC, 0x1919644F56C8197D, 0xA1A1BE40E161A11F,
+        0x81813EA0217C81BF, 0x494939E2AB724970, 0x7B7BF17C07FF7B8A, 0xD9D94335EC86D99A,
+        0x6F6FA1107F5F6FCE, 0x3737DC85B2A537EB, 0x60609D3D5D2760FD, 0xCACA0F4C861ECAC5,
+        0xE7E7BB8F686BE75C, 0x2B2BACD1FA452B87, 0x48483DE5AD7A4875, 0xFDFDD3C934BBFD2E,
+        0x969662C553C496F4, 0x454509C68312454C, 0xFCFCD7CE32B3FC2B, 0x414119DA9B324158,
+        0x1212487E6C90125A, 0x0D0D34232E680D39, 0x7979F9720BEF7980, 0xE5E5B381647BE556,
+        0x89891E98113C8997, 0x8C8C0A830F148C86, 0xE3E3AB93704BE348, 0x202080E0C01D20A0,
+        0x3030C090A09D30F0, 0xDCDC572EF2AEDC8B, 0xB7B7E62295D1B751, 0x6C6CAD1975476CC1,
+        0x4A4A35EBA16A4A7F, 0xB5B5EE2C99C1B55B, 0x3F3FFCBD82E53FC3, 0x979766C255CC97F1,
+        0xD4D47716C2EED4A3, 0x62629533513762F7, 0x2D2DB4C3EE752D99, 0x060618121430061E,
+        0xA4A4AA5BFF49A40E, 0xA5A5AE5CF941A50B, 0x838336AE2D6C83B5, 0x5F5F6180DFC25F3E,
+        0x2A2AA8D6FC4D2A82, 0xDADA4F3CE69EDA95, 0xC9C903458C06C9CA, 0x0000000000000000,
+        0x7E7EE56719D77E9B, 0xA2A2B249EB79A210, 0x555549B6E392551C, 0xBFBFC61AA591BF79,
+        0x1111447766881155, 0xD5D57311C4E6D5A6, 0x9C9C4AF36F949CD6, 0xCFCF1B579836CFD4,
+        0x0E0E382A24700E36, 0x0A0A28363C500A22, 0x3D3DF4B38EF53DC9, 0x515159AAFBB25108,
+        0x7D7DE96E13CF7D94, 0x939376DE4DEC93E5, 0x1B1B6C415AD81B77, 0xFEFEDFC03EA3FE21,
+        0xC4C43766A26EC4F3, 0x474701C88F024746, 0x0909243F3648092D, 0x868622B5334486A4,
+        0x0B0B2C313A580B27, 0x8F8F068A050C8F89, 0x9D9D4EF4699C9DD3, 0x6A6AB50B61776ADF,
+        0x07071C151238071B, 0xB9B9DE08B1A1B967, 0xB0B0FA3787E9B04A, 0x98985AEF77B498C2,
+        0x1818604850C01878, 0x3232C89EAC8D32FA, 0x7171D94A3BAF71A8, 0x4B4B31ECA7624B7A,
+        0xEFEF9BB7582BEF74, 0x3B3BECA19AC53BD7, 0x7070DD4D3DA770AD, 0xA0A0BA47E769A01A,
+        0xE4E4B7866273E453, 0x40401DDD9D3A405D, 0xFFFFDBC738ABFF24, 0xC3C32B73B056C3E8,
+        0xA9A99E78D121A937, 0xE6E6BF886E63E659, 0x7878FD750DE77885, 0xF9F9C3D52C9BF93A,
+        0x8B8B16961D2C8B9D, 0x464605CF890A4643, 0x80803AA7277480BA, 0x1E1E785A44F01E66,
+        0x3838E0A890DD38D8, 0xE1E1A39D7C5BE142, 0xB8B8DA0FB7A9B862, 0xA8A89A7FD729A832,
+        0xE0E0A79A7A53E047, 0x0C0C302428600C3C, 0x23238CE9CA0523AF, 0x7676C55F299776B3,
+        0x1D1D74534EE81D69, 0x252594FBDE3525B1, 0x242490FCD83D24B4, 0x0505141B1E280511,
+        0xF1F1E3ED1CDBF112, 0x6E6EA51779576ECB, 0x94946ACB5FD494FE, 0x2828A0D8F05D2888,
+        0x9A9A52E17BA49AC8, 0x84842ABB3F5484AE, 0xE8E887A24A13E86F, 0xA3A3B64EED71A315,
+        0x4F4F21F0BF424F6E, 0x7777C1582F9F77B6, 0xD3D36B03D0D6D3B8, 0x85852EBC395C85AB,
+        0xE2E2AF947643E24D, 0x525255A3F1AA5207, 0xF2F2EFE416C3F21D, 0x828232A92B6482B0,
+        0x50505DADFDBA500D, 0x7A7AF57B01F77A8F, 0x2F2FBCCDE2652F93, 0x7474CD51258774B9,
+        0x535351A4F7A25302, 0xB3B3F63E8DF1B345, 0x6161993A5B2F61F8, 0xAFAF866AC511AF29,
+        0x3939E4AF96D539DD, 0x3535D48BBEB535E1, 0xDEDE5F20FEBEDE81, 0xCDCD13599426CDDE,
+        0x1F1F7C5D42F81F63, 0x99995EE871BC99C7, 0xACAC8A63CF09AC26, 0xADAD8E64C901AD23,
+        0x7272D54331B772A7, 0x2C2CB0C4E87D2C9C, 0xDDDD5329F4A6DD8E, 0xD0D0670ADACED0B7,
+        0x878726B2354C87A1, 0xBEBEC21DA399BE7C, 0x5E5E6587D9CA5E3B, 0xA6A6A255F359A604,
+        0xECEC97BE5233EC7B, 0x0404101C18200414, 0xC6C63F68AE7EC6F9, 0x03030C090A18030F,
+        0x3434D08CB8BD34E4, 0xFBFBCBDB208BFB30, 0xDBDB4B3BE096DB90, 0x59597992CBF25920,
+        0xB6B6E22593D9B654, 0xC2C22F74B65EC2ED, 0x0101040706080105, 0xF0F0E7EA1AD3F017,
+        0x5A5A759BC1EA5A2F, 0xEDED93B9543BED7E, 0xA7A7A652F551A701, 0x6666852F491766E3,
+        0x212184E7C61521A5, 0x7F7FE1601FDF7F9E, 0x8A8A12911B248A98, 0x27279CF5D22527BB,
+        0xC7C73B6FA876C7FC, 0xC0C0277ABA4EC0E7, 0x2929A4DFF655298D, 0xD7D77B1FC8F6D7AC,
+    ],
+    [
+        0xE5939376DE4DEC93, 0x9AD9D94335EC86D9, 0xC89A9A52E17BA49A, 0x5BB5B5EE2C99C1B5,
+        0xC298985AEF77B498, 0xAA222288EECC0D22, 0x4C454509C6831245, 0x2BFCFCD7CE32B3FC,
+        0x68BABAD201BBB9BA, 0xDF6A6AB50B61776A, 0x84DFDF5B27F8B6DF, 0x0A0202080E0C1002,
+        0xD99F9F46FA658C9F, 0x8BDCDC572EF2AEDC, 0x08515159AAFBB251, 0x2059597992CBF259,
+        0x7F4A4A35EBA16A4A, 0x4B17175C6572B817, 0x872B2BACD1FA452B, 0xEDC2C22F74B65EC2,
+        0xFE94946ACB5FD494, 0x03F4F4F7F602F3F4, 0x6DBBBBD606BDB1BB, 0x15A3A3B64EED71A3,
+        0xF762629533513762, 0x53E4E4B7866273E4, 0xA87171D94A3BAF71, 0xA3D4D47716C2EED4,
+        0xDECDCD13599426CD, 0xAD7070DD4D3DA770, 0x4E1616586274B016, 0x42E1E1A39D7C5BE1,
+        0x70494939E2AB7249, 0xCC3C3CF0B488FD3C, 0xE7C0C0277ABA4EC0, 0x9FD8D84732EA8ED8,
+        0x315C5C6D89D5DA5C, 0xCD9B9B56E67DAC9B, 0x23ADAD8E64C901AD, 0xAB85852EBC395C85,
+        0x02535351A4F7A253, 0x1FA1A1BE40E161A1, 0x8F7A7AF57B01F77A, 0xCFC8C807428A0EC8,
+        0x992D2DB4C3EE752D, 0x47E0E0A79A7A53E0, 0xB2D1D1630DDCC6D1, 0xA77272D54331B772,
+        0x04A6A6A255F359A6, 0x9C2C2CB0C4E87D2C, 0xF3C4C43766A26EC4, 0x48E3E3AB93704BE3,
+        0xB37676C55F299776, 0x857878FD750DE778, 0x51B7B7E62295D1B7, 0x5EB4B4EA2B9FC9B4,
+        0x2D0909243F364809, 0xD73B3BECA19AC53B, 0x360E0E382A24700E, 0x58414119DA9B3241,
+        0x614C4C2DF9B55A4C, 0x81DEDE5F20FEBEDE, 0x40B2B2F2398BF9B2, 0xEA90907AD747F490,
+        0xB1252594FBDE3525, 0x0BA5A5AE5CF941A5, 0xACD7D77B1FC8F6D7, 0x0F03030C090A1803,
+        0x5511114477668811, 0x0000000000000000, 0xE8C3C32B73B056C3, 0x962E2EB8CAE46D2E,
+        0xE0929272D94BE492, 0x74EFEF9BB7582BEF, 0x6B4E4E25F7B94A4E, 0x5A1212487E6C9012,
+        0xD39D9D4EF4699C9D, 0x947D7DE96E13CF7D, 0xC0CBCB0B4B8016CB, 0xE13535D48BBEB535,
+        0x5010104070608010, 0xA6D5D57311C4E6D5, 0x6E4F4F21F0BF424F, 0xDC9E9E42FD63849E,
+        0x644D4D29FEB3524D, 0x37A9A99E78D121A9, 0x1C555549B6E39255, 0xF9C6C63F68AE7EC6,
+        0xB7D0D0670ADACED0, 0x8A7B7BF17C07FF7B, 0x781818604850C018, 0xF1979766C255CC97,
+        0xB8D3D36B03D0D6D3, 0xEE3636D882B4AD36, 0x59E6E6BF886E63E6, 0x7548483DE5AD7A48,
+        0x13565645BFE98A56, 0xBF81813EA0217C81, 0x898F8F068A050C8F, 0xB67777C1582F9F77,
+        0xDBCCCC175E922ECC, 0xD69C9C4AF36F949C, 0x67B9B9DE08B1A1B9, 0x4DE2E2AF947643E2,
+        0x26ACAC8A63CF09AC, 0x62B8B8DA0FB7A9B8, 0x932F2FBCCDE2652F, 0x411515546B7EA815,
+        0x0EA4A4AA5BFF49A4, 0x917C7CED6915C77C, 0x95DADA4F3CE69EDA, 0xD83838E0A890DD38,
+        0x661E1E785A44F01E, 0x270B0B2C313A580B, 0x110505141B1E2805, 0xA9D6D67F18CEFED6,
+        0x441414506C78A014, 0xCB6E6EA51779576E, 0xC16C6CAD1975476C, 0x9B7E7EE56719D77E,
+        0xE36666852F491766, 0x2EFDFDD3C934BBFD, 0x4FB1B1FE3081E1B1, 0x56E5E5B381647BE5,
+        0xFD60609D3D5D2760, 0x29AFAF866AC511AF, 0x3B5E5E6587D9CA5E, 0xFF3333CC99AA8533,
+        0xA1878726B2354C87, 0xCAC9C903458C06C9, 0x17F0F0E7EA1AD3F0, 0x345D5D698ED3D25D,
+        0xC46D6DA91E734F6D, 0xC33F3FFCBD82E53F, 0x9288881A9F173488, 0x838D8D0E84091C8D,
+        0xFCC7C73B6FA876C7, 0x0CF7F7FBFF08EBF7, 0x691D1D74534EE81D, 0x6AE9E983A54C1BE9,
+        0x7BECEC97BE5233EC, 0x7EEDED93B9543BED, 0xBA80803AA7277480, 0x8D2929A4DFF65529,
+        0xBB27279CF5D22527, 0xD4CFCF1B579836CF, 0xC799995EE871BC99, 0x32A8A89A7FD729A8,
+        0x0D50505DADFDBA50, 0x330F0F3C2D22780F, 0xEB3737DC85B2A537, 0xB4242490FCD83D24,
+        0x882828A0D8F05D28, 0xF03030C090A09D30, 0xFB95956ECC59DC95, 0xBDD2D26F04D6DED2,
+        0xC63E3EF8BA84ED3E, 0x2A5B5B719CC7E25B, 0x5D40401DDD9D3A40, 0xB5838336AE2D6C83,
+        0x45B3B3F63E8DF1B3, 0xD06969B9026B6F69, 0x16575741B8EF8257, 0x631F1F7C5D42F81F,
+        0x1B07071C15123807, 0x6C1C1C705448E01C, 0x988A8A12911B248A, 0x76BCBCCA13AF89BC,
+        0xA0202080E0C01D20, 0x60EBEB8BAB400BEB, 0xD1CECE1F509E3ECE, 0x8C8E8E028D03048E,
+        0x3DABAB9676DD31AB, 0x71EEEE9FB05E23EE, 0xF53131C497A69531, 0x10A2A2B249EB79A2,
+        0xA27373D14437BF73, 0x3AF9F9C3D52C9BF9, 0xC5CACA0F4C861ECA, 0xD23A3AE8A69CCD3A,
+        0x721A1A68465CD01A, 0x30FBFBCBDB208BFB, 0x390D0D34232E680D, 0xE2C1C1237DBC46C1,
+        0x21FEFEDFC03EA3FE, 0x35FAFACFDC2683FA, 0x1DF2F2EFE416C3F2, 0xCE6F6FA1107F5F6F,
+        0x73BDBDCE14A981BD, 0xF4969662C553C496, 0x8EDDDD5329F4A6DD, 0x52434311D4972243,
+        0x07525255A3F1AA52, 0x54B6B6E22593D9B6, 0x2808082038304008, 0x18F3F3EBE310CBF3,
+        0x2CAEAE826DC319AE, 0x7CBEBEC21DA399BE, 0x7D1919644F56C819, 0x9789891E98113C89,
+        0xFA3232C89EAC8D32, 0xBE262698F2D42D26, 0x4AB0B0FA3787E9B0, 0x65EAEA8FAC4603EA,
+        0x7A4B4B31ECA7624B, 0xE964648D21450764, 0xAE84842ABB3F5484, 0xB0828232A92B6482,
+        0xDA6B6BB10C677F6B, 0x06F5F5F3F104FBF5, 0x807979F9720BEF79, 0x79BFBFC61AA591BF,
+        0x0501010407060801, 0x3E5F5F6180DFC25F, 0xBC7575C956238F75, 0xF263639134573F63,
+        0x771B1B6C415AD81B, 0xAF23238CE9CA0523, 0xC93D3DF4B38EF53D, 0xD56868BD056D6768,
+        0x822A2AA8D6FC4D2A, 0xEC65658926430F65, 0x6FE8E887A24A13E8, 0xEF91917ED041FC91,
+        0x09F6F6FFF80EE3F6, 0x24FFFFDBC738ABFF, 0x5F13134C796A9813, 0x2558587D95CDFA58,
+        0x12F1F1E3ED1CDBF1, 0x46474701C88F0247, 0x220A0A28363C500A, 0x9E7F7FE1601FDF7F,
+        0xF6C5C53361A466C5, 0x01A7A7A652F551A7, 0x5CE7E7BB8F686BE7, 0xF86161993A5B2F61,
+        0x2F5A5A759BC1EA5A, 0x1E06061812143006, 0x43464605CF890A46, 0x4944440DC1851A44,
+        0x57424215D3912A42, 0x140404101C182004, 0x1AA0A0BA47E769A0, 0x90DBDB4B3BE096DB,
+        0xDD3939E4AF96D539, 0xA4868622B5334486, 0x1954544DB1E59A54, 0x38AAAA9271DB39AA,
+        0x868C8C0A830F148C, 0xE43434D08CB8BD34, 0xA5212184E7C61521, 0x9D8B8B16961D2C8B,
+        0x3FF8F8C7D22A93F8, 0x3C0C0C302428600C, 0xB97474CD51258774, 0xE6676781284F1F67,
+    ],
+    [
+        0x68D56868BD056D67, 0x8D838D8D0E84091C, 0xCAC5CACA0F4C861E, 0x4D644D4D29FEB352,
+        0x73A27373D14437BF, 0x4B7A4B4B31ECA762, 0x4E6B4E4E25F7B94A, 0x2A822A2AA8D6FC4D,
+        0xD4A3D4D47716C2EE, 0x5207525255A3F1AA, 0x26BE262698F2D42D, 0xB345B3B3F63E8DF1,
+        0x541954544DB1E59A, 0x1E661E1E785A44F0, 0x197D1919644F56C8, 0x1F631F1F7C5D42F8,
+        0x22AA222288EECC0D, 0x030F03030C090A18, 0x4643464605CF890A, 0x3DC93D3DF4B38EF5,
+        0x2D992D2DB4C3EE75, 0x4A7F4A4A35EBA16A, 0x5302535351A4F7A2, 0x83B5838336AE2D6C,
+        0x135F13134C796A98, 0x8A988A8A12911B24, 0xB751B7B7E62295D1, 0xD5A6D5D57311C4E6,
+        0x25B1252594FBDE35, 0x79807979F9720BEF, 0xF506F5F5F3F104FB, 0xBD73BDBDCE14A981,
+        0x582558587D95CDFA, 0x2F932F2FBCCDE265, 0x0D390D0D34232E68, 0x020A0202080E0C10,
+        0xED7EEDED93B9543B, 0x5108515159AAFBB2, 0x9EDC9E9E42FD6384, 0x1155111144776688,
+        0xF21DF2F2EFE416C3, 0x3EC63E3EF8BA84ED, 0x551C555549B6E392, 0x5E3B5E5E6587D9CA,
+        0xD1B2D1D1630DDCC6, 0x164E1616586274B0, 0x3CCC3C3CF0B488FD, 0x66E36666852F4917,
+        0x70AD7070DD4D3DA7, 0x5D345D5D698ED3D2, 0xF318F3F3EBE310CB, 0x454C454509C68312,
+        0x405D40401DDD9D3A, 0xCCDBCCCC175E922E, 0xE86FE8E887A24A13, 0x94FE94946ACB5FD4,
+        0x5613565645BFE98A, 0x0828080820383040, 0xCED1CECE1F509E3E, 0x1A721A1A68465CD0,
+        0x3AD23A3AE8A69CCD, 0xD2BDD2D26F04D6DE, 0xE142E1E1A39D7C5B, 0xDF84DFDF5B27F8B6,
+        0xB55BB5B5EE2C99C1, 0x38D83838E0A890DD, 0x6ECB6E6EA5177957, 0x0E360E0E382A2470,
+        0xE556E5E5B381647B, 0xF403F4F4F7F602F3, 0xF93AF9F9C3D52C9B, 0x86A4868622B53344,
+        0xE96AE9E983A54C1B, 0x4F6E4F4F21F0BF42, 0xD6A9D6D67F18CEFE, 0x85AB85852EBC395C,
+        0x23AF23238CE9CA05, 0xCFD4CFCF1B579836, 0x32FA3232C89EAC8D, 0x99C799995EE871BC,
+        0x31F53131C497A695, 0x14441414506C78A0, 0xAE2CAEAE826DC319, 0xEE71EEEE9FB05E23,
+        0xC8CFC8C807428A0E, 0x487548483DE5AD7A, 0xD3B8D3D36B03D0D6, 0x30F03030C090A09D,
+        0xA11FA1A1BE40E161, 0x92E0929272D94BE4, 0x4158414119DA9B32, 0xB14FB1B1FE3081E1,
+        0x18781818604850C0, 0xC4F3C4C43766A26E, 0x2C9C2C2CB0C4E87D, 0x71A87171D94A3BAF,
+        0x72A77272D54331B7, 0x444944440DC1851A, 0x15411515546B7EA8, 0xFD2EFDFDD3C934BB,
+        0x37EB3737DC85B2A5, 0xBE7CBEBEC21DA399, 0x5F3E5F5F6180DFC2, 0xAA38AAAA9271DB39,
+        0x9BCD9B9B56E67DAC, 0x889288881A9F1734, 0xD89FD8D84732EA8E, 0xAB3DABAB9676DD31,
+        0x899789891E98113C, 0x9CD69C9C4AF36F94, 0xFA35FAFACFDC2683, 0x60FD60609D3D5D27,
+        0xEA65EAEA8FAC4603, 0xBC76BCBCCA13AF89, 0x62F7626295335137, 0x0C3C0C0C30242860,
+        0x24B4242490FCD83D, 0xA604A6A6A255F359, 0xA832A8A89A7FD729, 0xEC7BECEC97BE5233,
+        0x67E6676781284F1F, 0x20A0202080E0C01D, 0xDB90DBDB4B3BE096, 0x7C917C7CED6915C7,
+        0x28882828A0D8F05D, 0xDD8EDDDD5329F4A6, 0xAC26ACAC8A63CF09, 0x5B2A5B5B719CC7E2,
+        0x34E43434D08CB8BD, 0x7E9B7E7EE56719D7, 0x1050101040706080, 0xF112F1F1E3ED1CDB,
+        0x7B8A7B7BF17C07FF, 0x8F898F8F068A050C, 0x63F263639134573F, 0xA01AA0A0BA47E769,
+        0x05110505141B1E28, 0x9AC89A9A52E17BA4, 0x4352434311D49722, 0x77B67777C1582F9F,
+        0x21A5212184E7C615, 0xBF79BFBFC61AA591, 0x27BB27279CF5D225, 0x092D0909243F3648,
+        0xC3E8C3C32B73B056, 0x9FD99F9F46FA658C, 0xB654B6B6E22593D9, 0xD7ACD7D77B1FC8F6,
+        0x298D2929A4DFF655, 0xC2EDC2C22F74B65E, 0xEB60EBEB8BAB400B, 0xC0E7C0C0277ABA4E,
+        0xA40EA4A4AA5BFF49, 0x8B9D8B8B16961D2C, 0x8C868C8C0A830F14, 0x1D691D1D74534EE8,
+        0xFB30FBFBCBDB208B, 0xFF24FFFFDBC738AB, 0xC1E2C1C1237DBC46, 0xB240B2B2F2398BF9,
+        0x97F1979766C255CC, 0x2E962E2EB8CAE46D, 0xF83FF8F8C7D22A93, 0x65EC65658926430F,
+        0xF609F6F6FFF80EE3, 0x75BC7575C956238F, 0x071B07071C151238, 0x04140404101C1820,
+        0x4970494939E2AB72, 0x33FF3333CC99AA85, 0xE453E4E4B7866273, 0xD99AD9D94335EC86,
+        0xB967B9B9DE08B1A1, 0xD0B7D0D0670ADACE, 0x4257424215D3912A, 0xC7FCC7C73B6FA876,
+        0x6CC16C6CAD197547, 0x90EA90907AD747F4, 0x0000000000000000, 0x8E8C8E8E028D0304,
+        0x6FCE6F6FA1107F5F, 0x500D50505DADFDBA, 0x0105010104070608, 0xC5F6C5C53361A466,
+        0xDA95DADA4F3CE69E, 0x4746474701C88F02, 0x3FC33F3FFCBD82E5, 0xCDDECDCD13599426,
+        0x69D06969B9026B6F, 0xA210A2A2B249EB79, 0xE24DE2E2AF947643, 0x7A8F7A7AF57B01F7,
+        0xA701A7A7A652F551, 0xC6F9C6C63F68AE7E, 0x93E5939376DE4DEC, 0x0F330F0F3C2D2278,
+        0x0A220A0A28363C50, 0x061E060618121430, 0xE659E6E6BF886E63, 0x2B872B2BACD1FA45,
+        0x96F4969662C553C4, 0xA315A3A3B64EED71, 0x1C6C1C1C705448E0, 0xAF29AFAF866AC511,
+        0x6ADF6A6AB50B6177, 0x125A1212487E6C90, 0x84AE84842ABB3F54, 0x39DD3939E4AF96D5,
+        0xE75CE7E7BB8F686B, 0xB04AB0B0FA3787E9, 0x82B0828232A92B64, 0xF70CF7F7FBFF08EB,
+        0xFE21FEFEDFC03EA3, 0x9DD39D9D4EF4699C, 0x87A1878726B2354C, 0x5C315C5C6D89D5DA,
+        0x81BF81813EA0217C, 0x35E13535D48BBEB5, 0xDE81DEDE5F20FEBE, 0xB45EB4B4EA2B9FC9,
+        0xA50BA5A5AE5CF941, 0xFC2BFCFCD7CE32B3, 0x80BA80803AA72774, 0xEF74EFEF9BB7582B,
+        0xCBC0CBCB0B4B8016, 0xBB6DBBBBD606BDB1, 0x6BDA6B6BB10C677F, 0x76B37676C55F2997,
+        0xBA68BABAD201BBB9, 0x5A2F5A5A759BC1EA, 0x7D947D7DE96E13CF, 0x78857878FD750DE7,
+        0x0B270B0B2C313A58, 0x95FB95956ECC59DC, 0xE348E3E3AB93704B, 0xAD23ADAD8E64C901,
+        0x74B97474CD512587, 0x98C298985AEF77B4, 0x3BD73B3BECA19AC5, 0x36EE3636D882B4AD,
+        0x64E964648D214507, 0x6DC46D6DA91E734F, 0xDC8BDCDC572EF2AE, 0xF017F0F0E7EA1AD3,
+        0x592059597992CBF2, 0xA937A9A99E78D121, 0x4C614C4C2DF9B55A, 0x174B17175C6572B8,
+        0x7F9E7F7FE1601FDF, 0x91EF91917ED041FC, 0xB862B8B8DA0FB7A9, 0xC9CAC9C903458C06,
+        0x5716575741B8EF82, 0x1B771B1B6C415AD8, 0xE047E0E0A79A7A53, 0x61F86161993A5B2F,
+    ],
+    [
+        0x29A832A8A89A7FD7, 0x224352434311D497, 0xC25F3E5F5F6180DF, 0x30061E0606181214,
+        0x7F6BDA6B6BB10C67, 0x8F75BC7575C95623, 0x476CC16C6CAD1975, 0xF2592059597992CB,
+        0xAF71A87171D94A3B, 0xB6DF84DFDF5B27F8, 0x4C87A1878726B235, 0xDC95FB95956ECC59,
+        0xB8174B17175C6572, 0xD3F017F0F0E7EA1A, 0x8ED89FD8D84732EA, 0x48092D0909243F36,
+        0x4F6DC46D6DA91E73, 0xCBF318F3F3EBE310, 0xE81D691D1D74534E, 0x16CBC0CBCB0B4B80,
+        0x06C9CAC9C903458C, 0x524D644D4D29FEB3, 0x7D2C9C2C2CB0C4E8, 0x11AF29AFAF866AC5,
+        0xEF79807979F9720B, 0x53E047E0E0A79A7A, 0xCC97F1979766C255, 0xBBFD2EFDFDD3C934,
+        0x5F6FCE6F6FA1107F, 0x624B7A4B4B31ECA7, 0x12454C454509C683, 0xD539DD3939E4AF96,
+        0xED3EC63E3EF8BA84, 0xA6DD8EDDDD5329F4, 0x71A315A3A3B64EED, 0x424F6E4F4F21F0BF,
+        0xC9B45EB4B4EA2B9F, 0xD9B654B6B6E22593, 0xA49AC89A9A52E17B, 0x700E360E0E382A24,
+        0xF81F631F1F7C5D42, 0x91BF79BFBFC61AA5, 0xA815411515546B7E, 0x5BE142E1E1A39D7C,
+        0x724970494939E2AB, 0xDED2BDD2D26F04D6, 0xEC93E5939376DE4D, 0x7EC6F9C6C63F68AE,
+        0xE492E0929272D94B, 0xB772A77272D54331, 0x849EDC9E9E42FD63, 0x2F61F86161993A5B,
+        0xC6D1B2D1D1630DDC, 0x3F63F26363913457, 0x83FA35FAFACFDC26, 0x23EE71EEEE9FB05E,
+        0xF3F403F4F4F7F602, 0xC8197D1919644F56, 0xE6D5A6D5D57311C4, 0x01AD23ADAD8E64C9,
+        0xFA582558587D95CD, 0x49A40EA4A4AA5BFF, 0xB1BB6DBBBBD606BD, 0x61A11FA1A1BE40E1,
+        0xAEDC8BDCDC572EF2, 0xC3F21DF2F2EFE416, 0x6C83B5838336AE2D, 0xA537EB3737DC85B2,
+        0x2A4257424215D391, 0x73E453E4E4B78662, 0xF77A8F7A7AF57B01, 0x8D32FA3232C89EAC,
+        0x949CD69C9C4AF36F, 0x2ECCDBCCCC175E92, 0x31AB3DABAB9676DD, 0x6A4A7F4A4A35EBA1,
+        0x0C8F898F8F068A05, 0x576ECB6E6EA51779, 0x2004140404101C18, 0x2527BB27279CF5D2,
+        0x6D2E962E2EB8CAE4, 0x6BE75CE7E7BB8F68, 0x43E24DE2E2AF9476, 0xEA5A2F5A5A759BC1,
+        0xC496F4969662C553, 0xB0164E1616586274, 0x0523AF23238CE9CA, 0x452B872B2BACD1FA,
+        0x5EC2EDC2C22F74B6, 0x0F65EC6565892643, 0x1766E36666852F49, 0x780F330F0F3C2D22,
+        0x89BC76BCBCCA13AF, 0x21A937A9A99E78D1, 0x024746474701C88F, 0x324158414119DA9B,
+        0xBD34E43434D08CB8, 0x7A487548483DE5AD, 0xB3FC2BFCFCD7CE32, 0xD1B751B7B7E62295,
+        0x776ADF6A6AB50B61, 0x34889288881A9F17, 0x41A50BA5A5AE5CF9, 0xA25302535351A4F7,
+        0x4486A4868622B533, 0x9BF93AF9F9C3D52C, 0xE25B2A5B5B719CC7, 0x96DB90DBDB4B3BE0,
+        0xDD38D83838E0A890, 0xFF7B8A7B7BF17C07, 0x56C3E8C3C32B73B0, 0xF01E661E1E785A44,
+        0x0D22AA222288EECC, 0x8533FF3333CC99AA, 0x3D24B4242490FCD8, 0x5D28882828A0D8F0,
+        0xAD36EE3636D882B4, 0x76C7FCC7C73B6FA8, 0xF9B240B2B2F2398B, 0xC53BD73B3BECA19A,
+        0x048E8C8E8E028D03, 0x9F77B67777C1582F, 0xB9BA68BABAD201BB, 0xFBF506F5F5F3F104,
+        0xA014441414506C78, 0x8C9FD99F9F46FA65, 0x4008280808203830, 0x92551C555549B6E3,
+        0xAC9BCD9B9B56E67D, 0x5A4C614C4C2DF9B5, 0xA3FE21FEFEDFC03E, 0x2760FD60609D3D5D,
+        0xDA5C315C5C6D89D5, 0x9EDA95DADA4F3CE6, 0xC018781818604850, 0x0A4643464605CF89,
+        0x26CDDECDCD135994, 0xCF7D947D7DE96E13, 0x1521A5212184E7C6, 0xE9B04AB0B0FA3787,
+        0xE53FC33F3FFCBD82, 0xD81B771B1B6C415A, 0x3C899789891E9811, 0xABFF24FFFFDBC738,
+        0x0BEB60EBEB8BAB40, 0x5484AE84842ABB3F, 0x6F69D06969B9026B, 0xCD3AD23A3AE8A69C,
+        0x9C9DD39D9D4EF469, 0xF6D7ACD7D77B1FC8, 0xD6D3B8D3D36B03D0, 0xA770AD7070DD4D3D,
+        0x1F67E6676781284F, 0x3A405D40401DDD9D, 0xC1B55BB5B5EE2C99, 0xBEDE81DEDE5F20FE,
+        0xD25D345D5D698ED3, 0x9D30F03030C090A0, 0xFC91EF91917ED041, 0xE1B14FB1B1FE3081,
+        0xE778857878FD750D, 0x8811551111447766, 0x0801050101040706, 0x7BE556E5E5B38164,
+        0x0000000000000000, 0x6768D56868BD056D, 0xB498C298985AEF77, 0x69A01AA0A0BA47E7,
+        0x66C5F6C5C53361A4, 0x10020A0202080E0C, 0x59A604A6A6A255F3, 0x8774B97474CD5125,
+        0x752D992D2DB4C3EE, 0x580B270B0B2C313A, 0x79A210A2A2B249EB, 0x9776B37676C55F29,
+        0xF1B345B3B3F63E8D, 0x99BE7CBEBEC21DA3, 0x3ECED1CECE1F509E, 0x81BD73BDBDCE14A9,
+        0x19AE2CAEAE826DC3, 0x1BE96AE9E983A54C, 0x248A988A8A12911B, 0x9531F53131C497A6,
+        0xE01C6C1C1C705448, 0x33EC7BECEC97BE52, 0xDBF112F1F1E3ED1C, 0xBC99C799995EE871,
+        0xD494FE94946ACB5F, 0x39AA38AAAA9271DB, 0xE3F609F6F6FFF80E, 0x2D26BE262698F2D4,
+        0x652F932F2FBCCDE2, 0x2BEF74EFEF9BB758, 0x13E86FE8E887A24A, 0x148C868C8C0A830F,
+        0xB535E13535D48BBE, 0x18030F03030C090A, 0xEED4A3D4D47716C2, 0xDF7F9E7F7FE1601F,
+        0x8BFB30FBFBCBDB20, 0x2805110505141B1E, 0x46C1E2C1C1237DBC, 0xCA5E3B5E5E6587D9,
+        0xF490EA90907AD747, 0x1D20A0202080E0C0, 0xF53DC93D3DF4B38E, 0x6482B0828232A92B,
+        0xEBF70CF7F7FBFF08, 0x03EA65EAEA8FAC46, 0x500A220A0A28363C, 0x680D390D0D34232E,
+        0xD77E9B7E7EE56719, 0x93F83FF8F8C7D22A, 0xBA500D50505DADFD, 0xD01A721A1A68465C,
+        0x6EC4F3C4C43766A2, 0x38071B07071C1512, 0x825716575741B8EF, 0xA9B862B8B8DA0FB7,
+        0xFD3CCC3C3CF0B488, 0x3762F76262953351, 0x4BE348E3E3AB9370, 0x0EC8CFC8C807428A,
+        0x09AC26ACAC8A63CF, 0xAA5207525255A3F1, 0x0764E964648D2145, 0x8010501010407060,
+        0xCED0B7D0D0670ADA, 0x86D99AD9D94335EC, 0x98135F13134C796A, 0x600C3C0C0C302428,
+        0x90125A1212487E6C, 0x55298D2929A4DFF6, 0xB25108515159AAFB, 0xA1B967B9B9DE08B1,
+        0x36CFD4CFCF1B5798, 0xFED6A9D6D67F18CE, 0xBF73A27373D14437, 0x1C8D838D8D0E8409,
+        0x7C81BF81813EA021, 0x9A541954544DB1E5, 0x4EC0E7C0C0277ABA, 0x3BED7EEDED93B954,
+        0x4A4E6B4E4E25F7B9, 0x1A444944440DC185, 0x51A701A7A7A652F5, 0x4D2A822A2AA8D6FC,
+        0x5C85AB85852EBC39, 0x3525B1252594FBDE, 0x63E659E6E6BF886E, 0x1ECAC5CACA0F4C86,
+        0xC77C917C7CED6915, 0x2C8B9D8B8B16961D, 0x8A5613565645BFE9, 0x7480BA80803AA727,
+    ],
+    [
+        0x9E3ECED1CECE1F50, 0xBDB1BB6DBBBBD606, 0x400BEB60EBEB8BAB, 0x4BE492E0929272D9,
+        0x4603EA65EAEA8FAC, 0x8016CBC0CBCB0B4B, 0x6A98135F13134C79, 0xBC46C1E2C1C1237D,
+        0x4C1BE96AE9E983A5, 0x9CCD3AD23A3AE8A6, 0xCEFED6A9D6D67F18, 0x8BF9B240B2B2F239,
+        0xD6DED2BDD2D26F04, 0x47F490EA90907AD7, 0x72B8174B17175C65, 0x2A93F83FF8F8C7D2,
+        0x912A4257424215D3, 0x7EA815411515546B, 0xE98A5613565645BF, 0x9FC9B45EB4B4EA2B,
+        0x430F65EC65658926, 0x48E01C6C1C1C7054, 0x1734889288881A9F, 0x97224352434311D4,
+        0xA466C5F6C5C53361, 0xD5DA5C315C5C6D89, 0xB4AD36EE3636D882, 0xBBB9BA68BABAD201,
+        0x04FBF506F5F5F3F1, 0xEF825716575741B8, 0x4F1F67E667678128, 0x091C8D838D8D0E84,
+        0xA69531F53131C497, 0x0EE3F609F6F6FFF8, 0x450764E964648D21, 0xCDFA582558587D95,
+        0x63849EDC9E9E42FD, 0x02F3F403F4F4F7F6, 0xCC0D22AA222288EE, 0xDB39AA38AAAA9271,
+        0x238F75BC7575C956, 0x22780F330F0F3C2D, 0x0C10020A0202080E, 0x81E1B14FB1B1FE30,
+        0xF8B6DF84DFDF5B27, 0x734F6DC46D6DA91E, 0x37BF73A27373D144, 0xB3524D644D4D29FE,
+        0x15C77C917C7CED69, 0xD42D26BE262698F2, 0xE46D2E962E2EB8CA, 0x08EBF70CF7F7FBFF,
+        0x3040082808082038, 0xD3D25D345D5D698E, 0x851A444944440DC1, 0x84ED3EC63E3EF8BA,
+        0x658C9FD99F9F46FA, 0x78A014441414506C, 0x8A0EC8CFC8C80742, 0xC319AE2CAEAE826D,
+        0xE59A541954544DB1, 0x6080105010104070, 0xEA8ED89FD8D84732, 0xAF89BC76BCBCCA13,
+        0x5CD01A721A1A6846, 0x677F6BDA6B6BB10C, 0x6B6F69D06969B902, 0x10CBF318F3F3EBE3,
+        0xA981BD73BDBDCE14, 0xAA8533FF3333CC99, 0xDD31AB3DABAB9676, 0x2683FA35FAFACFDC,
+        0xDCC6D1B2D1D1630D, 0x7DAC9BCD9B9B56E6, 0x6D6768D56868BD05, 0xB94A4E6B4E4E25F7,
+        0x74B0164E16165862, 0x59DC95FB95956ECC, 0x41FC91EF91917ED0, 0x5E23EE71EEEE9FB0,
+        0xB55A4C614C4C2DF9, 0x573F63F263639134, 0x03048E8C8E8E028D, 0xC7E25B2A5B5B719C,
+        0x922ECCDBCCCC175E, 0x88FD3CCC3C3CF0B4, 0x56C8197D1919644F, 0xE161A11FA1A1BE40,
+        0x217C81BF81813EA0, 0xAB724970494939E2, 0x07FF7B8A7B7BF17C, 0xEC86D99AD9D94335,
+        0x7F5F6FCE6F6FA110, 0xB2A537EB3737DC85, 0x5D2760FD60609D3D, 0x861ECAC5CACA0F4C,
+        0x686BE75CE7E7BB8F, 0xFA452B872B2BACD1, 0xAD7A487548483DE5, 0x34BBFD2EFDFDD3C9,
+        0x53C496F4969662C5, 0x8312454C454509C6, 0x32B3FC2BFCFCD7CE, 0x9B324158414119DA,
+        0x6C90125A1212487E, 0x2E680D390D0D3423, 0x0BEF79807979F972, 0x647BE556E5E5B381,
+        0x113C899789891E98, 0x0F148C868C8C0A83, 0x704BE348E3E3AB93, 0xC01D20A0202080E0,
+        0xA09D30F03030C090, 0xF2AEDC8BDCDC572E, 0x95D1B751B7B7E622, 0x75476CC16C6CAD19,
+        0xA16A4A7F4A4A35EB, 0x99C1B55BB5B5EE2C, 0x82E53FC33F3FFCBD, 0x55CC97F1979766C2,
+        0xC2EED4A3D4D47716, 0x513762F762629533, 0xEE752D992D2DB4C3, 0x1430061E06061812,
+        0xFF49A40EA4A4AA5B, 0xF941A50BA5A5AE5C, 0x2D6C83B5838336AE, 0xDFC25F3E5F5F6180,
+        0xFC4D2A822A2AA8D6, 0xE69EDA95DADA4F3C, 0x8C06C9CAC9C90345, 0x0000000000000000,
+        0x19D77E9B7E7EE567, 0xEB79A210A2A2B249, 0xE392551C555549B6, 0xA591BF79BFBFC61A,
+        0x6688115511114477, 0xC4E6D5A6D5D57311, 0x6F949CD69C9C4AF3, 0x9836CFD4CFCF1B57,
+        0x24700E360E0E382A, 0x3C500A220A0A2836, 0x8EF53DC93D3DF4B3, 0xFBB25108515159AA,
+        0x13CF7D947D7DE96E, 0x4DEC93E5939376DE, 0x5AD81B771B1B6C41, 0x3EA3FE21FEFEDFC0,
+        0xA26EC4F3C4C43766, 0x8F024746474701C8, 0x3648092D0909243F, 0x334486A4868622B5,
+        0x3A580B270B0B2C31, 0x050C8F898F8F068A, 0x699C9DD39D9D4EF4, 0x61776ADF6A6AB50B,
+        0x1238071B07071C15, 0xB1A1B967B9B9DE08, 0x87E9B04AB0B0FA37, 0x77B498C298985AEF,
+        0x50C0187818186048, 0xAC8D32FA3232C89E, 0x3BAF71A87171D94A, 0xA7624B7A4B4B31EC,
+        0x582BEF74EFEF9BB7, 0x9AC53BD73B3BECA1, 0x3DA770AD7070DD4D, 0xE769A01AA0A0BA47,
+        0x6273E453E4E4B786, 0x9D3A405D40401DDD, 0x38ABFF24FFFFDBC7, 0xB056C3E8C3C32B73,
+        0xD121A937A9A99E78, 0x6E63E659E6E6BF88, 0x0DE778857878FD75, 0x2C9BF93AF9F9C3D5,
+        0x1D2C8B9D8B8B1696, 0x890A4643464605CF, 0x277480BA80803AA7, 0x44F01E661E1E785A,
+        0x90DD38D83838E0A8, 0x7C5BE142E1E1A39D, 0xB7A9B862B8B8DA0F, 0xD729A832A8A89A7F,
+        0x7A53E047E0E0A79A, 0x28600C3C0C0C3024, 0xCA0523AF23238CE9, 0x299776B37676C55F,
+        0x4EE81D691D1D7453, 0xDE3525B1252594FB, 0xD83D24B4242490FC, 0x1E2805110505141B,
+        0x1CDBF112F1F1E3ED, 0x79576ECB6E6EA517, 0x5FD494FE94946ACB, 0xF05D28882828A0D8,
+        0x7BA49AC89A9A52E1, 0x3F5484AE84842ABB, 0x4A13E86FE8E887A2, 0xED71A315A3A3B64E,
+        0xBF424F6E4F4F21F0, 0x2F9F77B67777C158, 0xD0D6D3B8D3D36B03, 0x395C85AB85852EBC,
+        0x7643E24DE2E2AF94, 0xF1AA5207525255A3, 0x16C3F21DF2F2EFE4, 0x2B6482B0828232A9,
+        0xFDBA500D50505DAD, 0x01F77A8F7A7AF57B, 0xE2652F932F2FBCCD, 0x258774B97474CD51,
+        0xF7A25302535351A4, 0x8DF1B345B3B3F63E, 0x5B2F61F86161993A, 0xC511AF29AFAF866A,
+        0x96D539DD3939E4AF, 0xBEB535E13535D48B, 0xFEBEDE81DEDE5F20, 0x9426CDDECDCD1359,
+        0x42F81F631F1F7C5D, 0x71BC99C799995EE8, 0xCF09AC26ACAC8A63, 0xC901AD23ADAD8E64,
+        0x31B772A77272D543, 0xE87D2C9C2C2CB0C4, 0xF4A6DD8EDDDD5329, 0xDACED0B7D0D0670A,
+        0x354C87A1878726B2, 0xA399BE7CBEBEC21D, 0xD9CA5E3B5E5E6587, 0xF359A604A6A6A255,
+        0x5233EC7BECEC97BE, 0x182004140404101C, 0xAE7EC6F9C6C63F68, 0x0A18030F03030C09,
+        0xB8BD34E43434D08C, 0x208BFB30FBFBCBDB, 0xE096DB90DBDB4B3B, 0xCBF2592059597992,
+        0x93D9B654B6B6E225, 0xB65EC2EDC2C22F74, 0x0608010501010407, 0x1AD3F017F0F0E7EA,
+        0xC1EA5A2F5A5A759B, 0x543BED7EEDED93B9, 0xF551A701A7A7A652, 0x491766E36666852F,
+        0xC61521A5212184E7, 0x1FDF7F9E7F7FE160, 0x1B248A988A8A1291, 0xD22527BB27279CF5,
+        0xA876C7FCC7C73B6F, 0xBA4EC0E7C0C0277A, 0xF655298D2929A4DF, 0xC8F6D7ACD7D77B1F,
+    ],
+    [
+        0xDE4DEC93E5939376, 0x35EC86D99AD9D943, 0xE17BA49AC89A9A52, 0x2C99C1B55BB5B5EE,
+        0xEF77B498C298985A, 0xEECC0D22AA222288, 0xC68312454C454509, 0xCE32B3FC2BFCFCD7,
+        0x01BBB9BA68BABAD2, 0x0B61776ADF6A6AB5, 0x27F8B6DF84DFDF5B, 0x0E0C10020A020208,
+        0xFA658C9FD99F9F46, 0x2EF2AEDC8BDCDC57, 0xAAFBB25108515159, 0x92CBF25920595979,
+        0xEBA16A4A7F4A4A35, 0x6572B8174B17175C, 0xD1FA452B872B2BAC, 0x74B65EC2EDC2C22F,
+        0xCB5FD494FE94946A, 0xF602F3F403F4F4F7, 0x06BDB1BB6DBBBBD6, 0x4EED71A315A3A3B6,
+        0x33513762F7626295, 0x866273E453E4E4B7, 0x4A3BAF71A87171D9, 0x16C2EED4A3D4D477,
+        0x599426CDDECDCD13, 0x4D3DA770AD7070DD, 0x6274B0164E161658, 0x9D7C5BE142E1E1A3,
+        0xE2AB724970494939, 0xB488FD3CCC3C3CF0, 0x7ABA4EC0E7C0C027, 0x32EA8ED89FD8D847,
+        0x89D5DA5C315C5C6D, 0xE67DAC9BCD9B9B56, 0x64C901AD23ADAD8E, 0xBC395C85AB85852E,
+        0xA4F7A25302535351, 0x40E161A11FA1A1BE, 0x7B01F77A8F7A7AF5, 0x428A0EC8CFC8C807,
+        0xC3EE752D992D2DB4, 0x9A7A53E047E0E0A7, 0x0DDCC6D1B2D1D163, 0x4331B772A77272D5,
+        0x55F359A604A6A6A2, 0xC4E87D2C9C2C2CB0, 0x66A26EC4F3C4C437, 0x93704BE348E3E3AB,
+        0x5F299776B37676C5, 0x750DE778857878FD, 0x2295D1B751B7B7E6, 0x2B9FC9B45EB4B4EA,
+        0x3F3648092D090924, 0xA19AC53BD73B3BEC, 0x2A24700E360E0E38, 0xDA9B324158414119,
+        0xF9B55A4C614C4C2D, 0x20FEBEDE81DEDE5F, 0x398BF9B240B2B2F2, 0xD747F490EA90907A,
+        0xFBDE3525B1252594, 0x5CF941A50BA5A5AE, 0x1FC8F6D7ACD7D77B, 0x090A18030F03030C,
+        0x7766881155111144, 0x0000000000000000, 0x73B056C3E8C3C32B, 0xCAE46D2E962E2EB8,
+        0xD94BE492E0929272, 0xB7582BEF74EFEF9B, 0xF7B94A4E6B4E4E25, 0x7E6C90125A121248,
+        0xF4699C9DD39D9D4E, 0x6E13CF7D947D7DE9, 0x4B8016CBC0CBCB0B, 0x8BBEB535E13535D4,
+        0x7060801050101040, 0x11C4E6D5A6D5D573, 0xF0BF424F6E4F4F21, 0xFD63849EDC9E9E42,
+        0xFEB3524D644D4D29, 0x78D121A937A9A99E, 0xB6E392551C555549, 0x68AE7EC6F9C6C63F,
+        0x0ADACED0B7D0D067, 0x7C07FF7B8A7B7BF1, 0x4850C01878181860, 0xC255CC97F1979766,
+        0x03D0D6D3B8D3D36B, 0x82B4AD36EE3636D8, 0x886E63E659E6E6BF, 0xE5AD7A487548483D,
+        0xBFE98A5613565645, 0xA0217C81BF81813E, 0x8A050C8F898F8F06, 0x582F9F77B67777C1,
+        0x5E922ECCDBCCCC17, 0xF36F949CD69C9C4A, 0x08B1A1B967B9B9DE, 0x947643E24DE2E2AF,
+        0x63CF09AC26ACAC8A, 0x0FB7A9B862B8B8DA, 0xCDE2652F932F2FBC, 0x6B7EA81541151554,
+        0x5BFF49A40EA4A4AA, 0x6915C77C917C7CED, 0x3CE69EDA95DADA4F, 0xA890DD38D83838E0,
+        0x5A44F01E661E1E78, 0x313A580B270B0B2C, 0x1B1E280511050514, 0x18CEFED6A9D6D67F,
+        0x6C78A01444141450, 0x1779576ECB6E6EA5, 0x1975476CC16C6CAD, 0x6719D77E9B7E7EE5,
+        0x2F491766E3666685, 0xC934BBFD2EFDFDD3, 0x3081E1B14FB1B1FE, 0x81647BE556E5E5B3,
+        0x3D5D2760FD60609D, 0x6AC511AF29AFAF86, 0x87D9CA5E3B5E5E65, 0x99AA8533FF3333CC,
+        0xB2354C87A1878726, 0x458C06C9CAC9C903, 0xEA1AD3F017F0F0E7, 0x8ED3D25D345D5D69,
+        0x1E734F6DC46D6DA9, 0xBD82E53FC33F3FFC, 0x9F1734889288881A, 0x84091C8D838D8D0E,
+        0x6FA876C7FCC7C73B, 0xFF08EBF70CF7F7FB, 0x534EE81D691D1D74, 0xA54C1BE96AE9E983,
+        0xBE5233EC7BECEC97, 0xB9543BED7EEDED93, 0xA7277480BA80803A, 0xDFF655298D2929A4,
+        0xF5D22527BB27279C, 0x579836CFD4CFCF1B, 0xE871BC99C799995E, 0x7FD729A832A8A89A,
+        0xADFDBA500D50505D, 0x2D22780F330F0F3C, 0x85B2A537EB3737DC, 0xFCD83D24B4242490,
+        0xD8F05D28882828A0, 0x90A09D30F03030C0, 0xCC59DC95FB95956E, 0x04D6DED2BDD2D26F,
+        0xBA84ED3EC63E3EF8, 0x9CC7E25B2A5B5B71, 0xDD9D3A405D40401D, 0xAE2D6C83B5838336,
+        0x3E8DF1B345B3B3F6, 0x026B6F69D06969B9, 0xB8EF825716575741, 0x5D42F81F631F1F7C,
+        0x151238071B07071C, 0x5448E01C6C1C1C70, 0x911B248A988A8A12, 0x13AF89BC76BCBCCA,
+        0xE0C01D20A0202080, 0xAB400BEB60EBEB8B, 0x509E3ECED1CECE1F, 0x8D03048E8C8E8E02,
+        0x76DD31AB3DABAB96, 0xB05E23EE71EEEE9F, 0x97A69531F53131C4, 0x49EB79A210A2A2B2,
+        0x4437BF73A27373D1, 0xD52C9BF93AF9F9C3, 0x4C861ECAC5CACA0F, 0xA69CCD3AD23A3AE8,
+        0x465CD01A721A1A68, 0xDB208BFB30FBFBCB, 0x232E680D390D0D34, 0x7DBC46C1E2C1C123,
+        0xC03EA3FE21FEFEDF, 0xDC2683FA35FAFACF, 0xE416C3F21DF2F2EF, 0x107F5F6FCE6F6FA1,
+        0x14A981BD73BDBDCE, 0xC553C496F4969662, 0x29F4A6DD8EDDDD53, 0xD497224352434311,
+        0xA3F1AA5207525255, 0x2593D9B654B6B6E2, 0x3830400828080820, 0xE310CBF318F3F3EB,
+        0x6DC319AE2CAEAE82, 0x1DA399BE7CBEBEC2, 0x4F56C8197D191964, 0x98113C899789891E,
+        0x9EAC8D32FA3232C8, 0xF2D42D26BE262698, 0x3787E9B04AB0B0FA, 0xAC4603EA65EAEA8F,
+        0xECA7624B7A4B4B31, 0x21450764E964648D, 0xBB3F5484AE84842A, 0xA92B6482B0828232,
+        0x0C677F6BDA6B6BB1, 0xF104FBF506F5F5F3, 0x720BEF79807979F9, 0x1AA591BF79BFBFC6,
+        0x0706080105010104, 0x80DFC25F3E5F5F61, 0x56238F75BC7575C9, 0x34573F63F2636391,
+        0x415AD81B771B1B6C, 0xE9CA0523AF23238C, 0xB38EF53DC93D3DF4, 0x056D6768D56868BD,
+        0xD6FC4D2A822A2AA8, 0x26430F65EC656589, 0xA24A13E86FE8E887, 0xD041FC91EF91917E,
+        0xF80EE3F609F6F6FF, 0xC738ABFF24FFFFDB, 0x796A98135F13134C, 0x95CDFA582558587D,
+        0xED1CDBF112F1F1E3, 0xC88F024746474701, 0x363C500A220A0A28, 0x601FDF7F9E7F7FE1,
+        0x61A466C5F6C5C533, 0x52F551A701A7A7A6, 0x8F686BE75CE7E7BB, 0x3A5B2F61F8616199,
+        0x9BC1EA5A2F5A5A75, 0x121430061E060618, 0xCF890A4643464605, 0xC1851A444944440D,
+        0xD3912A4257424215, 0x1C18200414040410, 0x47E769A01AA0A0BA, 0x3BE096DB90DBDB4B,
+        0xAF96D539DD3939E4, 0xB5334486A4868622, 0xB1E59A541954544D, 0x71DB39AA38AAAA92,
+        0x830F148C868C8C0A, 0x8CB8BD34E43434D0, 0xE7C61521A5212184, 0x961D2C8B9D8B8B16,
+        0xD22A93F83FF8F8C7, 0x2428600C3C0C0C30, 0x51258774B97474CD, 0x284F1F67E6676781,
+    ],
+    [
+        0xBD056D6768D56868, 0x0E84091C8D838D8D, 0x0F4C861ECAC5CACA, 0x29FEB3524D644D4D,
+        0xD14437BF73A27373, 0x31ECA7624B7A4B4B, 0x25F7B94A4E6B4E4E, 0xA8D6FC4D2A822A2A,
+        0x7716C2EED4A3D4D4, 0x55A3F1AA52075252, 0x98F2D42D26BE2626, 0xF63E8DF1B345B3B3,
+        0x4DB1E59A54195454, 0x785A44F01E661E1E, 0x644F56C8197D1919, 0x7C5D42F81F631F1F,
+        0x88EECC0D22AA2222, 0x0C090A18030F0303, 0x05CF890A46434646, 0xF4B38EF53DC93D3D,
+        0xB4C3EE752D992D2D, 0x35EBA16A4A7F4A4A, 0x51A4F7A253025353, 0x36AE2D6C83B58383,
+        0x4C796A98135F1313, 0x12911B248A988A8A, 0xE62295D1B751B7B7, 0x7311C4E6D5A6D5D5,
+        0x94FBDE3525B12525, 0xF9720BEF79807979, 0xF3F104FBF506F5F5, 0xCE14A981BD73BDBD,
+        0x7D95CDFA58255858, 0xBCCDE2652F932F2F, 0x34232E680D390D0D, 0x080E0C10020A0202,
+        0x93B9543BED7EEDED, 0x59AAFBB251085151, 0x42FD63849EDC9E9E, 0x4477668811551111,
+        0xEFE416C3F21DF2F2, 0xF8BA84ED3EC63E3E, 0x49B6E392551C5555, 0x6587D9CA5E3B5E5E,
+        0x630DDCC6D1B2D1D1, 0x586274B0164E1616, 0xF0B488FD3CCC3C3C, 0x852F491766E36666,
+        0xDD4D3DA770AD7070, 0x698ED3D25D345D5D, 0xEBE310CBF318F3F3, 0x09C68312454C4545,
+        0x1DDD9D3A405D4040, 0x175E922ECCDBCCCC, 0x87A24A13E86FE8E8, 0x6ACB5FD494FE9494,
+        0x45BFE98A56135656, 0x2038304008280808, 0x1F509E3ECED1CECE, 0x68465CD01A721A1A,
+        0xE8A69CCD3AD23A3A, 0x6F04D6DED2BDD2D2, 0xA39D7C5BE142E1E1, 0x5B27F8B6DF84DFDF,
+        0xEE2C99C1B55BB5B5, 0xE0A890DD38D83838, 0xA51779576ECB6E6E, 0x382A24700E360E0E,
+        0xB381647BE556E5E5, 0xF7F602F3F403F4F4, 0xC3D52C9BF93AF9F9, 0x22B5334486A48686,
+        0x83A54C1BE96AE9E9, 0x21F0BF424F6E4F4F, 0x7F18CEFED6A9D6D6, 0x2EBC395C85AB8585,
+        0x8CE9CA0523AF2323, 0x1B579836CFD4CFCF, 0xC89EAC8D32FA3232, 0x5EE871BC99C79999,
+        0xC497A69531F53131, 0x506C78A014441414, 0x826DC319AE2CAEAE, 0x9FB05E23EE71EEEE,
+        0x07428A0EC8CFC8C8, 0x3DE5AD7A48754848, 0x6B03D0D6D3B8D3D3, 0xC090A09D30F03030,
+        0xBE40E161A11FA1A1, 0x72D94BE492E09292, 0x19DA9B3241584141, 0xFE3081E1B14FB1B1,
+        0x604850C018781818, 0x3766A26EC4F3C4C4, 0xB0C4E87D2C9C2C2C, 0xD94A3BAF71A87171,
+        0xD54331B772A77272, 0x0DC1851A44494444, 0x546B7EA815411515, 0xD3C934BBFD2EFDFD,
+        0xDC85B2A537EB3737, 0xC21DA399BE7CBEBE, 0x6180DFC25F3E5F5F, 0x9271DB39AA38AAAA,
+        0x56E67DAC9BCD9B9B, 0x1A9F173488928888, 0x4732EA8ED89FD8D8, 0x9676DD31AB3DABAB,
+        0x1E98113C89978989, 0x4AF36F949CD69C9C, 0xCFDC2683FA35FAFA, 0x9D3D5D2760FD6060,
+        0x8FAC4603EA65EAEA, 0xCA13AF89BC76BCBC, 0x9533513762F76262, 0x302428600C3C0C0C,
+        0x90FCD83D24B42424, 0xA255F359A604A6A6, 0x9A7FD729A832A8A8, 0x97BE5233EC7BECEC,
+        0x81284F1F67E66767, 0x80E0C01D20A02020, 0x4B3BE096DB90DBDB, 0xED6915C77C917C7C,
+        0xA0D8F05D28882828, 0x5329F4A6DD8EDDDD, 0x8A63CF09AC26ACAC, 0x719CC7E25B2A5B5B,
+        0xD08CB8BD34E43434, 0xE56719D77E9B7E7E, 0x4070608010501010, 0xE3ED1CDBF112F1F1,
+        0xF17C07FF7B8A7B7B, 0x068A050C8F898F8F, 0x9134573F63F26363, 0xBA47E769A01AA0A0,
+        0x141B1E2805110505, 0x52E17BA49AC89A9A, 0x11D4972243524343, 0xC1582F9F77B67777,
+        0x84E7C61521A52121, 0xC61AA591BF79BFBF, 0x9CF5D22527BB2727, 0x243F3648092D0909,
+        0x2B73B056C3E8C3C3, 0x46FA658C9FD99F9F, 0xE22593D9B654B6B6, 0x7B1FC8F6D7ACD7D7,
+        0xA4DFF655298D2929, 0x2F74B65EC2EDC2C2, 0x8BAB400BEB60EBEB, 0x277ABA4EC0E7C0C0,
+        0xAA5BFF49A40EA4A4, 0x16961D2C8B9D8B8B, 0x0A830F148C868C8C, 0x74534EE81D691D1D,
+        0xCBDB208BFB30FBFB, 0xDBC738ABFF24FFFF, 0x237DBC46C1E2C1C1, 0xF2398BF9B240B2B2,
+        0x66C255CC97F19797, 0xB8CAE46D2E962E2E, 0xC7D22A93F83FF8F8, 0x8926430F65EC6565,
+        0xFFF80EE3F609F6F6, 0xC956238F75BC7575, 0x1C151238071B0707, 0x101C182004140404,
+        0x39E2AB7249704949, 0xCC99AA8533FF3333, 0xB7866273E453E4E4, 0x4335EC86D99AD9D9,
+        0xDE08B1A1B967B9B9, 0x670ADACED0B7D0D0, 0x15D3912A42574242, 0x3B6FA876C7FCC7C7,
+        0xAD1975476CC16C6C, 0x7AD747F490EA9090, 0x0000000000000000, 0x028D03048E8C8E8E,
+        0xA1107F5F6FCE6F6F, 0x5DADFDBA500D5050, 0x0407060801050101, 0x3361A466C5F6C5C5,
+        0x4F3CE69EDA95DADA, 0x01C88F0247464747, 0xFCBD82E53FC33F3F, 0x13599426CDDECDCD,
+        0xB9026B6F69D06969, 0xB249EB79A210A2A2, 0xAF947643E24DE2E2, 0xF57B01F77A8F7A7A,
+        0xA652F551A701A7A7, 0x3F68AE7EC6F9C6C6, 0x76DE4DEC93E59393, 0x3C2D22780F330F0F,
+        0x28363C500A220A0A, 0x18121430061E0606, 0xBF886E63E659E6E6, 0xACD1FA452B872B2B,
+        0x62C553C496F49696, 0xB64EED71A315A3A3, 0x705448E01C6C1C1C, 0x866AC511AF29AFAF,
+        0xB50B61776ADF6A6A, 0x487E6C90125A1212, 0x2ABB3F5484AE8484, 0xE4AF96D539DD3939,
+        0xBB8F686BE75CE7E7, 0xFA3787E9B04AB0B0, 0x32A92B6482B08282, 0xFBFF08EBF70CF7F7,
+        0xDFC03EA3FE21FEFE, 0x4EF4699C9DD39D9D, 0x26B2354C87A18787, 0x6D89D5DA5C315C5C,
+        0x3EA0217C81BF8181, 0xD48BBEB535E13535, 0x5F20FEBEDE81DEDE, 0xEA2B9FC9B45EB4B4,
+        0xAE5CF941A50BA5A5, 0xD7CE32B3FC2BFCFC, 0x3AA7277480BA8080, 0x9BB7582BEF74EFEF,
+        0x0B4B8016CBC0CBCB, 0xD606BDB1BB6DBBBB, 0xB10C677F6BDA6B6B, 0xC55F299776B37676,
+        0xD201BBB9BA68BABA, 0x759BC1EA5A2F5A5A, 0xE96E13CF7D947D7D, 0xFD750DE778857878,
+        0x2C313A580B270B0B, 0x6ECC59DC95FB9595, 0xAB93704BE348E3E3, 0x8E64C901AD23ADAD,
+        0xCD51258774B97474, 0x5AEF77B498C29898, 0xECA19AC53BD73B3B, 0xD882B4AD36EE3636,
+        0x8D21450764E96464, 0xA91E734F6DC46D6D, 0x572EF2AEDC8BDCDC, 0xE7EA1AD3F017F0F0,
+        0x7992CBF259205959, 0x9E78D121A937A9A9, 0x2DF9B55A4C614C4C, 0x5C6572B8174B1717,
+        0xE1601FDF7F9E7F7F, 0x7ED041FC91EF9191, 0xDA0FB7A9B862B8B8, 0x03458C06C9CAC9C9,
+        0x41B8EF8257165757, 0x6C415AD81B771B1B, 0xA79A7A53E047E0E0, 0x993A5B2F61F86161,
+    ],
+];
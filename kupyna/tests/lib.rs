@@ -0,0 +1,24 @@
+//! Test messages including the examples of the Kupyna paper, the bytes
+//! 0x00 to 0x3f, 0x00 to 0x7f and the first 95 of them
+#![no_std]
+
+#[macro_use]
+extern crate digest;
+extern crate kupyna;
+
+use digest::dev::{digest_test, one_million_a};
+
+new_test!(kupyna256, "kupyna256", kupyna::Kupyna256, digest_test);
+new_test!(kupyna512, "kupyna512", kupyna::Kupyna512, digest_test);
+
+#[test]
+fn kupyna256_1million_a() {
+    let output = include_bytes!("data/kupyna256_one_million_a.bin");
+    one_million_a::<kupyna::Kupyna256>(&output[..]);
+}
+
+#[test]
+fn kupyna512_1million_a() {
+    let output = include_bytes!("data/kupyna512_one_million_a.bin");
+    one_million_a::<kupyna::Kupyna512>(&output[..]);
+}
@@ -0,0 +1,89 @@
+//! Constants of LSH
+//!
+//! The initialization vectors are the compression of an all-zero block into
+//! the chaining value whose first two words are the word size and the
+//! output size in bits, all other words being zero.
+
+/// Permutation of the message words in the message expansion
+pub const TAU: [usize; 16] = [3, 2, 0, 1, 7, 4, 5, 6, 11, 10, 8, 9, 15, 12, 13, 14];
+
+/// Permutation of the chaining words at the end of every step
+pub const SIGMA: [usize; 16] = [6, 4, 5, 7, 12, 15, 14, 13, 2, 0, 1, 3, 8, 11, 10, 9];
+
+/// First step constants of LSH-256
+pub const SC0_256: [u32; 8] = [
+    0x917caf90, 0x6c1b10a2, 0x6f352943, 0xcf778243,
+    0x2ceb7472, 0x29e96ff2, 0x8a9ba428, 0x2eeb2642,
+];
+
+/// First step constants of LSH-512
+pub const SC0_512: [u64; 8] = [
+    0x97884283c938982a, 0xba1fca93533e2355,
+    0xc519a2e87aeb1c03, 0x9a0fc95462af17b1,
+    0xfc3dda8ab019a82b, 0x02825d079a895407,
+    0x79f2d0a7ee06a6f7, 0xd76d15eed9fdf5fe,
+];
+
+/// Initialization vector of LSH-256-224
+pub const IV256_224: [u32; 16] = [
+    0x068608d3, 0x62d8f7a7, 0xd76652ab, 0x4c600a43,
+    0xbdc40aa8, 0x1eca0b68, 0xda1a89be, 0x3147d354,
+    0x707eb4f9, 0xf65b3862, 0x6b0b2abe, 0x56b8ec0a,
+    0xcf237286, 0xee0d1727, 0x33636595, 0x8bb8d05f,
+];
+
+/// Initialization vector of LSH-256-256
+pub const IV256_256: [u32; 16] = [
+    0x46a10f1f, 0xfddce486, 0xb41443a8, 0x198e6b9d,
+    0x3304388d, 0xb0f5a3c7, 0xb36061c4, 0x7adbd553,
+    0x105d5378, 0x2f74de54, 0x5c2f2d95, 0xf2553fbe,
+    0x8051357a, 0x138668c8, 0x47aa4484, 0xe01afb41,
+];
+
+/// Initialization vector of LSH-512-224
+pub const IV512_224: [u64; 16] = [
+    0x0c401e9fe8813a55, 0x4a5f446268fd3d35,
+    0xff13e452334f612a, 0xf8227661037e354a,
+    0xa5f223723c9ca29d, 0x95d965a11aed3979,
+    0x01e23835b9ab02cc, 0x52d49cbad5b30616,
+    0x9e5c2027773f4ed3, 0x66a5c8801925b701,
+    0x22bbc85b4c6779d9, 0xc13171a42c559c23,
+    0x31e2b67d25be3813, 0xd522c4deed8e4d83,
+    0xa79f5509b43fbafe, 0xe00d2cd88b4b6c6a,
+];
+
+/// Initialization vector of LSH-512-256
+pub const IV512_256: [u64; 16] = [
+    0x6dc57c33df989423, 0xd8ea7f6e8342c199,
+    0x76df8356f8603ac4, 0x40f1b44de838223a,
+    0x39ffe7cfc31484cd, 0x39c4326cc5281548,
+    0x8a2ff85a346045d8, 0xff202aa46dbdd61e,
+    0xcf785b3cd5fcdb8b, 0x1f0323b64a8150bf,
+    0xff75d972f29ea355, 0x2e567f30bf1ca9e1,
+    0xb596875bf8ff6dba, 0xfcca39b089ef4615,
+    0xecff4017d020b4b6, 0x7e77384c772ed802,
+];
+
+/// Initialization vector of LSH-512-384
+pub const IV512_384: [u64; 16] = [
+    0x53156a66292808f6, 0xb2c4f362b204c2bc,
+    0xb84b7213bfa05c4e, 0x976ceb7c1b299f73,
+    0xdf0cc63c0570ae97, 0xda4441baa486ce3f,
+    0x6559f5d9b5f2acc2, 0x22dacf19b4b52a16,
+    0xbbcdacefde80953a, 0xc9891a2879725b3e,
+    0x7c9fe6330237e440, 0xa30ba550553f7431,
+    0xbb08043fb34e3e30, 0xa0dec48d54618ead,
+    0x150317267464bc57, 0x32d1501fde63dc93,
+];
+
+/// Initialization vector of LSH-512-512
+pub const IV512_512: [u64; 16] = [
+    0xadd50f3c7f07094e, 0xe3f3cee8f9418a4f,
+    0xb527ecde5b3d0ae9, 0x2ef6dec68076f501,
+    0x8cb994cae5aca216, 0xfbb9eae4bba48cc7,
+    0x650a526174725fea, 0x1f9a61a73f8d8085,
+    0xb6607378173b539b, 0x1bc99853b0c0b9ed,
+    0xdf727fc19b182d47, 0xdbef360cf893a457,
+    0x4981f5e570147e80, 0xd00c4490ca7d3e30,
+    0x5d73940c0e4ae1ec, 0x894085e2edb2d819,
+];
@@ -0,0 +1,57 @@
+//! An implementation of the LSH family of cryptographic hash functions, the
+//! Korean standard KS X 3262 introduced in "LSH: A New Fast Secure Hash
+//! Function Family" (ICISC 2014).
+//!
+//! LSH-256 works on 32-bit words and 1024-bit blocks, LSH-512 on 64-bit
+//! words and 2048-bit blocks. The output size selects the initialization
+//! vector, so `Lsh512_256` is not a truncation of `Lsh512_512`. The
+//! supported combinations are `Lsh256_224`, `Lsh256_256`, `Lsh512_224`,
+//! `Lsh512_256`, `Lsh512_384` and `Lsh512_512`.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate lsh;
+//! # fn main() {
+//! use lsh::{Lsh256_256, Digest};
+//!
+//! // create a LSH-256-256 hasher instance
+//! let mut hasher = Lsh256_256::new();
+//!
+//! // process input message
+//! hasher.input(b"abc");
+//!
+//! // acquire hash digest in the form of GenericArray,
+//! // which in this case is equivalent to [u8; 32]
+//! let result = hasher.result();
+//! assert_eq!(result[..], hex!("
+//!     5fbf365daea5446a7053c52b57404d77a07a5f48a1f7c1963a0898ba1b714741
+//! ")[..]);
+//! # }
+//! ```
+//!
+//! Also see [RustCrypto/hashes][1] readme.
+//!
+//! [1]: https://github.com/RustCrypto/hashes
+#![no_std]
+#![doc(html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
+extern crate block_buffer;
+#[macro_use]
+extern crate opaque_debug;
+#[macro_use]
+pub extern crate digest;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub use digest::Digest;
+
+mod consts;
+#[macro_use]
+mod macros;
+
+mod lsh256;
+mod lsh512;
+
+pub use lsh256::{Lsh256_224, Lsh256_256};
+pub use lsh512::{Lsh512_224, Lsh512_256, Lsh512_384, Lsh512_512};
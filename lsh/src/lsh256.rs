@@ -0,0 +1,16 @@
+use core::mem::size_of;
+use block_buffer::BlockBuffer;
+use block_buffer::block_padding::Iso7816;
+use block_buffer::byteorder::{ByteOrder, LE};
+use digest::{BlockInput, FixedOutput, Input, Reset};
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::{Unsigned, U128, U28, U32};
+use consts::{IV256_224, IV256_256, SC0_256, SIGMA, TAU};
+
+define_compress!(u32, U128, 26, SC0_256, [(29, 1), (5, 17)],
+    [0, 8, 16, 24, 24, 16, 8, 0], read_u32_into);
+
+impl_lsh!(Lsh256_224, u32, U128, U28, IV256_224, write_u32_into,
+    "LSH-256-224 hasher state");
+impl_lsh!(Lsh256_256, u32, U128, U32, IV256_256, write_u32_into,
+    "LSH-256-256 hasher state");
@@ -0,0 +1,20 @@
+use core::mem::size_of;
+use block_buffer::BlockBuffer;
+use block_buffer::block_padding::Iso7816;
+use block_buffer::byteorder::{ByteOrder, LE};
+use digest::{BlockInput, FixedOutput, Input, Reset};
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::{Unsigned, U256, U28, U32, U48, U64};
+use consts::{IV512_224, IV512_256, IV512_384, IV512_512, SC0_512, SIGMA, TAU};
+
+define_compress!(u64, U256, 28, SC0_512, [(23, 59), (7, 3)],
+    [0, 16, 32, 48, 8, 24, 40, 56], read_u64_into);
+
+impl_lsh!(Lsh512_224, u64, U256, U28, IV512_224, write_u64_into,
+    "LSH-512-224 hasher state");
+impl_lsh!(Lsh512_256, u64, U256, U32, IV512_256, write_u64_into,
+    "LSH-512-256 hasher state");
+impl_lsh!(Lsh512_384, u64, U256, U48, IV512_384, write_u64_into,
+    "LSH-512-384 hasher state");
+impl_lsh!(Lsh512_512, u64, U256, U64, IV512_512, write_u64_into,
+    "LSH-512-512 hasher state");
@@ -0,0 +1,120 @@
+macro_rules! define_compress {
+    ($word:ty, $block:ty, $steps:expr, $sc0:expr, $rot:expr, $gamma:expr, $read:ident) => {
+        const STEPS: usize = $steps;
+
+        /// Add and rotate the left and right halves of the chaining value
+        #[inline(always)]
+        fn mix(t: &mut [$word; 16], sc: &[$word; 8], (alpha, beta): (u32, u32)) {
+            for l in 0..8 {
+                let (mut x, mut y) = (t[l], t[l + 8]);
+                x = x.wrapping_add(y).rotate_left(alpha) ^ sc[l];
+                y = y.wrapping_add(x).rotate_left(beta);
+                x = x.wrapping_add(y);
+                y = y.rotate_left($gamma[l]);
+                t[l] = x;
+                t[l + 8] = y;
+            }
+        }
+
+        fn compress(cv: &mut [$word; 16], block: &GenericArray<u8, $block>) {
+            let mut m = [0; 32];
+            LE::$read(block, &mut m);
+            // the sub-messages of the even and odd steps
+            let mut even = [0; 16];
+            let mut odd = [0; 16];
+            even.copy_from_slice(&m[..16]);
+            odd.copy_from_slice(&m[16..]);
+
+            let mut t = *cv;
+            // the step constants, each row is derived from the previous one
+            let mut sc: [$word; 8] = $sc0;
+            for j in 0..STEPS {
+                let (cur, next) = match j % 2 {
+                    0 => (&mut even, &odd),
+                    _ => (&mut odd, &even),
+                };
+                for l in 0..16 {
+                    t[l] ^= cur[l];
+                }
+                mix(&mut t, &sc, $rot[j % 2]);
+                for x in sc.iter_mut() {
+                    *x = x.wrapping_add(x.rotate_left(8));
+                }
+                let s = t;
+                for l in 0..16 {
+                    t[l] = s[SIGMA[l]];
+                }
+                // expand the sub-message of step j + 2 in place
+                let prev = *cur;
+                for l in 0..16 {
+                    cur[l] = next[l].wrapping_add(prev[TAU[l]]);
+                }
+            }
+            // the number of steps is even, the last sub-message is an even one
+            for l in 0..16 {
+                cv[l] = t[l] ^ even[l];
+            }
+        }
+    };
+}
+
+macro_rules! impl_lsh {
+    ($name:ident, $word:ty, $block:ty, $output:ty, $iv:expr, $write:ident, $doc:expr) => {
+        #[doc = $doc]
+        #[allow(non_camel_case_types)]
+        #[derive(Clone)]
+        pub struct $name {
+            cv: [$word; 16],
+            buffer: BlockBuffer<$block>,
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name { cv: $iv, buffer: Default::default() }
+            }
+        }
+
+        impl BlockInput for $name {
+            type BlockSize = $block;
+        }
+
+        impl Input for $name {
+            fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+                let cv = &mut self.cv;
+                self.buffer.input(input.as_ref(), |b| compress(cv, b));
+            }
+        }
+
+        impl FixedOutput for $name {
+            type OutputSize = $output;
+
+            fn fixed_result(mut self) -> GenericArray<u8, $output> {
+                // a single one bit followed by zeros up to a block boundary,
+                // the message length is not encoded
+                {
+                    let block = self.buffer.pad_with::<Iso7816>()
+                        .expect("we never use input_lazy");
+                    compress(&mut self.cv, block);
+                }
+                let mut h = [0; 8];
+                for l in 0..8 {
+                    h[l] = self.cv[l] ^ self.cv[l + 8];
+                }
+                // large enough for eight 64-bit words
+                let mut out = [0u8; 64];
+                LE::$write(&h, &mut out[..8 * size_of::<$word>()]);
+                GenericArray::clone_from_slice(&out[..<$output>::to_usize()])
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                self.cv = $iv;
+                self.buffer.reset();
+            }
+        }
+
+        impl_opaque_debug!($name);
+        impl_write!($name);
+    };
+}
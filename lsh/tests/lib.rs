@@ -0,0 +1,28 @@
+//! Test messages for all six variants, the digests of "abc" match the
+//! examples of the specification
+#![no_std]
+
+#[macro_use]
+extern crate digest;
+extern crate lsh;
+
+use digest::dev::{digest_test, one_million_a};
+
+new_test!(lsh256_224, "lsh256_224", lsh::Lsh256_224, digest_test);
+new_test!(lsh256_256, "lsh256_256", lsh::Lsh256_256, digest_test);
+new_test!(lsh512_224, "lsh512_224", lsh::Lsh512_224, digest_test);
+new_test!(lsh512_256, "lsh512_256", lsh::Lsh512_256, digest_test);
+new_test!(lsh512_384, "lsh512_384", lsh::Lsh512_384, digest_test);
+new_test!(lsh512_512, "lsh512_512", lsh::Lsh512_512, digest_test);
+
+#[test]
+fn lsh256_256_1million_a() {
+    let output = include_bytes!("data/lsh256_256_one_million_a.bin");
+    one_million_a::<lsh::Lsh256_256>(&output[..]);
+}
+
+#[test]
+fn lsh512_512_1million_a() {
+    let output = include_bytes!("data/lsh512_512_one_million_a.bin");
+    one_million_a::<lsh::Lsh512_512>(&output[..]);
+}
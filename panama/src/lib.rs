@@ -0,0 +1,106 @@
+//! An implementation of the [Panama][1] cryptographic hash function.
+//!
+//! Only the hash mode of Panama is provided, the stream cipher mode with
+//! its key and initialization vector is not. Collisions for Panama can be
+//! found with negligible effort, so it should only be used to verify
+//! existing digests.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate panama;
+//! # fn main() {
+//! use panama::{Panama, Digest};
+//!
+//! // create a Panama hasher instance
+//! let mut hasher = Panama::new();
+//!
+//! // process input message
+//! hasher.input(&b"The quick brown fox jumps over the lazy dog"[..]);
+//!
+//! // acquire hash digest in the form of GenericArray,
+//! // which in this case is equivalent to [u8; 32]
+//! let result = hasher.result();
+//! assert_eq!(result[..], hex!("
+//!     5f5ca355b90ac622b0aa7e654ef5f27e9e75111415b48b8afe3add1c6b89cba1
+//! ")[..]);
+//! # }
+//! ```
+//!
+//! Also see [RustCrypto/hashes][2] readme.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Panama_(cryptography)
+//! [2]: https://github.com/RustCrypto/hashes
+#![no_std]
+#![doc(html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
+extern crate block_buffer;
+#[macro_use]
+extern crate opaque_debug;
+#[macro_use]
+pub extern crate digest;
+#[cfg(feature = "std")]
+extern crate std;
+
+use block_buffer::block_padding::ZeroPadding;
+use block_buffer::byteorder::{ByteOrder, LE};
+use block_buffer::BlockBuffer;
+use digest::generic_array::typenum::U32;
+use digest::generic_array::GenericArray;
+pub use digest::Digest;
+use digest::{BlockInput, FixedOutput, Input, Reset};
+
+mod state;
+use state::State;
+
+/// Number of pull iterations after the message is pushed
+const BLANK_PULLS: usize = 32;
+
+/// Structure representing the state of a Panama computation
+#[derive(Clone, Default)]
+pub struct Panama {
+    state: State,
+    buffer: BlockBuffer<U32>,
+}
+
+impl BlockInput for Panama {
+    type BlockSize = U32;
+}
+
+impl Input for Panama {
+    fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+        let state = &mut self.state;
+        self.buffer.input(input.as_ref(), |b| state.push(b));
+    }
+}
+
+impl FixedOutput for Panama {
+    type OutputSize = U32;
+
+    fn fixed_result(mut self) -> GenericArray<u8, U32> {
+        // a single one bit followed by zeros up to a block boundary
+        let pos = self.buffer.position();
+        {
+            let block = self.buffer.pad_with::<ZeroPadding>().unwrap();
+            block[pos] = 0x01;
+            self.state.push(block);
+        }
+        for _ in 0..BLANK_PULLS {
+            self.state.pull();
+        }
+
+        let mut out = GenericArray::default();
+        LE::write_u32_into(self.state.output(), &mut out);
+        out
+    }
+}
+
+impl Reset for Panama {
+    fn reset(&mut self) {
+        self.state = Default::default();
+        self.buffer.reset();
+    }
+}
+
+impl_opaque_debug!(Panama);
+impl_write!(Panama);
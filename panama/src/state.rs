@@ -0,0 +1,78 @@
+//! The Panama state and its push and pull iterations
+use block_buffer::byteorder::{ByteOrder, LE};
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::U32;
+
+pub type Block = GenericArray<u8, U32>;
+
+const STATE_LEN: usize = 17;
+const STAGES: usize = 32;
+
+#[derive(Clone, Default)]
+pub struct State {
+    a: [u32; STATE_LEN],
+    // the linear feedback shift register of 32 stages of 8 words
+    b: [[u32; 8]; STAGES],
+}
+
+impl State {
+    /// The nonlinear state transformation without the final injection,
+    /// i.e. theta(pi(gamma(a)))
+    fn transform(&self) -> [u32; STATE_LEN] {
+        let a = &self.a;
+        let mut g = [0u32; STATE_LEN];
+        for i in 0..STATE_LEN {
+            g[i] = a[i] ^ (a[(i + 1) % STATE_LEN] | !a[(i + 2) % STATE_LEN]);
+        }
+        let mut p = [0u32; STATE_LEN];
+        for i in 0..STATE_LEN {
+            let r = (i * (i + 1) / 2) % 32;
+            p[i] = g[(7 * i) % STATE_LEN].rotate_left(r as u32);
+        }
+        let mut t = [0u32; STATE_LEN];
+        for i in 0..STATE_LEN {
+            t[i] = p[i] ^ p[(i + 1) % STATE_LEN] ^ p[(i + 4) % STATE_LEN];
+        }
+        t
+    }
+
+    /// One iteration with the input `p` to the state, and `q` to the buffer
+    fn iterate(&mut self, p: &[u32; 8], q: &[u32; 8]) {
+        let mut a = self.transform();
+        a[0] ^= 1;
+        for i in 0..8 {
+            a[1 + i] ^= p[i];
+            a[9 + i] ^= self.b[16][i];
+        }
+
+        let last = self.b[STAGES - 1];
+        for j in (1..STAGES).rev() {
+            self.b[j] = self.b[j - 1];
+        }
+        for i in 0..8 {
+            self.b[0][i] = last[i] ^ q[i];
+            self.b[25][i] ^= last[(i + 2) % 8];
+        }
+        self.a = a;
+    }
+
+    /// Push a block of the message
+    pub fn push(&mut self, block: &Block) {
+        let mut p = [0u32; 8];
+        LE::read_u32_into(block, &mut p);
+        self.iterate(&p, &p);
+    }
+
+    /// Pull iteration without output
+    pub fn pull(&mut self) {
+        let mut q = [0u32; 8];
+        q.copy_from_slice(&self.a[1..9]);
+        let p = self.b[4];
+        self.iterate(&p, &q);
+    }
+
+    /// The output words of the state
+    pub fn output(&self) -> &[u32] {
+        &self.a[9..]
+    }
+}
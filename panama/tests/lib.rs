@@ -0,0 +1,18 @@
+//! Test messages for the hash mode of Panama, the digests of the empty
+//! string and of "The quick brown fox jumps over the lazy dog" are the
+//! published ones
+#![no_std]
+
+#[macro_use]
+extern crate digest;
+extern crate panama;
+
+use digest::dev::{digest_test, one_million_a};
+
+new_test!(panama_main, "panama", panama::Panama, digest_test);
+
+#[test]
+fn panama_1million_a() {
+    let output = include_bytes!("data/one_million_a.bin");
+    one_million_a::<panama::Panama>(&output[..]);
+}
@@ -0,0 +1,52 @@
+extern crate radiogatun;
+
+use radiogatun::RadioGatun32;
+use radiogatun::digest::{Input, ExtendableOutput, XofReader};
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+
+const BUFFER_SIZE: usize = 1024;
+
+/// Print digest result as hex string and name pair
+fn print_result(sum: &[u8], name: &str) {
+    for byte in sum {
+        print!("{:02x}", byte);
+    }
+    println!("\t{}", name);
+}
+
+/// Compute the 256-bit digest for given `Reader` and print it
+/// On any error simply return without doing anything
+fn process<R: Read>(reader: &mut R, name: &str) {
+    let mut sh = RadioGatun32::default();
+    let mut buffer = [0u8; BUFFER_SIZE];
+    loop {
+        let n = match reader.read(&mut buffer) {
+            Ok(n) => n,
+            Err(_) => return,
+        };
+        sh.input(&buffer[..n]);
+        if n == 0 || n < BUFFER_SIZE {
+            break;
+        }
+    }
+    let mut sum = [0u8; 32];
+    XofReader::read(&mut sh.xof_result(), &mut sum);
+    print_result(&sum, name);
+}
+
+fn main() {
+    let args = env::args();
+    // Process files listed in command line arguments one by one
+    // If no files provided process input from stdin
+    if args.len() > 1 {
+        for path in args.skip(1) {
+            if let Ok(mut file) = fs::File::open(&path) {
+                process(&mut file, &path);
+            }
+        }
+    } else {
+        process(&mut io::stdin(), "-");
+    }
+}
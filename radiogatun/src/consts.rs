@@ -0,0 +1,5 @@
+pub const MILL_LEN: usize = 19;
+pub const BELT_LEN: usize = 13;
+
+/// Number of rounds without input or output between absorbing and squeezing
+pub const BLANK_ROUNDS: usize = 16;
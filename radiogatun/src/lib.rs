@@ -0,0 +1,56 @@
+//! An implementation of the [RadioGatún][1] cryptographic hash functions.
+//!
+//! RadioGatún is the belt-and-mill design preceding Keccak, with an
+//! arbitrary output length. The 32-bit and 64-bit word variants are
+//! provided as `RadioGatun32` and `RadioGatun64`, both implementing the
+//! `ExtendableOutput` trait. The published digests are the first 256 bits
+//! of the output.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate radiogatun;
+//! # fn main() {
+//! use radiogatun::RadioGatun32;
+//! use radiogatun::digest::{Input, ExtendableOutput, XofReader};
+//!
+//! // create a RadioGatún[32] hasher instance
+//! let mut hasher = RadioGatun32::default();
+//!
+//! // process input message
+//! hasher.input(&b"The quick brown fox jumps over the lazy dog"[..]);
+//!
+//! // read the first 256 bits of output
+//! let mut reader = hasher.xof_result();
+//! let mut result = [0u8; 32];
+//! reader.read(&mut result);
+//! assert_eq!(result[..], hex!("
+//!     191589005fec1f2a248f96a16e9553bf38d0aee1648ffa036655ce29c2e229ae
+//! ")[..]);
+//! # }
+//! ```
+//!
+//! Also see [RustCrypto/hashes][2] readme.
+//!
+//! [1]: https://en.wikipedia.org/wiki/RadioGat%C3%BAn
+//! [2]: https://github.com/RustCrypto/hashes
+#![no_std]
+#![doc(html_logo_url =
+    "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
+extern crate block_buffer;
+#[macro_use] extern crate opaque_debug;
+#[macro_use] pub extern crate digest;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod consts;
+
+#[macro_use]
+mod macros;
+
+mod radiogatun32;
+mod radiogatun64;
+
+pub use radiogatun32::{RadioGatun32, RadioGatun32Reader};
+pub use radiogatun64::{RadioGatun64, RadioGatun64Reader};
@@ -0,0 +1,156 @@
+macro_rules! define_radiogatun {
+    (
+        $state:ident, $reader:ident, $word:ty, $word_len:expr, $block:ty,
+        $read:ident, $write:ident,
+        $doc:expr, $readerdoc:expr
+    ) => {
+        use digest::{Input, BlockInput, ExtendableOutput, XofReader, Reset};
+        use digest::generic_array::GenericArray;
+        use block_buffer::BlockBuffer;
+        use block_buffer::block_padding::ZeroPadding;
+        use block_buffer::byteorder::{ByteOrder, LE};
+        use consts::{MILL_LEN, BELT_LEN, BLANK_ROUNDS};
+        #[cfg(feature = "std")]
+        use std::io;
+
+        /// Size of a word in bytes
+        const WORD_LEN: usize = $word_len;
+
+        #[derive(Clone, Default)]
+        struct Belt {
+            mill: [$word; MILL_LEN],
+            belt: [[$word; 3]; BELT_LEN],
+        }
+
+        impl Belt {
+            /// The round function, the belt and mill feedforwards use the
+            /// values before the round
+            fn round(&mut self) {
+                let last = self.belt[BELT_LEN - 1];
+                for i in (1..BELT_LEN).rev() {
+                    self.belt[i] = self.belt[i - 1];
+                }
+                self.belt[0] = last;
+                for i in 0..12 {
+                    self.belt[i + 1][i % 3] ^= self.mill[i + 1];
+                }
+                self.mill();
+                for i in 0..3 {
+                    self.mill[i + 13] ^= last[i];
+                }
+            }
+
+            fn mill(&mut self) {
+                let a = &mut self.mill;
+                // gamma: nonlinearity
+                let mut t = [0; MILL_LEN];
+                for i in 0..MILL_LEN {
+                    t[i] = a[i] ^ (a[(i + 1) % MILL_LEN] | !a[(i + 2) % MILL_LEN]);
+                }
+                // pi: intra-word and inter-word dispersion
+                let mut p = [0; MILL_LEN];
+                for i in 0..MILL_LEN {
+                    let r = (i * (i + 1) / 2) % (8 * WORD_LEN);
+                    p[i] = t[(7 * i) % MILL_LEN].rotate_right(r as u32);
+                }
+                // theta: diffusion
+                for i in 0..MILL_LEN {
+                    a[i] = p[i] ^ p[(i + 1) % MILL_LEN] ^ p[(i + 4) % MILL_LEN];
+                }
+                // iota: asymmetry
+                a[0] ^= 1;
+            }
+
+            fn absorb(&mut self, block: &GenericArray<u8, $block>) {
+                let mut p = [0; 3];
+                LE::$read(block, &mut p);
+                for i in 0..3 {
+                    self.belt[0][i] ^= p[i];
+                    self.mill[i + 16] ^= p[i];
+                }
+                self.round();
+            }
+        }
+
+        #[doc = $doc]
+        #[derive(Clone, Default)]
+        pub struct $state {
+            state: Belt,
+            buffer: BlockBuffer<$block>,
+        }
+
+        impl BlockInput for $state {
+            type BlockSize = $block;
+        }
+
+        impl Input for $state {
+            fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+                let state = &mut self.state;
+                self.buffer.input(input.as_ref(), |b| state.absorb(b));
+            }
+        }
+
+        impl ExtendableOutput for $state {
+            type Reader = $reader;
+
+            fn xof_result(mut self) -> $reader {
+                // a single one bit followed by zeros up to a block boundary
+                let pos = self.buffer.position();
+                {
+                    let block = self.buffer.pad_with::<ZeroPadding>().unwrap();
+                    block[pos] = 0x01;
+                    self.state.absorb(block);
+                }
+                for _ in 0..BLANK_ROUNDS {
+                    self.state.round();
+                }
+                $reader { state: self.state, out: [0; 2 * WORD_LEN], pos: 2 * WORD_LEN }
+            }
+        }
+
+        impl Reset for $state {
+            fn reset(&mut self) {
+                self.state = Default::default();
+                self.buffer.reset();
+            }
+        }
+
+        impl_opaque_debug!($state);
+        impl_write!($state);
+
+        #[doc = $readerdoc]
+        #[derive(Clone)]
+        pub struct $reader {
+            state: Belt,
+            out: [u8; 2 * WORD_LEN],
+            pos: usize,
+        }
+
+        impl XofReader for $reader {
+            fn read(&mut self, buffer: &mut [u8]) {
+                for byte in buffer.iter_mut() {
+                    if self.pos == self.out.len() {
+                        // every round outputs the mill words 1 and 2
+                        self.state.round();
+                        let (a, b) = self.out.split_at_mut(WORD_LEN);
+                        LE::$write(a, self.state.mill[1]);
+                        LE::$write(b, self.state.mill[2]);
+                        self.pos = 0;
+                    }
+                    *byte = self.out[self.pos];
+                    self.pos += 1;
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl io::Read for $reader {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                <Self as XofReader>::read(self, buf);
+                Ok(buf.len())
+            }
+        }
+
+        impl_opaque_debug!($reader);
+    };
+}
@@ -0,0 +1,6 @@
+use digest::generic_array::typenum::U12;
+
+define_radiogatun!(RadioGatun32, RadioGatun32Reader, u32, 4, U12,
+    read_u32_into, write_u32,
+    "RadioGatún with 32-bit words.",
+    "Reader for the output of RadioGatún with 32-bit words.");
@@ -0,0 +1,6 @@
+use digest::generic_array::typenum::U24;
+
+define_radiogatun!(RadioGatun64, RadioGatun64Reader, u64, 8, U24,
+    read_u64_into, write_u64,
+    "RadioGatún with 64-bit words.",
+    "Reader for the output of RadioGatún with 64-bit words.");
@@ -0,0 +1,12 @@
+//! Test messages for both word sizes, the 256-bit digests of the empty
+//! string and of "The quick brown fox jumps over the lazy dog" are the
+//! published ones
+#![no_std]
+#[macro_use]
+extern crate digest;
+extern crate radiogatun;
+
+use digest::dev::xof_test;
+
+new_test!(radiogatun32, "radiogatun32", radiogatun::RadioGatun32, xof_test);
+new_test!(radiogatun64, "radiogatun64", radiogatun::RadioGatun64, xof_test);
@@ -0,0 +1,10 @@
+// UBI tweak flags and block types
+pub const FIRST: u64 = 1 << 62;
+pub const FINAL: u64 = 1 << 63;
+pub const CFG: u64 = 4 << 56;
+pub const MSG: u64 = 48 << 56;
+pub const OUT: u64 = 63 << 56;
+
+/// "SHA3" in ASCII, the schema identifier of the configuration block
+pub const SCHEMA: u32 = 0x3341_4853;
+pub const VERSION: u16 = 1;
@@ -0,0 +1,77 @@
+//! An implementation of the [Skein][1] family of cryptographic hash
+//! functions, the SHA-3 finalist built on the Threefish block cipher.
+//!
+//! Skein-256, Skein-512 and Skein-1024 are provided in the simple hashing
+//! configuration of version 1.3 of the specification, i.e. without key,
+//! personalization or tree hashing. `Skein256`, `Skein512` and `Skein1024`
+//! produce the output size equal to their state size, `VarSkein256`,
+//! `VarSkein512` and `VarSkein1024` implement the `VariableOutput` trait for
+//! any output size up to the state size. As the output size is part of the
+//! configuration, e.g. Skein-512-256 is not a truncation of Skein-512-512.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate skein;
+//! # fn main() {
+//! use skein::{Skein512, Digest};
+//!
+//! // create a Skein-512 hasher instance
+//! let mut hasher = Skein512::new();
+//!
+//! // process input message
+//! hasher.input(b"abc");
+//!
+//! // acquire hash digest in the form of GenericArray,
+//! // which in this case is equivalent to [u8; 64]
+//! let result = hasher.result();
+//! assert_eq!(result[..], hex!("
+//!     8f5dd9ec798152668e35129496b029a960c9a9b88662f7f9482f110b31f9f938
+//!     93ecfb25c009baad9e46737197d5630379816a886aa05526d3a70df272d96e75
+//! ")[..]);
+//! # }
+//! ```
+//!
+//! Other output sizes are available through the `VariableOutput` trait:
+//!
+//! ```rust
+//! # extern crate skein;
+//! # fn main() {
+//! use skein::VarSkein512;
+//! use skein::digest::{Input, VariableOutput};
+//!
+//! // create a Skein-512 hasher instance with 256-bit output
+//! let mut hasher = VarSkein512::new(32).unwrap();
+//! hasher.input(b"abc");
+//! hasher.variable_result(|res| assert_eq!(res.len(), 32));
+//! # }
+//! ```
+//!
+//! Also see [RustCrypto/hashes][2] readme.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Skein_(hash_function)
+//! [2]: https://github.com/RustCrypto/hashes
+#![no_std]
+#![doc(html_logo_url =
+    "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
+extern crate block_buffer;
+#[macro_use] extern crate opaque_debug;
+#[macro_use] pub extern crate digest;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod consts;
+mod threefish;
+
+#[macro_use]
+mod skein;
+
+mod skein256;
+mod skein512;
+mod skein1024;
+
+pub use digest::Digest;
+pub use skein256::{Skein256, VarSkein256};
+pub use skein512::{Skein512, VarSkein512};
+pub use skein1024::{Skein1024, VarSkein1024};
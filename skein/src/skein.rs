@@ -0,0 +1,158 @@
+macro_rules! define_skein {
+    (
+        $var:ident, $fixed:ident, $threefish:ident, $n:expr, $block:ty,
+        $vardoc:expr, $doc:expr
+    ) => {
+        use digest::{Input, BlockInput, FixedOutput, VariableOutput, Reset};
+        use digest::InvalidOutputSize;
+        use digest::generic_array::GenericArray;
+        use block_buffer::BlockBuffer;
+        use block_buffer::block_padding::ZeroPadding;
+        use block_buffer::byteorder::{ByteOrder, LE};
+        use threefish::$threefish;
+        use consts::{CFG, MSG, OUT, FIRST, FINAL, SCHEMA, VERSION};
+
+        type Block = GenericArray<u8, $block>;
+
+        /// Process `block` with Unique Block Iteration, `pos` is the number
+        /// of bytes processed including this block
+        fn ubi(h: &mut [u64; $n], block: &[u8], pos: u64, flags: u64) {
+            let mut m = [0u64; $n];
+            LE::read_u64_into(block, &mut m);
+            let mut v = m;
+            $threefish(h, [pos, flags], &mut v);
+            for ((h, v), m) in h.iter_mut().zip(v.iter()).zip(m.iter()) {
+                *h = v ^ m;
+            }
+        }
+
+        #[derive(Clone)]
+        #[doc=$vardoc]
+        pub struct $var {
+            h: [u64; $n],
+            // number of message bytes processed, without the buffer
+            pos: u64,
+            buffer: BlockBuffer<$block>,
+            n: usize,
+            h0: [u64; $n],
+        }
+
+        impl $var {
+            fn with_size(output_size: usize) -> Self {
+                let mut cfg = Block::default();
+                LE::write_u32(&mut cfg[..4], SCHEMA);
+                LE::write_u16(&mut cfg[4..6], VERSION);
+                LE::write_u64(&mut cfg[8..16], (output_size as u64) << 3);
+                let mut h = [0u64; $n];
+                ubi(&mut h, &cfg, 32, CFG | FIRST | FINAL);
+                $var { h, pos: 0, buffer: Default::default(), n: output_size, h0: h }
+            }
+
+            fn finalize(mut self, out: &mut [u8]) {
+                let pos = self.pos + self.buffer.position() as u64;
+                let flags = if self.pos == 0 { MSG | FIRST | FINAL } else { MSG | FINAL };
+                let block = self.buffer.pad_with::<ZeroPadding>().unwrap();
+                ubi(&mut self.h, block, pos, flags);
+
+                // output_size never exceeds the state size, so a single
+                // output block with counter zero suffices
+                let mut h = self.h;
+                ubi(&mut h, &Block::default(), 8, OUT | FIRST | FINAL);
+                let mut res = Block::default();
+                LE::write_u64_into(&h, &mut res);
+                let n = out.len();
+                out.copy_from_slice(&res[..n]);
+            }
+        }
+
+        impl BlockInput for $var {
+            type BlockSize = $block;
+        }
+
+        impl Input for $var {
+            fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+                let (h, pos) = (&mut self.h, &mut self.pos);
+                // the last block is processed by finalize with the final
+                // flag set, so blocks are only compressed once more input
+                // arrives
+                self.buffer.input_lazy(input.as_ref(), |b| {
+                    let flags = if *pos == 0 { MSG | FIRST } else { MSG };
+                    *pos += b.len() as u64;
+                    ubi(h, b, *pos, flags);
+                });
+            }
+        }
+
+        impl VariableOutput for $var {
+            fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+                if output_size == 0 || output_size > 8 * $n {
+                    return Err(InvalidOutputSize);
+                }
+                Ok(Self::with_size(output_size))
+            }
+
+            fn output_size(&self) -> usize {
+                self.n
+            }
+
+            fn variable_result<F: FnOnce(&[u8])>(self, f: F) {
+                let mut res = Block::default();
+                let n = self.n;
+                self.finalize(&mut res[..n]);
+                f(&res[..n]);
+            }
+        }
+
+        impl Reset for $var {
+            fn reset(&mut self) {
+                self.h = self.h0;
+                self.pos = 0;
+                self.buffer.reset();
+            }
+        }
+
+        impl_opaque_debug!($var);
+        impl_write!($var);
+
+        #[derive(Clone)]
+        #[doc=$doc]
+        pub struct $fixed {
+            state: $var,
+        }
+
+        impl Default for $fixed {
+            fn default() -> Self {
+                $fixed { state: $var::with_size(8 * $n) }
+            }
+        }
+
+        impl BlockInput for $fixed {
+            type BlockSize = $block;
+        }
+
+        impl Input for $fixed {
+            fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+                self.state.input(input);
+            }
+        }
+
+        impl FixedOutput for $fixed {
+            type OutputSize = $block;
+
+            fn fixed_result(self) -> Block {
+                let mut res = Block::default();
+                self.state.finalize(&mut res);
+                res
+            }
+        }
+
+        impl Reset for $fixed {
+            fn reset(&mut self) {
+                self.state.reset();
+            }
+        }
+
+        impl_opaque_debug!($fixed);
+        impl_write!($fixed);
+    };
+}
@@ -0,0 +1,5 @@
+use digest::generic_array::typenum::U128;
+
+define_skein!(VarSkein1024, Skein1024, threefish1024, 16, U128,
+    "Skein-1024 instance with a variable output.",
+    "Skein-1024 instance with a fixed output.");
@@ -0,0 +1,5 @@
+use digest::generic_array::typenum::U32;
+
+define_skein!(VarSkein256, Skein256, threefish256, 4, U32,
+    "Skein-256 instance with a variable output.",
+    "Skein-256 instance with a fixed output.");
@@ -0,0 +1,5 @@
+use digest::generic_array::typenum::U64;
+
+define_skein!(VarSkein512, Skein512, threefish512, 8, U64,
+    "Skein-512 instance with a variable output.",
+    "Skein-512 instance with a fixed output.");
@@ -0,0 +1,64 @@
+//! The Threefish block ciphers, encryption only
+
+/// Key schedule parity constant of Skein 1.3
+const C240: u64 = 0x1bd1_1bda_a9fc_1a22;
+
+macro_rules! define_threefish {
+    ($name:ident, $n:expr, $rounds:expr, $rot:expr, $perm:expr) => {
+        /// Encrypt `v` in place with the given key and tweak
+        pub fn $name(key: &[u64; $n], tweak: [u64; 2], v: &mut [u64; $n]) {
+            const ROT: [[u32; $n / 2]; 8] = $rot;
+            const PERM: [usize; $n] = $perm;
+
+            let mut k = [0u64; $n + 1];
+            k[..$n].copy_from_slice(key);
+            k[$n] = key.iter().fold(C240, |acc, w| acc ^ w);
+            let t = [tweak[0], tweak[1], tweak[0] ^ tweak[1]];
+
+            let inject = |v: &mut [u64; $n], s: usize| {
+                for (i, w) in v.iter_mut().enumerate() {
+                    *w = w.wrapping_add(k[(s + i) % ($n + 1)]);
+                }
+                v[$n - 3] = v[$n - 3].wrapping_add(t[s % 3]);
+                v[$n - 2] = v[$n - 2].wrapping_add(t[(s + 1) % 3]);
+                v[$n - 1] = v[$n - 1].wrapping_add(s as u64);
+            };
+
+            for d in 0..$rounds {
+                if d % 4 == 0 {
+                    inject(v, d / 4);
+                }
+                for (j, r) in ROT[d % 8].iter().enumerate() {
+                    v[2 * j] = v[2 * j].wrapping_add(v[2 * j + 1]);
+                    v[2 * j + 1] = v[2 * j + 1].rotate_left(*r) ^ v[2 * j];
+                }
+                let f = *v;
+                for (w, &p) in v.iter_mut().zip(PERM.iter()) {
+                    *w = f[p];
+                }
+            }
+            inject(v, $rounds / 4);
+        }
+    };
+}
+
+define_threefish!(threefish256, 4, 72, [
+    [14, 16], [52, 57], [23, 40], [5, 37],
+    [25, 33], [46, 12], [58, 22], [32, 32],
+], [0, 3, 2, 1]);
+
+define_threefish!(threefish512, 8, 72, [
+    [46, 36, 19, 37], [33, 27, 14, 42], [17, 49, 36, 39], [44, 9, 54, 56],
+    [39, 30, 34, 24], [13, 50, 10, 17], [25, 29, 39, 43], [8, 35, 56, 22],
+], [2, 1, 4, 7, 6, 5, 0, 3]);
+
+define_threefish!(threefish1024, 16, 80, [
+    [24, 13, 8, 47, 8, 17, 22, 37],
+    [38, 19, 10, 55, 49, 18, 23, 52],
+    [33, 4, 51, 13, 34, 41, 59, 17],
+    [5, 20, 48, 41, 47, 28, 16, 25],
+    [41, 9, 37, 31, 12, 47, 44, 30],
+    [16, 34, 56, 51, 4, 53, 42, 41],
+    [31, 44, 47, 46, 19, 42, 44, 25],
+    [9, 48, 35, 52, 23, 31, 37, 20],
+], [0, 9, 2, 13, 6, 11, 4, 15, 10, 7, 12, 3, 14, 5, 8, 1]);
@@ -0,0 +1,15 @@
+//! Test messages for the fixed and variable output sizes, the digests were
+//! computed with the `skein` crate of RustCrypto
+#![no_std]
+#[macro_use]
+extern crate digest;
+extern crate skein;
+
+use digest::dev::{digest_test, variable_test};
+
+new_test!(skein256_fixed, "skein256/fixed", skein::Skein256, digest_test);
+new_test!(skein256_variable, "skein256/variable", skein::VarSkein256, variable_test);
+new_test!(skein512_fixed, "skein512/fixed", skein::Skein512, digest_test);
+new_test!(skein512_variable, "skein512/variable", skein::VarSkein512, variable_test);
+new_test!(skein1024_fixed, "skein1024/fixed", skein::Skein1024, digest_test);
+new_test!(skein1024_variable, "skein1024/variable", skein::VarSkein1024, variable_test);
@@ -0,0 +1,72 @@
+use block_buffer::byteorder::{ByteOrder, BE};
+use digest::generic_array::typenum::U64;
+use digest::generic_array::GenericArray;
+
+pub const DIGEST_BUF_LEN: usize = 8;
+
+pub const H0: [u32; DIGEST_BUF_LEN] = [
+    0x7380_166f,
+    0x4914_b2b9,
+    0x1724_42d7,
+    0xda8a_0600,
+    0xa96f_30bc,
+    0x1631_38aa,
+    0xe38d_ee4d,
+    0xb0fb_0e4e,
+];
+
+const T0: u32 = 0x79cc_4519;
+const T1: u32 = 0x7a87_9d8a;
+
+type Block = GenericArray<u8, U64>;
+
+#[inline(always)]
+fn p0(x: u32) -> u32 {
+    x ^ x.rotate_left(9) ^ x.rotate_left(17)
+}
+
+#[inline(always)]
+fn p1(x: u32) -> u32 {
+    x ^ x.rotate_left(15) ^ x.rotate_left(23)
+}
+
+pub fn process_msg_block(h: &mut [u32; DIGEST_BUF_LEN], block: &Block) {
+    // message expansion
+    let mut w = [0u32; 68];
+    BE::read_u32_into(block, &mut w[..16]);
+    for j in 16..68 {
+        w[j] = p1(w[j - 16] ^ w[j - 9] ^ w[j - 3].rotate_left(15))
+            ^ w[j - 13].rotate_left(7)
+            ^ w[j - 6];
+    }
+
+    let (mut a, mut b, mut c, mut d) = (h[0], h[1], h[2], h[3]);
+    let (mut e, mut f, mut g, mut hh) = (h[4], h[5], h[6], h[7]);
+    for j in 0..64 {
+        let t = if j < 16 { T0 } else { T1 };
+        let ss1 = a.rotate_left(12)
+            .wrapping_add(e)
+            .wrapping_add(t.rotate_left(j as u32 % 32))
+            .rotate_left(7);
+        let ss2 = ss1 ^ a.rotate_left(12);
+        let (ff, gg) = if j < 16 {
+            (a ^ b ^ c, e ^ f ^ g)
+        } else {
+            ((a & b) | (a & c) | (b & c), (e & f) | (!e & g))
+        };
+        let tt1 = ff.wrapping_add(d).wrapping_add(ss2).wrapping_add(w[j] ^ w[j + 4]);
+        let tt2 = gg.wrapping_add(hh).wrapping_add(ss1).wrapping_add(w[j]);
+        d = c;
+        c = b.rotate_left(9);
+        b = a;
+        a = tt1;
+        hh = g;
+        g = f.rotate_left(19);
+        f = e;
+        e = p0(tt2);
+    }
+
+    for (h, &v) in h.iter_mut().zip([a, b, c, d, e, f, g, hh].iter()) {
+        *h ^= v;
+    }
+}
@@ -0,0 +1,109 @@
+//! An implementation of the [SM3][1] cryptographic hash function of the
+//! Chinese national standard GB/T 32905-2016.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate sm3;
+//! # fn main() {
+//! use sm3::{Sm3, Digest};
+//!
+//! // create a SM3 hasher instance
+//! let mut hasher = Sm3::new();
+//!
+//! // process input message
+//! hasher.input(b"abc");
+//!
+//! // acquire hash digest in the form of GenericArray,
+//! // which in this case is equivalent to [u8; 32]
+//! let result = hasher.result();
+//! assert_eq!(result[..], hex!("
+//!     66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0
+//! ")[..]);
+//! # }
+//! ```
+//!
+//! Also see [RustCrypto/hashes][2] readme.
+//!
+//! [1]: https://en.wikipedia.org/wiki/SM3_(hash_function)
+//! [2]: https://github.com/RustCrypto/hashes
+#![no_std]
+#![doc(html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
+extern crate block_buffer;
+#[macro_use]
+extern crate opaque_debug;
+#[macro_use]
+pub extern crate digest;
+#[cfg(feature = "std")]
+extern crate std;
+
+use block_buffer::byteorder::{ByteOrder, BE};
+use block_buffer::BlockBuffer;
+use digest::generic_array::typenum::{U32, U64};
+use digest::generic_array::GenericArray;
+pub use digest::Digest;
+use digest::{BlockInput, FixedOutput, Input, Reset};
+
+mod block;
+use block::{process_msg_block, DIGEST_BUF_LEN, H0};
+
+/// Structure representing the state of a SM3 computation
+#[derive(Clone)]
+pub struct Sm3 {
+    h: [u32; DIGEST_BUF_LEN],
+    len: u64,
+    buffer: BlockBuffer<U64>,
+}
+
+impl Default for Sm3 {
+    fn default() -> Self {
+        Sm3 {
+            h: H0,
+            len: 0,
+            buffer: Default::default(),
+        }
+    }
+}
+
+impl BlockInput for Sm3 {
+    type BlockSize = U64;
+}
+
+impl Input for Sm3 {
+    fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+        let input = input.as_ref();
+        // Assumes that input.len() can be converted to u64 without overflow
+        self.len += input.len() as u64;
+        let h = &mut self.h;
+        self.buffer.input(input, |b| process_msg_block(h, b));
+    }
+}
+
+impl FixedOutput for Sm3 {
+    type OutputSize = U32;
+
+    fn fixed_result(mut self) -> GenericArray<u8, Self::OutputSize> {
+        {
+            let h = &mut self.h;
+            let l = self.len << 3;
+            self.buffer
+                .len64_padding::<BE, _>(l, |b| process_msg_block(h, b));
+        }
+
+        let mut out = GenericArray::default();
+        BE::write_u32_into(&self.h, &mut out[..]);
+        out
+    }
+}
+
+impl Reset for Sm3 {
+    fn reset(&mut self) {
+        self.buffer.reset();
+        self.len = 0;
+        self.h = H0;
+    }
+}
+
+impl_opaque_debug!(Sm3);
+impl_write!(Sm3);
@@ -0,0 +1,17 @@
+//! Test messages including the two examples of GB/T 32905-2016, "abc" and
+//! "abcd" repeated 16 times
+#![no_std]
+
+#[macro_use]
+extern crate digest;
+extern crate sm3;
+
+use digest::dev::{digest_test, one_million_a};
+
+new_test!(sm3_main, "sm3", sm3::Sm3, digest_test);
+
+#[test]
+fn sm3_1million_a() {
+    let output = include_bytes!("data/one_million_a.bin");
+    one_million_a::<sm3::Sm3>(&output[..]);
+}
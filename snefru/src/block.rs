@@ -0,0 +1,36 @@
+//! The Snefru compression function
+use block_buffer::byteorder::{ByteOrder, BE};
+use consts::SBOXES;
+
+/// Number of passes, the eight recommended by Merkle after the attacks on
+/// the original two and four pass versions
+pub const PASSES: usize = 8;
+
+const SHIFTS: [u32; 4] = [16, 8, 16, 24];
+
+/// Compress the first `words` words of `state` together with `block`, which
+/// holds the remaining `16 - words` words of the 512-bit input
+pub fn compress(state: &mut [u32; 8], block: &[u8], words: usize) {
+    debug_assert_eq!(block.len(), 4 * (16 - words));
+    let mut w = [0u32; 16];
+    w[..words].copy_from_slice(&state[..words]);
+    BE::read_u32_into(block, &mut w[words..]);
+
+    for pass in 0..PASSES {
+        for &shift in SHIFTS.iter() {
+            for i in 0..16 {
+                let sbox = &SBOXES[2 * pass + ((i >> 1) & 1)];
+                let x = sbox[(w[i] & 0xff) as usize];
+                w[(i + 15) % 16] ^= x;
+                w[(i + 1) % 16] ^= x;
+            }
+            for w in w.iter_mut() {
+                *w = w.rotate_right(shift);
+            }
+        }
+    }
+
+    for (i, s) in state[..words].iter_mut().enumerate() {
+        *s ^= w[15 - i];
+    }
+}
@@ -0,0 +1,126 @@
+//! An implementation of the [Snefru][1] cryptographic hash function.
+//!
+//! Snefru-128 and Snefru-256 are provided, both with the eight passes
+//! recommended by Ralph Merkle after differential attacks on the versions
+//! with fewer passes. The compression function takes a 512-bit input, so
+//! Snefru-128 processes 48 byte and Snefru-256 32 byte blocks. Snefru is
+//! slow and should only be used to verify existing digests.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate snefru;
+//! # fn main() {
+//! use snefru::{Snefru128, Digest};
+//!
+//! // create a Snefru-128 hasher instance
+//! let mut hasher = Snefru128::new();
+//!
+//! // process input message
+//! hasher.input(b"abc");
+//!
+//! // acquire hash digest in the form of GenericArray,
+//! // which in this case is equivalent to [u8; 16]
+//! let result = hasher.result();
+//! assert_eq!(result[..], hex!("553d0648928299a0f22a275a02c83b10")[..]);
+//! # }
+//! ```
+//!
+//! Also see [RustCrypto/hashes][2] readme.
+//!
+//! [1]: https://en.wikipedia.org/wiki/Snefru
+//! [2]: https://github.com/RustCrypto/hashes
+#![no_std]
+#![doc(html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
+extern crate block_buffer;
+#[macro_use]
+extern crate opaque_debug;
+#[macro_use]
+pub extern crate digest;
+#[cfg(feature = "std")]
+extern crate std;
+
+use block_buffer::block_padding::ZeroPadding;
+use block_buffer::byteorder::{ByteOrder, BE};
+use block_buffer::BlockBuffer;
+use digest::generic_array::typenum::{U16, U32, U48};
+use digest::generic_array::GenericArray;
+pub use digest::Digest;
+use digest::{BlockInput, FixedOutput, Input, Reset};
+
+mod block;
+mod consts;
+use block::compress;
+
+macro_rules! impl_snefru {
+    ($name:ident, $output:ty, $block:ty, $words:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub struct $name {
+            state: [u32; 8],
+            len: u64,
+            buffer: BlockBuffer<$block>,
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name {
+                    state: [0; 8],
+                    len: 0,
+                    buffer: Default::default(),
+                }
+            }
+        }
+
+        impl BlockInput for $name {
+            type BlockSize = $block;
+        }
+
+        impl Input for $name {
+            fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+                let input = input.as_ref();
+                // Assumes that input.len() can be converted to u64 without
+                // overflow
+                self.len += input.len() as u64;
+                let state = &mut self.state;
+                self.buffer.input(input, |b| compress(state, b, $words));
+            }
+        }
+
+        impl FixedOutput for $name {
+            type OutputSize = $output;
+
+            fn fixed_result(mut self) -> GenericArray<u8, $output> {
+                // the last block is padded with zeros, followed by a block
+                // of zeros ending with the message length in bits
+                if self.buffer.position() != 0 {
+                    let block = self.buffer.pad_with::<ZeroPadding>().unwrap();
+                    compress(&mut self.state, block, $words);
+                }
+                let mut block = GenericArray::<u8, $block>::default();
+                let n = block.len();
+                BE::write_u64(&mut block[n - 8..], self.len << 3);
+                compress(&mut self.state, &block, $words);
+
+                let mut out = GenericArray::default();
+                BE::write_u32_into(&self.state[..$words], &mut out);
+                out
+            }
+        }
+
+        impl Reset for $name {
+            fn reset(&mut self) {
+                self.buffer.reset();
+                self.len = 0;
+                self.state = [0; 8];
+            }
+        }
+
+        impl_opaque_debug!($name);
+        impl_write!($name);
+    };
+}
+
+impl_snefru!(Snefru128, U16, U48, 4, "Snefru with 128-bit output and 8 passes");
+impl_snefru!(Snefru256, U32, U32, 8, "Snefru with 256-bit output and 8 passes");
@@ -0,0 +1,24 @@
+//! Test messages for Snefru-128 and Snefru-256 with 8 passes, the digests
+//! were computed with the implementation of RHash
+#![no_std]
+
+#[macro_use]
+extern crate digest;
+extern crate snefru;
+
+use digest::dev::{digest_test, one_million_a};
+
+new_test!(snefru128_main, "snefru128", snefru::Snefru128, digest_test);
+new_test!(snefru256_main, "snefru256", snefru::Snefru256, digest_test);
+
+#[test]
+fn snefru128_1million_a() {
+    let output = include_bytes!("data/snefru128_one_million_a.bin");
+    one_million_a::<snefru::Snefru128>(&output[..]);
+}
+
+#[test]
+fn snefru256_1million_a() {
+    let output = include_bytes!("data/snefru256_one_million_a.bin");
+    one_million_a::<snefru::Snefru256>(&output[..]);
+}
@@ -1,6 +1,31 @@
 cd md5 && cargo test --features asm && cd .. &&
 cd sha1 && cargo test --features asm && cd .. &&
 cd whirlpool && cargo test --features asm && cd .. &&
+cd tiger && cargo test --features parallel &&
+            cargo test --features hmac &&
+            cargo test --features pbkdf2 &&
+            cargo test --features hkdf &&
+            cargo test --features serde &&
+            cargo test --features zeroize &&
+            cargo test --features hasher &&
+            cargo test --features subtle &&
+            cargo test --no-default-features --features alloc &&
+            cargo test --features avx2 &&
+            cargo test --features small-tables &&
+            cargo test --features compact &&
+            cargo test --features prefetch &&
+            cargo test --features async &&
+            cargo test --features async-tokio &&
+            cargo test --features mmap &&
+            cargo test --features bitprint &&
+            cargo test --features capi &&
+            cargo test --features wasm &&
+            cargo test --features reference &&
+            cargo test --features keyed &&
+            cargo test --features simd &&
+            cargo test --release --features constant-time &&
+            cargo test --features hazmat &&
+            cargo test --features trace && cd .. &&
 cd blake2 && cargo test --features simd &&
              cargo test --features simd_opt &&
              cargo test --features simd_asm
@@ -0,0 +1,29 @@
+extern crate cbindgen;
+
+use std::env;
+use std::path::Path;
+
+fn main() {
+    // for compiling the C program in tests/c_program.rs
+    for var in ["TARGET", "HOST"].iter() {
+        println!("cargo:rustc-env=TIGER_CAPI_{}={}", var, env::var(var).unwrap());
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // include/tiger.h is generated from the source of the tiger::capi
+    // module. Outside of the repository, e.g. when built from crates.io, the
+    // shipped header is used.
+    let capi = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("../tiger/src/capi.rs");
+    if !capi.exists() {
+        return;
+    }
+    cbindgen::Builder::new()
+        .with_config(cbindgen::Config::from_file("cbindgen.toml").unwrap())
+        .with_src(&capi)
+        .generate()
+        .expect("failed to generate tiger.h")
+        .write_to_file("include/tiger.h");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-changed=../tiger/src/capi.rs");
+}
@@ -0,0 +1,87 @@
+/*
+ * C interface to the Tiger hash function, see tiger::capi.
+ *
+ * The digest is the 24 byte string defined by the Tiger reference
+ * implementation, i.e. the three state words in little-endian order.
+ *
+ * Generated by cbindgen from tiger/src/capi.rs, do not edit.
+ */
+
+#ifndef TIGER_H
+#define TIGER_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Size of a Tiger digest in bytes
+ */
+#define TIGER_DIGEST_SIZE 24
+
+/**
+ * Hashing context of the C interface, `tiger_ctx` in `tiger.h`
+ *
+ * The fields are the snapshot of a `Tiger` hasher, so that C knows the
+ * size of the context, but they must be treated as opaque.
+ */
+typedef struct tiger_ctx {
+  uint64_t state[3];
+  uint64_t length;
+  uint8_t buffer[64];
+} tiger_ctx;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Initialize `ctx` for hashing a new message
+ *
+ * # Safety
+ *
+ * `ctx` must be null or point to writable memory for a `TigerCtx`.
+ */
+void tiger_init(struct tiger_ctx *ctx);
+
+/**
+ * Hash `len` bytes at `data` into `ctx`
+ *
+ * Returns 0 on success and -1, leaving `ctx` unchanged, if the message
+ * would exceed `MAX_MESSAGE_LEN` bytes.
+ *
+ * # Safety
+ *
+ * `ctx` must be null or point to a context set up by `tiger_init`, and
+ * `data` must be valid for reads of `len` bytes.
+ */
+int tiger_update(struct tiger_ctx *ctx, const uint8_t *data, size_t len);
+
+/**
+ * Write the 24 byte Tiger digest of the message hashed into `ctx` to
+ * `digest` and re-initialize `ctx`
+ *
+ * # Safety
+ *
+ * `ctx` must be null or point to a context set up by `tiger_init`, and
+ * `digest` must be valid for writes of 24 bytes.
+ */
+void tiger_final(struct tiger_ctx *ctx, uint8_t *digest);
+
+/**
+ * Write the 24 byte Tiger digest of `len` bytes at `data` to `digest`
+ *
+ * Returns 0 on success and -1, writing nothing, if `len` exceeds
+ * `MAX_MESSAGE_LEN`.
+ *
+ * # Safety
+ *
+ * `data` must be valid for reads of `len` bytes and `digest` must be valid
+ * for writes of 24 bytes.
+ */
+int tiger_hash(const uint8_t *data, size_t len, uint8_t *digest);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* TIGER_H */
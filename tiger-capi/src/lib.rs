@@ -0,0 +1,22 @@
+//! C library exporting the Tiger hash function
+//!
+//! Builds `libtiger_capi` as shared and static library with the functions of the
+//! `tiger::capi` module, declared for C and C++ in `include/tiger.h`. The
+//! header is generated from `tiger::capi` by cbindgen when building in the
+//! repository, the tests compile and run the C program in `tests/c` with it.
+//!
+//!
+//! Hashing a message from C:
+//!
+//! ```c
+//! #include "tiger.h"
+//!
+//! uint8_t digest[TIGER_DIGEST_SIZE];
+//! tiger_ctx ctx;
+//! tiger_init(&ctx);
+//! tiger_update(&ctx, (const uint8_t *)"abc", 3);
+//! tiger_final(&ctx, digest);
+//! ```
+extern crate tiger;
+
+pub use tiger::capi::*;
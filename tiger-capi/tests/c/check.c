@@ -0,0 +1,40 @@
+/*
+ * Hashes a message through tiger.h and prints the digests in hex, one per
+ * line, for tests/c_program.rs
+ */
+#include <stdio.h>
+
+#include "tiger.h"
+
+static void print(const uint8_t digest[TIGER_DIGEST_SIZE])
+{
+    size_t i;
+
+    for (i = 0; i < TIGER_DIGEST_SIZE; i++)
+        printf("%02x", digest[i]);
+    printf("\n");
+}
+
+int main(void)
+{
+    uint8_t msg[1000], digest[TIGER_DIGEST_SIZE];
+    tiger_ctx ctx;
+    size_t i;
+
+    for (i = 0; i < sizeof msg; i++)
+        msg[i] = (uint8_t)(i * 7);
+
+    tiger_init(&ctx);
+    for (i = 0; i < sizeof msg; i += 7) {
+        size_t n = sizeof msg - i < 7 ? sizeof msg - i : 7;
+        if (tiger_update(&ctx, msg + i, n) != 0)
+            return 1;
+    }
+    tiger_final(&ctx, digest);
+    print(digest);
+
+    if (tiger_hash((const uint8_t *)"abc", 3, digest) != 0)
+        return 1;
+    print(digest);
+    return 0;
+}
@@ -0,0 +1,52 @@
+//! Compiles `tests/c/check.c` against the generated `include/tiger.h` and
+//! the shared library and checks its output
+#![cfg(unix)]
+extern crate cc;
+extern crate tiger;
+
+use std::env;
+use std::process::Command;
+
+fn hex(digest: &[u8]) -> String {
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[test]
+fn c_program() {
+    let (target, host) = (env!("TIGER_CAPI_TARGET"), env!("TIGER_CAPI_HOST"));
+    if target != host {
+        // the program could not be run without an emulator
+        return;
+    }
+    let manifest = env!("CARGO_MANIFEST_DIR");
+    // the test binary lives next to the libraries of the crate
+    let exe = env::current_exe().unwrap();
+    let deps = exe.parent().unwrap();
+    let out = deps.join("tiger_capi_check");
+
+    let compiler = cc::Build::new()
+        .target(target)
+        .host(host)
+        .opt_level(0)
+        .debug(false)
+        .warnings(true)
+        .warnings_into_errors(true)
+        .cargo_metadata(false)
+        .get_compiler();
+    let status = compiler.to_command()
+        .arg(format!("-I{}/include", manifest))
+        .arg(format!("{}/tests/c/check.c", manifest))
+        .arg("-o").arg(&out)
+        .arg(format!("-L{}", deps.display()))
+        .arg("-ltiger_capi")
+        .arg(format!("-Wl,-rpath,{}", deps.display()))
+        .status()
+        .unwrap();
+    assert!(status.success(), "compiling tests/c/check.c failed");
+
+    let output = Command::new(&out).output().unwrap();
+    assert!(output.status.success());
+    let msg: Vec<u8> = (0..1000u32).map(|i| (i * 7) as u8).collect();
+    let expected = format!("{}\n{}\n", hex(&tiger::hash(&msg)), hex(&tiger::hash(b"abc")));
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), expected);
+}
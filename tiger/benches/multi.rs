@@ -0,0 +1,66 @@
+#![no_std]
+#![feature(test)]
+extern crate test;
+extern crate tiger;
+
+use test::Bencher;
+
+#[bench]
+fn hash4_64(b: &mut Bencher) {
+    let data = [0u8; 64];
+    b.iter(|| tiger::multi::hash4([&data[..]; 4]));
+    b.bytes = 4 * 64;
+}
+
+#[bench]
+fn hash4_1000(b: &mut Bencher) {
+    let data = [0u8; 1000];
+    b.iter(|| tiger::multi::hash4([&data[..]; 4]));
+    b.bytes = 4 * 1000;
+}
+
+#[bench]
+fn sequential4_64(b: &mut Bencher) {
+    let data = [0u8; 64];
+    b.iter(|| {
+        for _ in 0..4 {
+            test::black_box(tiger::hash(&data[..]));
+        }
+    });
+    b.bytes = 4 * 64;
+}
+
+#[bench]
+fn sequential4_1000(b: &mut Bencher) {
+    let data = [0u8; 1000];
+    b.iter(|| {
+        for _ in 0..4 {
+            test::black_box(tiger::hash(&data[..]));
+        }
+    });
+    b.bytes = 4 * 1000;
+}
+
+#[bench]
+fn compress4_bench(b: &mut Bencher) {
+    let block = tiger::Block::default();
+    let mut states = [[0u64; 3]; 4];
+    b.iter(|| {
+        tiger::multi::compress4(&mut states, [&block, &block, &block, &block]);
+        test::black_box(&states);
+    });
+    b.bytes = 4 * 64;
+}
+
+#[bench]
+fn compress_x4_bench(b: &mut Bencher) {
+    let block = [tiger::Block::default()];
+    let mut states = [[0u64; 3]; 4];
+    b.iter(|| {
+        for s in states.iter_mut() {
+            tiger::compress(s, &block);
+        }
+        test::black_box(&states);
+    });
+    b.bytes = 4 * 64;
+}
@@ -0,0 +1,68 @@
+#![feature(test)]
+extern crate test;
+extern crate tiger;
+
+use test::Bencher;
+use tiger::Digest;
+use tiger::tth::TigerTree;
+
+const SMALL: usize = 64;
+const MEDIUM: usize = 1 << 10;
+const LARGE: usize = 1 << 20;
+
+macro_rules! bench_input {
+    ($name:ident, $engine:path, $size:expr) => {
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            let mut d = <$engine>::default();
+            let data = vec![0u8; $size];
+            b.iter(|| d.input(&data[..]));
+            b.bytes = $size as u64;
+        }
+    };
+}
+
+macro_rules! bench_digest {
+    ($name:ident, $engine:path, $size:expr) => {
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            let data = vec![0u8; $size];
+            b.iter(|| test::black_box(<$engine>::digest(&data[..])));
+            b.bytes = $size as u64;
+        }
+    };
+}
+
+macro_rules! bench_hash {
+    ($name:ident, $size:expr) => {
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            let data = vec![0u8; $size];
+            b.iter(|| test::black_box(tiger::hash(&data[..])));
+            b.bytes = $size as u64;
+        }
+    };
+}
+
+bench_input!(input_small, tiger::Tiger, SMALL);
+bench_input!(input_medium, tiger::Tiger, MEDIUM);
+bench_input!(input_large, tiger::Tiger, LARGE);
+
+bench_digest!(digest_small, tiger::Tiger, SMALL);
+bench_digest!(digest_medium, tiger::Tiger, MEDIUM);
+bench_digest!(digest_large, tiger::Tiger, LARGE);
+
+bench_hash!(hash_small, SMALL);
+bench_hash!(hash_medium, MEDIUM);
+bench_hash!(hash_large, LARGE);
+
+bench_digest!(tth_medium, TigerTree, MEDIUM);
+bench_digest!(tth_large, TigerTree, LARGE);
+
+#[cfg(feature = "parallel")]
+#[bench]
+fn tth_parallel_large(b: &mut Bencher) {
+    let data = vec![0u8; 16 * LARGE];
+    b.iter(|| test::black_box(TigerTree::hash_slice_parallel(&data)));
+    b.bytes = 16 * LARGE as u64;
+}
@@ -0,0 +1,13 @@
+#[cfg(feature = "reference")]
+extern crate cc;
+
+fn main() {
+    // the C reference implementation for the differential tests
+    #[cfg(feature = "reference")]
+    cc::Build::new()
+        .file("reference/tiger.c")
+        .warnings(true)
+        .compile("tiger_reference");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=reference/tiger.c");
+}
@@ -0,0 +1,52 @@
+//! Arbitrary sequences of input, reset, finalization and state round trips
+//! must behave like hashing the bytes fed in since the last reset.
+//!
+//! Every operation is an opcode byte, input operations are followed by a
+//! length byte and that many message bytes.
+//!
+//! Run with `cargo +nightly fuzz run lifecycle` in the `tiger` directory.
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use tiger::{Digest, Tiger};
+
+fuzz_target!(|data: &[u8]| {
+    let mut hasher = Tiger::new();
+    let mut model = Vec::new();
+    let mut data = data;
+
+    while let Some((&op, rest)) = data.split_first() {
+        data = rest;
+        match op % 5 {
+            0 => {
+                let (&len, rest) = match data.split_first() {
+                    Some(v) => v,
+                    None => return,
+                };
+                let len = (len as usize).min(rest.len());
+                let (chunk, rest) = rest.split_at(len);
+                hasher.input(chunk);
+                model.extend_from_slice(chunk);
+                data = rest;
+            }
+            1 => {
+                hasher.reset();
+                model.clear();
+            }
+            2 => {
+                let result = hasher.result_reset();
+                assert_eq!(result[..], tiger::hash(&model)[..]);
+                model.clear();
+            }
+            3 => {
+                let result = hasher.clone().result();
+                assert_eq!(result[..], tiger::hash(&model)[..]);
+            }
+            _ => {
+                let state = hasher.serialize_state();
+                hasher = Tiger::deserialize_state(&state).unwrap();
+            }
+        }
+    }
+
+    assert_eq!(hasher.result()[..], tiger::hash(&model)[..]);
+});
@@ -0,0 +1,37 @@
+//! Hashing input split at arbitrary points must match one-shot hashing.
+//!
+//! The first byte gives the number of split points, each taking two bytes,
+//! the remaining bytes are the message.
+//!
+//! Run with `cargo +nightly fuzz run split` in the `tiger` directory.
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use tiger::{Digest, Tiger, Tiger2};
+
+fuzz_target!(|data: &[u8]| {
+    let (&n, rest) = match data.split_first() {
+        Some(v) => v,
+        None => return,
+    };
+    let n = n as usize;
+    if rest.len() < 2 * n {
+        return;
+    }
+    let (splits, msg) = rest.split_at(2 * n);
+
+    let mut tiger = Tiger::new();
+    let mut tiger2 = Tiger2::new();
+    let mut pos = 0;
+    for s in splits.chunks_exact(2) {
+        let len = u16::from_le_bytes([s[0], s[1]]) as usize;
+        let end = msg.len().min(pos + len);
+        tiger.input(&msg[pos..end]);
+        tiger2.input(&msg[pos..end]);
+        pos = end;
+    }
+    tiger.input(&msg[pos..]);
+    tiger2.input(&msg[pos..]);
+
+    assert_eq!(tiger.result()[..], tiger::hash(msg)[..]);
+    assert_eq!(tiger2.result()[..], tiger::hash2(msg)[..]);
+});
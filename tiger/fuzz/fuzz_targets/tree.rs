@@ -0,0 +1,56 @@
+//! The streaming Tiger Tree hasher fed in arbitrary chunks must match a
+//! naive recursive construction of the tree.
+//!
+//! The first byte selects the chunk length used for feeding the hasher,
+//! the remaining bytes are the message.
+//!
+//! Run with `cargo +nightly fuzz run tree` in the `tiger` directory.
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use tiger::tth::TigerTree;
+use tiger::{Digest, Tiger};
+
+fn leaf(data: &[u8]) -> Vec<u8> {
+    Tiger::new().chain([0x00]).chain(data).result().to_vec()
+}
+
+fn node(left: &[u8], right: &[u8]) -> Vec<u8> {
+    Tiger::new().chain([0x01]).chain(left).chain(right).result().to_vec()
+}
+
+/// Root of the tree over `leaves`: the left subtree covers the largest
+/// power of two of leaves which is less than their number, so a node
+/// without sibling is promoted to the next level unchanged
+fn root(leaves: &[Vec<u8>]) -> Vec<u8> {
+    if leaves.len() == 1 {
+        return leaves[0].clone();
+    }
+    let mut split = 1;
+    while 2 * split < leaves.len() {
+        split *= 2;
+    }
+    node(&root(&leaves[..split]), &root(&leaves[split..]))
+}
+
+fuzz_target!(|data: &[u8]| {
+    let (&chunk, msg) = match data.split_first() {
+        Some(v) => v,
+        None => return,
+    };
+    // chunk lengths from 1 byte up to more than two leaves
+    let chunk = 1 + chunk as usize * 9;
+
+    let leaves: Vec<Vec<u8>> = if msg.is_empty() {
+        vec![leaf(b"")]
+    } else {
+        msg.chunks(1024).map(leaf).collect()
+    };
+    let expected = root(&leaves);
+
+    let mut tree = TigerTree::new();
+    for part in msg.chunks(chunk) {
+        tree.input(part);
+    }
+    assert_eq!(tree.result()[..], expected[..]);
+    assert_eq!(TigerTree::digest(msg)[..], expected[..]);
+});
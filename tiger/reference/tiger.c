@@ -0,0 +1,271 @@
+/*
+ * Tiger reference implementation by Ross Anderson and Eli Biham, placed in
+ * the public domain, transcribed for differential testing of the Rust code.
+ *
+ * Only used by the tests behind the `reference` feature of the tiger crate.
+ * Compared to the original distribution the S-boxes are generated by
+ * `tiger_gen` (the procedure of the paper, from the original `sboxes.c`
+ * generator) instead of being compiled in, message words are loaded
+ * byte-wise instead of through the `BIG_ENDIAN` ifdefs, and `tiger2` pads
+ * with 0x80 for the Tiger2 variant. Tables, padding and byte order
+ * handling are thus all independent of the Rust code.
+ *
+ * `tiger_bits` and `tiger2_bits` are not part of the reference, they were
+ * added together with the bit-level API of the Rust code and follow its
+ * bit order convention. They only check the buffering and padding of
+ * partial bytes, not the convention itself.
+ */
+#include <stdint.h>
+#include <string.h>
+
+typedef uint64_t word64;
+typedef unsigned char byte;
+
+#define PASSES 3
+
+static word64 table[4 * 256];
+static int table_ready = 0;
+
+#define t1 (table)
+#define t2 (table + 256)
+#define t3 (table + 256 * 2)
+#define t4 (table + 256 * 3)
+
+#define save_abc \
+      aa = a; \
+      bb = b; \
+      cc = c;
+
+#define round(a, b, c, x, mul) \
+      c ^= x; \
+      a -= t1[((c) >> (0 * 8)) & 0xFF] ^ t2[((c) >> (2 * 8)) & 0xFF] ^ \
+           t3[((c) >> (4 * 8)) & 0xFF] ^ t4[((c) >> (6 * 8)) & 0xFF]; \
+      b += t4[((c) >> (1 * 8)) & 0xFF] ^ t3[((c) >> (3 * 8)) & 0xFF] ^ \
+           t2[((c) >> (5 * 8)) & 0xFF] ^ t1[((c) >> (7 * 8)) & 0xFF]; \
+      b *= mul;
+
+#define pass(a, b, c, mul) \
+      round(a, b, c, x0, mul) \
+      round(b, c, a, x1, mul) \
+      round(c, a, b, x2, mul) \
+      round(a, b, c, x3, mul) \
+      round(b, c, a, x4, mul) \
+      round(c, a, b, x5, mul) \
+      round(a, b, c, x6, mul) \
+      round(b, c, a, x7, mul)
+
+#define key_schedule \
+      x0 -= x7 ^ 0xA5A5A5A5A5A5A5A5ULL; \
+      x1 ^= x0; \
+      x2 += x1; \
+      x3 -= x2 ^ ((~x1) << 19); \
+      x4 ^= x3; \
+      x5 += x4; \
+      x6 -= x5 ^ ((~x4) >> 23); \
+      x7 ^= x6; \
+      x0 += x7; \
+      x1 -= x0 ^ ((~x7) << 19); \
+      x2 ^= x1; \
+      x3 += x2; \
+      x4 -= x3 ^ ((~x2) >> 23); \
+      x5 ^= x4; \
+      x6 += x5; \
+      x7 -= x6 ^ 0x0123456789ABCDEFULL;
+
+#define feedforward \
+      a ^= aa; \
+      b -= bb; \
+      c += cc;
+
+#define compress \
+      save_abc \
+      pass(a, b, c, 5) \
+      key_schedule \
+      pass(c, a, b, 7) \
+      key_schedule \
+      pass(b, c, a, 9) \
+      for (pass_no = 3; pass_no < PASSES; pass_no++) { \
+        key_schedule \
+        pass(a, b, c, 9) \
+        tmpa = a; a = c; c = b; b = tmpa; \
+      } \
+      feedforward
+
+/* The message block is read as eight little-endian words whatever the
+ * byte order of the host, as in the original `#ifdef BIG_ENDIAN` code */
+static word64 load64(const byte *p)
+{
+  word64 w = 0;
+  int i;
+  for (i = 7; i >= 0; i--)
+    w = (w << 8) | p[i];
+  return w;
+}
+
+void tiger_compress(const byte *str, word64 state[3])
+{
+  register word64 a, b, c, tmpa;
+  word64 aa, bb, cc;
+  register word64 x0, x1, x2, x3, x4, x5, x6, x7;
+  int pass_no;
+
+  a = state[0];
+  b = state[1];
+  c = state[2];
+
+  x0 = load64(str + 0 * 8); x1 = load64(str + 1 * 8);
+  x2 = load64(str + 2 * 8); x3 = load64(str + 3 * 8);
+  x4 = load64(str + 4 * 8); x5 = load64(str + 5 * 8);
+  x6 = load64(str + 6 * 8); x7 = load64(str + 7 * 8);
+
+  compress;
+
+  state[0] = a;
+  state[1] = b;
+  state[2] = c;
+}
+
+/* Generate the S-boxes from the seed string of the paper: start with
+ * identity tables and swap the bytes of every column as directed by the
+ * output of the compression function, which uses the tables so far */
+static void tiger_gen(const char *str, int passes)
+{
+  word64 state[3];
+  byte tempstr[64];
+  int i, sb, col, abc, cnt;
+
+  memcpy(tempstr, str, 64);
+  state[0] = 0x0123456789ABCDEFULL;
+  state[1] = 0xFEDCBA9876543210ULL;
+  state[2] = 0xF096A5B4C3B2E187ULL;
+
+  for (i = 0; i < 1024; i++)
+    for (col = 0; col < 8; col++)
+      table[i] |= (word64)(i & 255) << (8 * col);
+
+  abc = 2;
+  for (cnt = 0; cnt < passes; cnt++)
+    for (i = 0; i < 256; i++)
+      for (sb = 0; sb < 1024; sb += 256) {
+        abc++;
+        if (abc == 3) {
+          abc = 0;
+          tiger_compress(tempstr, state);
+        }
+        for (col = 0; col < 8; col++) {
+          int j = (int)((state[abc] >> (8 * col)) & 0xFF);
+          word64 mask = (word64)0xFF << (8 * col);
+          word64 bi = table[sb + i] & mask, bj = table[sb + j] & mask;
+          table[sb + i] = (table[sb + i] & ~mask) | bj;
+          table[sb + j] = (table[sb + j] & ~mask) | bi;
+        }
+      }
+}
+
+static void tiger_setup(void)
+{
+  if (!table_ready) {
+    memset(table, 0, sizeof(table));
+    tiger_gen("Tiger - A Fast New Hash Function, by Ross Anderson and Eli Biham", 5);
+    table_ready = 1;
+  }
+}
+
+static void tiger_padded(const byte *str, word64 length, word64 res[3], byte pad)
+{
+  register word64 i, j;
+  byte temp[64];
+
+  tiger_setup();
+
+  res[0] = 0x0123456789ABCDEFULL;
+  res[1] = 0xFEDCBA9876543210ULL;
+  res[2] = 0xF096A5B4C3B2E187ULL;
+
+  for (i = length; i >= 64; i -= 64) {
+    tiger_compress(str, res);
+    str += 64;
+  }
+
+  for (j = 0; j < i; j++)
+    temp[j] = str[j];
+
+  temp[j++] = pad;
+  for (; j & 7; j++)
+    temp[j] = 0;
+  if (j > 56) {
+    for (; j < 64; j++)
+      temp[j] = 0;
+    tiger_compress(temp, res);
+    j = 0;
+  }
+
+  for (; j < 56; j++)
+    temp[j] = 0;
+  for (i = 0; i < 8; i++)
+    temp[56 + i] = (byte)(((word64)length << 3) >> (8 * i));
+  tiger_compress(temp, res);
+}
+
+/* Tiger digest of `length` bytes at `str`, as three words */
+void tiger(const byte *str, word64 length, word64 res[3])
+{
+  tiger_padded(str, length, res, 0x01);
+}
+
+/* Tiger2 digest of `length` bytes at `str`, as three words */
+void tiger2(const byte *str, word64 length, word64 res[3])
+{
+  tiger_padded(str, length, res, 0x80);
+}
+
+/* Digest of the first `bits` bits of `str`, padded bit by bit: the message
+ * is followed by a single one bit, zero bits and the 64-bit bit length.
+ * The bits of a byte are numbered from the least significant one for
+ * Tiger, which makes the padding byte 0x01, and from the most significant
+ * one for Tiger2, which makes it 0x80. */
+static void tiger_padded_bits(const byte *str, word64 bits, word64 res[3],
+  int msb_first)
+{
+  word64 i, n;
+  byte temp[128];
+  int shift, bit, blocks;
+
+  tiger_setup();
+
+  res[0] = 0x0123456789ABCDEFULL;
+  res[1] = 0xFEDCBA9876543210ULL;
+  res[2] = 0xF096A5B4C3B2E187ULL;
+
+  for (n = bits; n >= 512; n -= 512) {
+    tiger_compress(str, res);
+    str += 64;
+  }
+
+  memset(temp, 0, sizeof(temp));
+  for (i = 0; i <= n; i++) {
+    shift = msb_first ? 7 - (int)(i & 7) : (int)(i & 7);
+    bit = i < n ? (str[i >> 3] >> shift) & 1 : 1;
+    temp[i >> 3] |= (byte)(bit << shift);
+  }
+
+  /* the byte holding the padding bit must precede the length field */
+  blocks = (n >> 3) + 1 > 56 ? 2 : 1;
+  for (i = 0; i < 8; i++)
+    temp[64 * blocks - 8 + i] = (byte)(bits >> (8 * i));
+  tiger_compress(temp, res);
+  if (blocks == 2)
+    tiger_compress(temp + 64, res);
+}
+
+/* Tiger digest of the first `bits` bits of `str`, as three words */
+void tiger_bits(const byte *str, word64 bits, word64 res[3])
+{
+  tiger_padded_bits(str, bits, res, 0);
+}
+
+/* Tiger2 digest of the first `bits` bits of `str`, as three words */
+void tiger2_bits(const byte *str, word64 bits, word64 res[3])
+{
+  tiger_padded_bits(str, bits, res, 1);
+}
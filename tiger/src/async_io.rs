@@ -0,0 +1,198 @@
+//! Hashing of data passing through asynchronous readers and writers
+//!
+//! The adapters implement the `futures-io` traits, and with the
+//! `async-tokio` feature also the Tokio ones. Wrapped readers
+//! and writers must be `Unpin`, pin them with `Box::pin` otherwise.
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::io;
+use std::vec::Vec;
+use digest::Digest;
+use digest::generic_array::GenericArray;
+use futures_io::{AsyncRead, AsyncWrite};
+use {to_array, Tiger};
+
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Asynchronous reader which hashes all bytes read through it with `D`,
+/// see `HashingReader`
+#[derive(Clone, Debug)]
+pub struct AsyncHashingReader<R, D: Digest> {
+    inner: R,
+    hasher: D,
+}
+
+/// Asynchronous writer which hashes all bytes accepted by the inner writer
+/// with `D`
+#[derive(Clone, Debug)]
+pub struct AsyncHashingWriter<W, D: Digest> {
+    inner: W,
+    hasher: D,
+}
+
+macro_rules! impl_adapter {
+    ($name:ident, $inner:ident, $kind:expr) => {
+        impl<$inner, D: Digest> $name<$inner, D> {
+            #[doc = "Wrap `inner` with a new hasher"]
+            pub fn new(inner: $inner) -> Self {
+                $name { inner, hasher: D::new() }
+            }
+
+            #[doc = "Wrap `inner` with the given hasher"]
+            pub fn with_hasher(inner: $inner, hasher: D) -> Self {
+                $name { inner, hasher }
+            }
+
+            #[doc = concat!("Get a reference to the inner ", $kind)]
+            pub fn get_ref(&self) -> &$inner {
+                &self.inner
+            }
+
+            #[doc = concat!("Get a mutable reference to the inner ", $kind)]
+            pub fn get_mut(&mut self) -> &mut $inner {
+                &mut self.inner
+            }
+
+            #[doc = concat!("Split into the inner ", $kind, " and the hasher")]
+            pub fn into_parts(self) -> ($inner, D) {
+                (self.inner, self.hasher)
+            }
+
+            #[doc = "Retrieve the hash of all bytes passed through so far"]
+            pub fn finalize(self) -> GenericArray<u8, D::OutputSize> {
+                self.hasher.result()
+            }
+        }
+    };
+}
+
+impl_adapter!(AsyncHashingReader, R, "reader");
+impl_adapter!(AsyncHashingWriter, W, "writer");
+
+impl<R, D> AsyncRead for AsyncHashingReader<R, D>
+    where R: AsyncRead + Unpin, D: Digest + Unpin
+{
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context, buf: &mut [u8])
+        -> Poll<io::Result<usize>>
+    {
+        let this = self.get_mut();
+        match Pin::new(&mut this.inner).poll_read(cx, buf) {
+            Poll::Ready(Ok(n)) => {
+                this.hasher.input(&buf[..n]);
+                Poll::Ready(Ok(n))
+            }
+            other => other,
+        }
+    }
+}
+
+impl<W, D> AsyncWrite for AsyncHashingWriter<W, D>
+    where W: AsyncWrite + Unpin, D: Digest + Unpin
+{
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8])
+        -> Poll<io::Result<usize>>
+    {
+        let this = self.get_mut();
+        match Pin::new(&mut this.inner).poll_write(cx, buf) {
+            Poll::Ready(Ok(n)) => {
+                this.hasher.input(&buf[..n]);
+                Poll::Ready(Ok(n))
+            }
+            other => other,
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+impl<R, D> ::tokio::io::AsyncRead for AsyncHashingReader<R, D>
+    where R: ::tokio::io::AsyncRead + Unpin, D: Digest + Unpin
+{
+    fn poll_read(
+        self: Pin<&mut Self>, cx: &mut Context, buf: &mut ::tokio::io::ReadBuf,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let start = buf.filled().len();
+        match Pin::new(&mut this.inner).poll_read(cx, buf) {
+            Poll::Ready(Ok(())) => {
+                this.hasher.input(&buf.filled()[start..]);
+                Poll::Ready(Ok(()))
+            }
+            other => other,
+        }
+    }
+}
+
+#[cfg(feature = "async-tokio")]
+impl<W, D> ::tokio::io::AsyncWrite for AsyncHashingWriter<W, D>
+    where W: ::tokio::io::AsyncWrite + Unpin, D: Digest + Unpin
+{
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8])
+        -> Poll<io::Result<usize>>
+    {
+        let this = self.get_mut();
+        match Pin::new(&mut this.inner).poll_write(cx, buf) {
+            Poll::Ready(Ok(n)) => {
+                this.hasher.input(&buf[..n]);
+                Poll::Ready(Ok(n))
+            }
+            other => other,
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+/// Future returned by `hash_async_reader`
+#[derive(Debug)]
+pub struct HashAsyncReader<R> {
+    reader: R,
+    hasher: Tiger,
+    buf: Vec<u8>,
+}
+
+/// Compute the Tiger digest of all data read from the asynchronous
+/// `reader`, see `hash_reader`
+///
+/// The returned future resolves to the same value as
+/// `async fn hash_async_reader(reader: R) -> io::Result<[u8; 24]>` would.
+pub fn hash_async_reader<R: AsyncRead + Unpin>(reader: R) -> HashAsyncReader<R> {
+    HashAsyncReader {
+        reader,
+        hasher: Tiger::new(),
+        buf: ::std::vec::from_elem(0, BUFFER_SIZE),
+    }
+}
+
+impl<R: AsyncRead + Unpin> Future for HashAsyncReader<R> {
+    type Output = io::Result<[u8; 24]>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut this.reader).poll_read(cx, &mut this.buf) {
+                Poll::Ready(Ok(0)) => {
+                    return Poll::Ready(Ok(to_array(this.hasher.result_reset())));
+                }
+                Poll::Ready(Ok(n)) => this.hasher.input(&this.buf[..n]),
+                Poll::Ready(Err(ref e)) if e.kind() == io::ErrorKind::Interrupted => (),
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! AVX2 implementation of the four-lane compression function
+//!
+//! Every 256-bit register holds one 64-bit word of each of the four lanes,
+//! S-box lookups are done with gather instructions and the multiplications
+//! by 5, 7 and 9 are replaced by shifts and additions.
+use core::arch::x86_64::*;
+use consts::SBOXES;
+use Block;
+
+macro_rules! lookup {
+    ($table:ident, $c:expr, $shift:expr) => {
+        _mm256_i64gather_epi64(
+            SBOXES.$table.as_ptr() as *const i64,
+            _mm256_and_si256(_mm256_srli_epi64($c, $shift), _mm256_set1_epi64x(0xff)),
+            8,
+        )
+    };
+}
+
+macro_rules! mul {
+    ($b:expr, 5) => { _mm256_add_epi64(_mm256_slli_epi64($b, 2), $b) };
+    ($b:expr, 7) => { _mm256_sub_epi64(_mm256_slli_epi64($b, 3), $b) };
+    ($b:expr, 9) => { _mm256_add_epi64(_mm256_slli_epi64($b, 3), $b) };
+}
+
+macro_rules! round_avx2 {
+    ($a:expr, $b:expr, $c:expr, $x:expr, $mul:tt) => {
+        $c = _mm256_xor_si256($c, $x);
+        let even = _mm256_xor_si256(
+            _mm256_xor_si256(lookup!(T1, $c, 0), lookup!(T2, $c, 16)),
+            _mm256_xor_si256(lookup!(T3, $c, 32), lookup!(T4, $c, 48)),
+        );
+        let odd = _mm256_xor_si256(
+            _mm256_xor_si256(lookup!(T4, $c, 8), lookup!(T3, $c, 24)),
+            _mm256_xor_si256(lookup!(T2, $c, 40), lookup!(T1, $c, 56)),
+        );
+        $a = _mm256_sub_epi64($a, even);
+        $b = _mm256_add_epi64($b, odd);
+        $b = mul!($b, $mul);
+    };
+}
+
+macro_rules! pass_avx2 {
+    ($a:expr, $b:expr, $c:expr, $x:expr, $mul:tt) => {
+        round_avx2!($a, $b, $c, $x[0], $mul);
+        round_avx2!($b, $c, $a, $x[1], $mul);
+        round_avx2!($c, $a, $b, $x[2], $mul);
+        round_avx2!($a, $b, $c, $x[3], $mul);
+        round_avx2!($b, $c, $a, $x[4], $mul);
+        round_avx2!($c, $a, $b, $x[5], $mul);
+        round_avx2!($a, $b, $c, $x[6], $mul);
+        round_avx2!($b, $c, $a, $x[7], $mul);
+    };
+}
+
+#[inline(always)]
+unsafe fn not(x: __m256i) -> __m256i {
+    _mm256_xor_si256(x, _mm256_set1_epi64x(-1))
+}
+
+#[inline(always)]
+unsafe fn key_schedule(x: &mut [__m256i; 8]) {
+    let k0 = _mm256_set1_epi64x(0xA5A5_A5A5_A5A5_A5A5u64 as i64);
+    let k1 = _mm256_set1_epi64x(0x0123_4567_89AB_CDEF);
+    x[0] = _mm256_sub_epi64(x[0], _mm256_xor_si256(x[7], k0));
+    x[1] = _mm256_xor_si256(x[1], x[0]);
+    x[2] = _mm256_add_epi64(x[2], x[1]);
+    x[3] = _mm256_sub_epi64(x[3],
+        _mm256_xor_si256(x[2], _mm256_slli_epi64(not(x[1]), 19)));
+    x[4] = _mm256_xor_si256(x[4], x[3]);
+    x[5] = _mm256_add_epi64(x[5], x[4]);
+    x[6] = _mm256_sub_epi64(x[6],
+        _mm256_xor_si256(x[5], _mm256_srli_epi64(not(x[4]), 23)));
+    x[7] = _mm256_xor_si256(x[7], x[6]);
+    x[0] = _mm256_add_epi64(x[0], x[7]);
+    x[1] = _mm256_sub_epi64(x[1],
+        _mm256_xor_si256(x[0], _mm256_slli_epi64(not(x[7]), 19)));
+    x[2] = _mm256_xor_si256(x[2], x[1]);
+    x[3] = _mm256_add_epi64(x[3], x[2]);
+    x[4] = _mm256_sub_epi64(x[4],
+        _mm256_xor_si256(x[3], _mm256_srli_epi64(not(x[2]), 23)));
+    x[5] = _mm256_xor_si256(x[5], x[4]);
+    x[6] = _mm256_add_epi64(x[6], x[5]);
+    x[7] = _mm256_sub_epi64(x[7], _mm256_xor_si256(x[6], k1));
+}
+
+#[inline(always)]
+unsafe fn load(w: [u64; 4]) -> __m256i {
+    _mm256_set_epi64x(w[3] as i64, w[2] as i64, w[1] as i64, w[0] as i64)
+}
+
+#[inline(always)]
+unsafe fn store(v: __m256i) -> [u64; 4] {
+    let mut w = [0u64; 4];
+    _mm256_storeu_si256(w.as_mut_ptr() as *mut __m256i, v);
+    w
+}
+
+fn word(block: &Block, i: usize) -> u64 {
+    let mut w = [0u8; 8];
+    w.copy_from_slice(&block[8 * i..8 * (i + 1)]);
+    u64::from_le_bytes(w)
+}
+
+/// See `multi::compress4`, the caller must ensure that AVX2 is available
+#[target_feature(enable = "avx2")]
+pub unsafe fn compress4(states: &mut [[u64; 3]; 4], blocks: [&Block; 4]) {
+    let mut x = [_mm256_setzero_si256(); 8];
+    for (i, x) in x.iter_mut().enumerate() {
+        *x = load([
+            word(blocks[0], i), word(blocks[1], i),
+            word(blocks[2], i), word(blocks[3], i),
+        ]);
+    }
+    let lane = |j: usize| [states[0][j], states[1][j], states[2][j], states[3][j]];
+    let mut a = load(lane(0));
+    let mut b = load(lane(1));
+    let mut c = load(lane(2));
+    let (aa, bb, cc) = (a, b, c);
+
+    pass_avx2!(a, b, c, x, 5);
+    key_schedule(&mut x);
+    pass_avx2!(c, a, b, x, 7);
+    key_schedule(&mut x);
+    pass_avx2!(b, c, a, x, 9);
+
+    let a = store(_mm256_xor_si256(a, aa));
+    let b = store(_mm256_sub_epi64(b, bb));
+    let c = store(_mm256_add_epi64(c, cc));
+    for (i, s) in states.iter_mut().enumerate() {
+        *s = [a[i], b[i], c[i]];
+    }
+}
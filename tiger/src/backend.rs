@@ -0,0 +1,150 @@
+//! Run-time selection of the four-lane compression function
+use core::fmt;
+use core::sync::atomic::{AtomicU8, Ordering};
+#[cfg(feature = "std")]
+use std::error;
+#[cfg(all(feature = "avx2", target_arch = "x86_64",
+    not(feature = "small-tables")))]
+use avx2;
+#[cfg(feature = "simd")]
+use simd;
+#[cfg(not(feature = "constant-time"))]
+use multi::compress4_soft as scalar4;
+use Block;
+
+/// Implementation of `multi::compress4`
+///
+/// All backends compute the same results. Which of them are compiled in
+/// depends on the crate features, `Backend::is_available` tells whether a
+/// backend can be used on the running CPU. With the `constant-time`
+/// feature only the `Scalar` backend is available, it then compresses the
+/// lanes one after the other without secret-dependent memory accesses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// Interleaved scalar code, always available
+    Scalar,
+    /// AVX2 with gather instructions, requires the `avx2` feature, an
+    /// x86-64 CPU supporting AVX2 and is not available with `small-tables`
+    Avx2,
+    /// Portable vector types of the `wide` crate, requires the `simd`
+    /// feature
+    Simd,
+}
+
+/// The error type for forcing a backend which is not available
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnsupportedBackend;
+
+impl fmt::Display for UnsupportedBackend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("backend not available")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for UnsupportedBackend {
+    fn description(&self) -> &str {
+        "backend not available"
+    }
+}
+
+// 0 until the first call of `backend`, `Backend` index plus one afterwards
+static SELECTED: AtomicU8 = AtomicU8::new(0);
+
+impl Backend {
+    /// All backends, in order of increasing preference
+    pub const ALL: [Backend; 3] = [Backend::Scalar, Backend::Simd, Backend::Avx2];
+
+    /// Whether the backend is compiled in and supported by the CPU
+    pub fn is_available(self) -> bool {
+        match self {
+            Backend::Scalar => true,
+            Backend::Avx2 => !cfg!(feature = "constant-time") && avx2_available(),
+            Backend::Simd => cfg!(all(feature = "simd", not(feature = "constant-time"))),
+        }
+    }
+
+    /// The preferred available backend
+    pub fn detect() -> Self {
+        Self::ALL.iter().rev().cloned()
+            .find(|b| b.is_available())
+            .unwrap_or(Backend::Scalar)
+    }
+
+    fn from_index(i: u8) -> Self {
+        match i {
+            1 => Backend::Avx2,
+            2 => Backend::Simd,
+            _ => Backend::Scalar,
+        }
+    }
+
+    fn index(self) -> u8 {
+        match self {
+            Backend::Scalar => 0,
+            Backend::Avx2 => 1,
+            Backend::Simd => 2,
+        }
+    }
+}
+
+#[cfg(all(feature = "avx2", target_arch = "x86_64",
+    not(feature = "small-tables")))]
+fn avx2_available() -> bool {
+    std::is_x86_feature_detected!("avx2")
+}
+
+#[cfg(not(all(feature = "avx2", target_arch = "x86_64",
+    not(feature = "small-tables"))))]
+fn avx2_available() -> bool {
+    false
+}
+
+/// The backend used by `multi::compress4` and `multi::hash4`
+///
+/// It is detected on the first call and cached afterwards, unless it was
+/// selected with `force_backend` before.
+pub fn backend() -> Backend {
+    match SELECTED.load(Ordering::Relaxed) {
+        0 => {
+            let detected = Backend::detect();
+            // a backend forced concurrently takes precedence
+            let _ = SELECTED.compare_exchange(0, detected.index() + 1,
+                Ordering::Relaxed, Ordering::Relaxed);
+            Backend::from_index(SELECTED.load(Ordering::Relaxed) - 1)
+        }
+        i => Backend::from_index(i - 1),
+    }
+}
+
+/// Use `backend` for all following calls in this process, e.g. to test or
+/// benchmark a particular implementation
+///
+/// Fails if the backend is not available, see `Backend::is_available`.
+pub fn force_backend(backend: Backend) -> Result<(), UnsupportedBackend> {
+    if !backend.is_available() {
+        return Err(UnsupportedBackend);
+    }
+    SELECTED.store(backend.index() + 1, Ordering::Relaxed);
+    Ok(())
+}
+
+pub fn compress4(states: &mut [[u64; 3]; 4], blocks: [&Block; 4]) {
+    match backend() {
+        #[cfg(all(feature = "avx2", target_arch = "x86_64",
+            not(feature = "small-tables")))]
+        // only selected if AVX2 support was detected
+        #[allow(unsafe_code)]
+        Backend::Avx2 => unsafe { avx2::compress4(states, blocks) },
+        #[cfg(feature = "simd")]
+        Backend::Simd => simd::compress4(states, blocks),
+        _ => scalar4(states, blocks),
+    }
+}
+
+#[cfg(feature = "constant-time")]
+fn scalar4(states: &mut [[u64; 3]; 4], blocks: [&Block; 4]) {
+    for (state, block) in states.iter_mut().zip(blocks.iter()) {
+        ::compress(state, core::slice::from_ref(*block));
+    }
+}
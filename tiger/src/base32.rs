@@ -0,0 +1,97 @@
+use core::fmt;
+use core::str;
+#[cfg(feature = "std")]
+use std::error;
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::U24;
+
+/// Length of an unpadded Base32 encoded 24 byte digest
+pub const BASE32_LEN: usize = 39;
+
+const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// The error type for decoding of Base32 encoded digests
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InvalidBase32;
+
+impl fmt::Display for InvalidBase32 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid base32 digest")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for InvalidBase32 {
+    fn description(&self) -> &str {
+        "invalid base32 digest"
+    }
+}
+
+/// Encode a 24 byte digest as an unpadded RFC 4648 Base32 string, which is
+/// the representation used for TTH roots.
+///
+/// The encoded string is written into `buf` and returned.
+pub fn encode_base32<'a>(
+    digest: &GenericArray<u8, U24>, buf: &'a mut [u8; BASE32_LEN],
+) -> &'a str {
+    encode(digest, buf);
+    str::from_utf8(buf).expect("base32 alphabet is ASCII")
+}
+
+/// Encode `data` as unpadded Base32 into `buf`, which must be exactly
+/// `(8 * data.len() + 4) / 5` bytes long
+pub(crate) fn encode(data: &[u8], buf: &mut [u8]) {
+    debug_assert_eq!(buf.len(), (8 * data.len() + 4) / 5);
+    let mut acc = 0u16;
+    let mut bits = 0;
+    let mut pos = 0;
+    for &byte in data.iter() {
+        acc = (acc << 8) | u16::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            buf[pos] = ALPHABET[((acc >> bits) & 0x1f) as usize];
+            pos += 1;
+        }
+    }
+    // remaining bits are padded with zeros on the right
+    if bits > 0 {
+        buf[pos] = ALPHABET[((acc << (5 - bits)) & 0x1f) as usize];
+    }
+}
+
+/// Decode an unpadded RFC 4648 Base32 string into a 24 byte digest.
+///
+/// Both upper and lower case letters are accepted. Returns an error if the
+/// string has a wrong length, contains characters outside of the Base32
+/// alphabet or has non-zero trailing bits.
+pub fn decode_base32(s: &str)
+    -> Result<GenericArray<u8, U24>, InvalidBase32>
+{
+    let s = s.as_bytes();
+    if s.len() != BASE32_LEN { return Err(InvalidBase32); }
+
+    let mut out = GenericArray::default();
+    let mut acc = 0u16;
+    let mut bits = 0;
+    let mut pos = 0;
+    for &c in s {
+        let v = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a',
+            b'2'..=b'7' => c - b'2' + 26,
+            _ => return Err(InvalidBase32),
+        };
+        acc = (acc << 5) | u16::from(v);
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out[pos] = (acc >> bits) as u8;
+            pos += 1;
+        }
+        acc &= (1 << bits) - 1;
+    }
+    if acc != 0 { return Err(InvalidBase32); }
+
+    Ok(out)
+}
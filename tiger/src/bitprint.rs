@@ -0,0 +1,94 @@
+//! Bitprints, the combined SHA-1 and TTH identifiers used by Gnutella and
+//! Bitzi
+use core::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+use digest::{Digest, Input};
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::{U20, U24};
+use sha1::Sha1;
+use base32::{encode, BASE32_LEN};
+use tth::TigerTree;
+
+const BUFFER_SIZE: usize = 64 * 1024;
+const SHA1_BASE32_LEN: usize = 32;
+const PREFIX: &str = "urn:bitprint:";
+
+/// Length of a bitprint URN, `urn:bitprint:<SHA-1>.<TTH>` with both
+/// digests in unpadded Base32
+pub const BITPRINT_LEN: usize = 13 + SHA1_BASE32_LEN + 1 + BASE32_LEN;
+
+/// SHA-1 digest and Tiger Tree root of the same data
+///
+/// Formatting with `Display` gives the `urn:bitprint:` URN.
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// let bitprint = tiger::bitprint_reader(&b""[..]).unwrap();
+/// assert_eq!(bitprint.to_string(), "urn:bitprint:\
+///     3I42H3S6NNFQ2MSVX7XZKYAYSCX5QBYJ.LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ");
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bitprint {
+    sha1: GenericArray<u8, U20>,
+    tth: GenericArray<u8, U24>,
+}
+
+impl Bitprint {
+    /// Compute the bitprint of `data`
+    pub fn new(data: &[u8]) -> Self {
+        Bitprint { sha1: Sha1::digest(data), tth: TigerTree::digest(data) }
+    }
+
+    /// SHA-1 digest of the data
+    pub fn sha1(&self) -> &GenericArray<u8, U20> {
+        &self.sha1
+    }
+
+    /// Tiger Tree root of the data
+    pub fn tth(&self) -> &GenericArray<u8, U24> {
+        &self.tth
+    }
+}
+
+impl fmt::Display for Bitprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut buf = [0u8; BITPRINT_LEN];
+        buf[..PREFIX.len()].copy_from_slice(PREFIX.as_bytes());
+        let (sha1, rest) = buf[PREFIX.len()..].split_at_mut(SHA1_BASE32_LEN);
+        encode(&self.sha1, sha1);
+        rest[0] = b'.';
+        encode(&self.tth, &mut rest[1..]);
+        f.write_str(core::str::from_utf8(&buf).expect("bitprint is ASCII"))
+    }
+}
+
+/// Compute the bitprint of all data read from `reader`
+///
+/// Both digests are computed in a single pass, `reader` does not need to be
+/// buffered, see `hash_reader`.
+pub fn bitprint_reader<R: Read>(mut reader: R) -> io::Result<Bitprint> {
+    let mut sha1 = Sha1::new();
+    let mut tth = TigerTree::new();
+    let mut buf = ::std::vec::from_elem(0u8, BUFFER_SIZE);
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => {
+                Input::input(&mut sha1, &buf[..n]);
+                Input::input(&mut tth, &buf[..n]);
+            }
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(Bitprint { sha1: sha1.result(), tth: tth.result() })
+}
+
+/// Compute the bitprint of the file at `path`, see `bitprint_reader`
+pub fn bitprint_file<P: AsRef<Path>>(path: P) -> io::Result<Bitprint> {
+    bitprint_reader(File::open(path)?)
+}
@@ -0,0 +1,148 @@
+//! C interface modelled after the reference `tiger.c`, enabled by the
+//! `capi` feature
+//!
+//! The `tiger-capi` crate in this repository builds these functions into a
+//! shared and a static library and ships the matching `tiger.h` header.
+//! The hashing context is a plain C struct, so that callers can allocate
+//! it on the stack, but its fields must be treated as opaque.
+//!
+//! All functions accept a null `data` pointer when the length is zero and
+//! do nothing when passed a null context or digest pointer. None of them
+//! panics, messages longer than `MAX_MESSAGE_LEN` are reported with a
+//! return value of -1 instead.
+use core::ffi::c_int;
+use core::slice;
+use digest::generic_array::typenum::U3;
+use tiger::{Snapshot, TigerEngine};
+use {variant, MAX_MESSAGE_LEN};
+
+/// Size of a Tiger digest in bytes
+pub const TIGER_DIGEST_SIZE: usize = 24;
+
+type Engine = TigerEngine<variant::Tiger, U3>;
+
+/// Hashing context of the C interface, `tiger_ctx` in `tiger.h`
+///
+/// The fields are the snapshot of a `Tiger` hasher, so that C knows the
+/// size of the context, but they must be treated as opaque.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct TigerCtx {
+    state: [u64; 3],
+    length: u64,
+    buffer: [u8; 64],
+}
+
+impl TigerCtx {
+    fn new() -> Self {
+        Self::from_engine(&Engine::new())
+    }
+
+    fn from_engine(engine: &Engine) -> Self {
+        let snapshot = engine.snapshot();
+        TigerCtx {
+            state: snapshot.state,
+            length: snapshot.length,
+            buffer: snapshot.buffer,
+        }
+    }
+
+    fn engine(&self) -> Engine {
+        Engine::from_snapshot(&Snapshot {
+            state: self.state,
+            length: self.length,
+            buffer: self.buffer,
+        })
+    }
+}
+
+/// Whether `len` more bytes can be hashed after the first `length` bytes,
+/// checked up front as the hasher panics otherwise
+fn fits(length: u64, len: usize) -> bool {
+    length.checked_add(len as u64).is_some_and(|len| len <= MAX_MESSAGE_LEN)
+}
+
+/// Turn a C buffer into a slice, accepting null for empty buffers
+unsafe fn input<'a>(data: *const u8, len: usize) -> &'a [u8] {
+    if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(data, len)
+    }
+}
+
+/// Initialize `ctx` for hashing a new message
+///
+/// # Safety
+///
+/// `ctx` must be null or point to writable memory for a `TigerCtx`.
+#[no_mangle]
+pub unsafe extern "C" fn tiger_init(ctx: *mut TigerCtx) {
+    if let Some(ctx) = ctx.as_mut() {
+        *ctx = TigerCtx::new();
+    }
+}
+
+/// Hash `len` bytes at `data` into `ctx`
+///
+/// Returns 0 on success and -1, leaving `ctx` unchanged, if the message
+/// would exceed `MAX_MESSAGE_LEN` bytes.
+///
+/// # Safety
+///
+/// `ctx` must be null or point to a context set up by `tiger_init`, and
+/// `data` must be valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn tiger_update(ctx: *mut TigerCtx, data: *const u8, len: usize)
+    -> c_int
+{
+    if let Some(ctx) = ctx.as_mut() {
+        if !fits(ctx.length, len) {
+            return -1;
+        }
+        let mut engine = ctx.engine();
+        engine.input(input(data, len));
+        *ctx = TigerCtx::from_engine(&engine);
+    }
+    0
+}
+
+/// Write the 24 byte Tiger digest of the message hashed into `ctx` to
+/// `digest` and re-initialize `ctx`
+///
+/// # Safety
+///
+/// `ctx` must be null or point to a context set up by `tiger_init`, and
+/// `digest` must be valid for writes of 24 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn tiger_final(ctx: *mut TigerCtx, digest: *mut u8) {
+    if let Some(ctx) = ctx.as_mut() {
+        if !digest.is_null() {
+            let out = slice::from_raw_parts_mut(digest, TIGER_DIGEST_SIZE);
+            ctx.engine().finalize_into(out);
+        }
+        *ctx = TigerCtx::new();
+    }
+}
+
+/// Write the 24 byte Tiger digest of `len` bytes at `data` to `digest`
+///
+/// Returns 0 on success and -1, writing nothing, if `len` exceeds
+/// `MAX_MESSAGE_LEN`.
+///
+/// # Safety
+///
+/// `data` must be valid for reads of `len` bytes and `digest` must be valid
+/// for writes of 24 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn tiger_hash(data: *const u8, len: usize, digest: *mut u8) -> c_int {
+    if !fits(0, len) {
+        return -1;
+    }
+    if !digest.is_null() {
+        let mut engine = Engine::new();
+        engine.input(input(data, len));
+        engine.finalize_into(slice::from_raw_parts_mut(digest, TIGER_DIGEST_SIZE));
+    }
+    0
+}
@@ -0,0 +1,168 @@
+//! Flat chunked hashing with per-chunk digests
+use core::fmt;
+use std::io;
+use std::slice;
+use std::vec::Vec;
+use digest::{Digest, Input, Reset};
+use digest::generic_array::GenericArray;
+use Tiger;
+
+type Output<D> = GenericArray<u8, <D as Digest>::OutputSize>;
+
+/// Default chunk size of `ChunkedHasher`, 4 MiB
+pub const DEFAULT_CHUNK_SIZE: usize = 4 << 20;
+
+/// Hasher which splits the message into fixed-size chunks and records the
+/// digest of every chunk
+///
+/// The overall digest is the digest of the concatenated chunk digests. This
+/// flat scheme allows verification of single chunks without the cost of a
+/// full tree hash. All chunks but the last one are exactly `chunk_size`
+/// bytes long, the empty message consists of a single empty chunk.
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// use tiger::{ChunkedHasher, Digest, Tiger};
+/// use tiger::digest::Input;
+///
+/// let mut hasher = ChunkedHasher::<Tiger>::new(4);
+/// hasher.input(b"abcdefghij");
+/// let result = hasher.finalize();
+/// assert_eq!(result.chunks().len(), 3);
+/// assert_eq!(*result.chunk(1).unwrap(), Tiger::digest(b"efgh"));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ChunkedHasher<D: Digest + Clone = Tiger> {
+    chunk_size: usize,
+    hasher: D,
+    filled: usize,
+    chunks: Vec<Output<D>>,
+}
+
+impl<D: Digest + Clone> Default for ChunkedHasher<D> {
+    fn default() -> Self {
+        Self::new(DEFAULT_CHUNK_SIZE)
+    }
+}
+
+impl<D: Digest + Clone> ChunkedHasher<D> {
+    /// Create new hasher splitting the message into `chunk_size` byte chunks
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_size` is zero.
+    pub fn new(chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk size must not be zero");
+        ChunkedHasher { chunk_size, hasher: D::new(), filled: 0, chunks: Vec::new() }
+    }
+
+    /// Size of the chunks
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Retrieve the digests of all chunks and the overall digest
+    pub fn finalize(mut self) -> ChunkedDigest<D> {
+        if self.filled > 0 || self.chunks.is_empty() {
+            self.chunks.push(self.hasher.result_reset());
+        }
+        let mut hasher = D::new();
+        for chunk in self.chunks.iter() {
+            hasher.input(chunk);
+        }
+        ChunkedDigest {
+            root: hasher.result(),
+            chunk_size: self.chunk_size,
+            chunks: self.chunks,
+        }
+    }
+}
+
+impl<D: Digest + Clone> Input for ChunkedHasher<D> {
+    fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+        let mut data = input.as_ref();
+        while !data.is_empty() {
+            let n = data.len().min(self.chunk_size - self.filled);
+            self.hasher.input(&data[..n]);
+            self.filled += n;
+            data = &data[n..];
+            if self.filled == self.chunk_size {
+                self.chunks.push(self.hasher.result_reset());
+                self.filled = 0;
+            }
+        }
+    }
+}
+
+impl<D: Digest + Clone> Reset for ChunkedHasher<D> {
+    fn reset(&mut self) {
+        self.hasher.reset();
+        self.filled = 0;
+        self.chunks.clear();
+    }
+}
+
+impl<D: Digest + Clone> fmt::Debug for ChunkedHasher<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ChunkedHasher {{ ... }}")
+    }
+}
+
+impl<D: Digest + Clone> io::Write for ChunkedHasher<D> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Input::input(self, buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Chunk digests and overall digest computed by `ChunkedHasher`
+#[derive(Clone, Debug)]
+pub struct ChunkedDigest<D: Digest + Clone = Tiger> {
+    root: Output<D>,
+    chunk_size: usize,
+    chunks: Vec<Output<D>>,
+}
+
+impl<D: Digest + Clone> PartialEq for ChunkedDigest<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.chunk_size == other.chunk_size && self.chunks == other.chunks
+    }
+}
+
+impl<D: Digest + Clone> Eq for ChunkedDigest<D> {}
+
+impl<D: Digest + Clone> ChunkedDigest<D> {
+    /// Digest of the concatenated chunk digests
+    pub fn root(&self) -> &Output<D> {
+        &self.root
+    }
+
+    /// Size of the chunks
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Iterate over the chunk digests in message order
+    pub fn chunks<'a>(&'a self) -> slice::Iter<'a, Output<D>> {
+        self.chunks.iter()
+    }
+
+    /// Digest of the `index`-th chunk, `None` if `index` is out of range
+    pub fn chunk(&self, index: usize) -> Option<&Output<D>> {
+        self.chunks.get(index)
+    }
+
+    /// Check that `data` is the `index`-th chunk of the message
+    pub fn verify_chunk(&self, index: usize, data: &[u8]) -> bool {
+        match self.chunks.get(index) {
+            Some(chunk) => *chunk == D::digest(data),
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,342 @@
+//! Exchange of complete Tiger trees in the THEX XML + DIME format
+//!
+//! Gnutella and Shareaza peers transfer tree data as the [THEX][1]
+//! serialization: a [DIME][2] message whose first record is an XML
+//! document describing the file and tree, and whose second record holds
+//! the tree nodes in breadth-first order, starting with the root. The tree
+//! may be cut off below some depth to save bandwidth.
+//!
+//! ```rust
+//! # extern crate tiger;
+//! # fn main() {
+//! use std::io;
+//! use tiger::dime::HashTree;
+//! use tiger::tth::TigerTreeLevel;
+//!
+//! let data = vec![0u8; 5000];
+//! let mut tree = TigerTreeLevel::new(0);
+//! io::copy(&mut &data[..], &mut tree).unwrap();
+//! let tree = HashTree::new(&tree.finalize(), data.len() as u64);
+//! assert_eq!(tree.depth(), 4);
+//!
+//! let mut dime = Vec::new();
+//! tree.write_dime("uuid:09233523-345b-4351-b623-5dfa35c95d61", &mut dime)
+//!     .unwrap();
+//! assert_eq!(HashTree::parse_dime(&dime).unwrap(), tree);
+//! # }
+//! ```
+//!
+//! [1]: https://adc.sourceforge.io/draft-jchapweske-thex-02.html
+//! [2]: https://tools.ietf.org/html/draft-nielsen-dime-02
+use core::fmt;
+use std::error;
+use std::io;
+use std::{format, str, vec};
+use std::vec::Vec;
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::U24;
+use thex::LEAF_SIZE;
+use tth::{TigerTree, TigerTreeNodes};
+use MAX_MESSAGE_LEN;
+
+type Node = GenericArray<u8, U24>;
+
+const NODE_SIZE: usize = 24;
+const XML_TYPE: &str = "text/xml";
+const TREE_TYPE: &str = "http://open-content.net/spec/thex/breadthfirst";
+const DIGEST_ALGORITHM: &str = "http://open-content.net/spec/digest/tiger";
+
+// DIME header: version 1 in the top five bits of the first byte, followed
+// by the message begin, message end and chunk flags
+const DIME_VERSION: u8 = 0x08;
+const DIME_MB: u8 = 0x04;
+const DIME_ME: u8 = 0x02;
+const DIME_CF: u8 = 0x01;
+// Type formats, stored in the top four bits of the second byte
+const TYPE_MEDIA: u8 = 0x10;
+const TYPE_URI: u8 = 0x20;
+const DIME_HEADER_SIZE: usize = 12;
+
+/// The error type for parsing of THEX tree data
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InvalidThex;
+
+impl fmt::Display for InvalidThex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid THEX tree data")
+    }
+}
+
+impl error::Error for InvalidThex {
+    fn description(&self) -> &str {
+        "invalid THEX tree data"
+    }
+}
+
+/// Tiger tree of a file with all nodes down to some depth
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashTree {
+    file_size: u64,
+    // levels[0] holds the root, every further level the children of the
+    // previous one
+    levels: Vec<Vec<Node>>,
+}
+
+impl HashTree {
+    /// Build the tree of a `file_size` byte file from the nodes of one of
+    /// its levels, the upper levels are computed from them
+    ///
+    /// Recording level 0 (the leaves) gives the complete tree.
+    pub fn new(nodes: &TigerTreeNodes, file_size: u64) -> Self {
+        let mut level: Vec<Node> = nodes.leaves().cloned().collect();
+        if level.is_empty() {
+            level.push(*nodes.root());
+        }
+        let mut levels = vec![level];
+        while levels[0].len() > 1 {
+            let parent = parent_level(&levels[0]);
+            levels.insert(0, parent);
+        }
+        HashTree { file_size, levels }
+    }
+
+    /// Tree root
+    pub fn root(&self) -> &Node {
+        &self.levels[0][0]
+    }
+
+    /// Size of the file in bytes
+    pub fn file_size(&self) -> u64 {
+        self.file_size
+    }
+
+    /// Number of levels present, including the root
+    pub fn depth(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Nodes of the `index`-th level below the root, level 0 is the root
+    /// itself
+    pub fn level(&self, index: usize) -> Option<&[Node]> {
+        self.levels.get(index).map(|l| &l[..])
+    }
+
+    /// Drop all levels below `depth`, which must be at least one
+    pub fn truncate(&mut self, depth: usize) {
+        self.levels.truncate(depth.max(1));
+    }
+
+    /// Write the tree as THEX DIME message, the tree record is identified by
+    /// `uri`, usually a random `uuid:` URI
+    ///
+    /// Fails with `InvalidInput` if `uri` can not be embedded in an XML
+    /// attribute or exceeds the DIME length limits.
+    pub fn write_dime<W: io::Write>(&self, uri: &str, w: &mut W) -> io::Result<()> {
+        if uri.is_empty() || uri.contains(&['\'', '"', '<', '>', '&'][..])
+            || uri.len() > 0xffff
+        {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid tree URI"));
+        }
+        let xml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <!DOCTYPE hashtree SYSTEM \"http://open-content.net/spec/thex/thex.dtd\">\n\
+             <hashtree>\n\
+             \t<file size='{}' segmentsize='{}'/>\n\
+             \t<digest algorithm='{}' outputsize='{}'/>\n\
+             \t<serializedtree depth='{}' type='{}' uri='{}'/>\n\
+             </hashtree>\n",
+            self.file_size, LEAF_SIZE, DIGEST_ALGORITHM, NODE_SIZE,
+            self.depth(), TREE_TYPE, uri,
+        );
+        let mut nodes = Vec::new();
+        for node in self.levels.iter().flat_map(|l| l.iter()) {
+            nodes.extend_from_slice(node);
+        }
+        if nodes.len() > u32::MAX as usize {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "tree too large"));
+        }
+        write_record(w, DIME_MB, TYPE_MEDIA, "", XML_TYPE, xml.as_bytes())?;
+        write_record(w, DIME_ME, TYPE_URI, uri, TREE_TYPE, &nodes)
+    }
+
+    /// Parse a THEX DIME message created by `write_dime` or another THEX
+    /// implementation
+    ///
+    /// Only Tiger trees with 1 KiB segments are accepted. Every level is
+    /// checked against the level above it, so the result is consistent, but
+    /// it is up to the caller to compare the root with a trusted value.
+    pub fn parse_dime(mut data: &[u8]) -> Result<Self, InvalidThex> {
+        let mut records = Vec::new();
+        let mut first = true;
+        loop {
+            let (record, rest) = read_record(data, first)?;
+            first = false;
+            data = rest;
+            let last = record.last;
+            records.push(record);
+            if last {
+                break;
+            }
+        }
+
+        let xml = records.iter().find(|r| r.type_ == XML_TYPE.as_bytes())
+            .ok_or(InvalidThex)?;
+        let xml = str::from_utf8(xml.data).map_err(|_| InvalidThex)?;
+        let file = element(xml, "file")?;
+        let digest = element(xml, "digest")?;
+        let tree = element(xml, "serializedtree")?;
+        let file_size: u64 = number(attribute(file, "size")?)?;
+        let depth: usize = number(attribute(tree, "depth")?)?;
+        if number::<usize>(attribute(file, "segmentsize")?)? != LEAF_SIZE
+            || attribute(digest, "algorithm")? != DIGEST_ALGORITHM
+            || number::<usize>(attribute(digest, "outputsize")?)? != NODE_SIZE
+            || attribute(tree, "type")? != TREE_TYPE
+            || depth == 0
+            || file_size > MAX_MESSAGE_LEN
+        {
+            return Err(InvalidThex);
+        }
+        let uri = attribute(tree, "uri")?;
+        let nodes = records.iter().find(|r| r.id == uri.as_bytes())
+            .ok_or(InvalidThex)?
+            .data;
+
+        // Level sizes from the root down, levels below the leaves do not
+        // exist regardless of `depth`
+        let leaves = (file_size + LEAF_SIZE as u64 - 1) / LEAF_SIZE as u64;
+        let mut sizes = vec![leaves.max(1)];
+        while sizes[0] > 1 {
+            sizes.insert(0, (sizes[0] + 1) / 2);
+        }
+        sizes.truncate(depth);
+        let total = sizes.iter().fold(0u64, |acc, &n| acc.saturating_add(n));
+        if total.checked_mul(NODE_SIZE as u64) != Some(nodes.len() as u64) {
+            return Err(InvalidThex);
+        }
+
+        let mut chunks = nodes.chunks(NODE_SIZE).map(GenericArray::clone_from_slice);
+        let mut levels: Vec<Vec<Node>> = Vec::with_capacity(sizes.len());
+        for &size in sizes.iter() {
+            let level: Vec<Node> = chunks.by_ref().take(size as usize).collect();
+            if let Some(parent) = levels.last() {
+                if parent_level(&level) != *parent {
+                    return Err(InvalidThex);
+                }
+            }
+            levels.push(level);
+        }
+        Ok(HashTree { file_size, levels })
+    }
+}
+
+/// Combine pairs of nodes into the next level up, promoting an unpaired
+/// last node
+fn parent_level(nodes: &[Node]) -> Vec<Node> {
+    nodes.chunks(2).map(|pair| match pair.len() {
+        2 => TigerTree::node_hash(&pair[0], &pair[1]),
+        _ => pair[0],
+    }).collect()
+}
+
+fn padding(len: usize) -> usize {
+    (4 - len % 4) % 4
+}
+
+fn write_record<W: io::Write>(
+    w: &mut W, flags: u8, type_format: u8, id: &str, type_: &str, data: &[u8],
+) -> io::Result<()> {
+    let mut header = [0u8; DIME_HEADER_SIZE];
+    header[0] = DIME_VERSION | flags;
+    header[1] = type_format;
+    header[4..6].copy_from_slice(&(id.len() as u16).to_be_bytes());
+    header[6..8].copy_from_slice(&(type_.len() as u16).to_be_bytes());
+    header[8..12].copy_from_slice(&(data.len() as u32).to_be_bytes());
+    w.write_all(&header)?;
+    for field in [id.as_bytes(), type_.as_bytes(), data].iter() {
+        w.write_all(field)?;
+        w.write_all(&[0u8; 3][..padding(field.len())])?;
+    }
+    Ok(())
+}
+
+struct Record<'a> {
+    last: bool,
+    id: &'a [u8],
+    type_: &'a [u8],
+    data: &'a [u8],
+}
+
+fn read_record<'a>(data: &'a [u8], first: bool)
+    -> Result<(Record<'a>, &'a [u8]), InvalidThex>
+{
+    if data.len() < DIME_HEADER_SIZE {
+        return Err(InvalidThex);
+    }
+    let flags = data[0];
+    if flags & 0xf8 != DIME_VERSION || flags & DIME_CF != 0
+        || (flags & DIME_MB != 0) != first
+    {
+        return Err(InvalidThex);
+    }
+    let be16 = |i: usize| usize::from(u16::from_be_bytes([data[i], data[i + 1]]));
+    let lengths = [
+        be16(2), be16(4), be16(6),
+        u32::from_be_bytes([data[8], data[9], data[10], data[11]]) as usize,
+    ];
+    let mut fields = [&data[..0]; 4];
+    let mut rest = &data[DIME_HEADER_SIZE..];
+    for (field, &len) in fields.iter_mut().zip(lengths.iter()) {
+        let padded = len.checked_add(padding(len)).ok_or(InvalidThex)?;
+        if rest.len() < padded {
+            return Err(InvalidThex);
+        }
+        *field = &rest[..len];
+        rest = &rest[padded..];
+    }
+    let record = Record {
+        last: flags & DIME_ME != 0,
+        id: fields[1],
+        type_: fields[2],
+        data: fields[3],
+    };
+    Ok((record, rest))
+}
+
+/// Attributes of the first empty element `<name .../>` in `xml`
+fn element<'a>(xml: &'a str, name: &str) -> Result<&'a str, InvalidThex> {
+    let tag = format!("<{}", name);
+    let mut search = xml;
+    while let Some(pos) = search.find(&tag[..]) {
+        let rest = &search[pos + tag.len()..];
+        if rest.starts_with(char::is_whitespace) {
+            let end = rest.find('>').ok_or(InvalidThex)?;
+            return Ok(rest[..end].trim_end_matches('/'));
+        }
+        search = rest;
+    }
+    Err(InvalidThex)
+}
+
+/// Value of attribute `name` in the attribute list `attrs`
+fn attribute<'a>(attrs: &'a str, name: &str) -> Result<&'a str, InvalidThex> {
+    let mut rest = attrs.trim_start();
+    while !rest.is_empty() {
+        let eq = rest.find('=').ok_or(InvalidThex)?;
+        let key = rest[..eq].trim_end();
+        let value = rest[eq + 1..].trim_start();
+        let quote = value.chars().next().ok_or(InvalidThex)?;
+        if quote != '\'' && quote != '"' {
+            return Err(InvalidThex);
+        }
+        let end = value[1..].find(quote).ok_or(InvalidThex)?;
+        if key == name {
+            return Ok(&value[1..end + 1]);
+        }
+        rest = value[end + 2..].trim_start();
+    }
+    Err(InvalidThex)
+}
+
+fn number<T: str::FromStr>(s: &str) -> Result<T, InvalidThex> {
+    s.parse().map_err(|_| InvalidThex)
+}
@@ -0,0 +1,182 @@
+//! Concurrent hashing of many files on a rayon thread pool
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::vec::Vec;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use io::digest_reader;
+use {to_array, Tiger};
+
+/// Compute the Tiger digests of the files at `paths` concurrently, see
+/// `HashFiles` for the details and for other options
+///
+/// ```rust,no_run
+/// # extern crate tiger;
+/// # fn main() {
+/// for (path, sum) in tiger::hash_files(&["a.iso", "b.iso"]) {
+///     match sum {
+///         Ok(sum) => println!("{:?}: {:?}", path, sum),
+///         Err(e) => eprintln!("{:?}: {}", path, e),
+///     }
+/// }
+/// # }
+/// ```
+pub fn hash_files<I>(paths: I) -> Vec<(PathBuf, io::Result<[u8; 24]>)>
+    where I: IntoIterator, I::Item: AsRef<Path>
+{
+    HashFiles::new().run(paths)
+}
+
+/// Builder for hashing many files concurrently
+///
+/// The results are returned in the order of the input paths, every path
+/// with its own result, so a missing or unreadable file does not affect
+/// the others. By default the files are hashed on the global rayon thread
+/// pool and symbolic links are followed.
+///
+/// ```rust,no_run
+/// # extern crate tiger;
+/// # fn main() {
+/// use std::sync::Arc;
+/// use std::sync::atomic::AtomicBool;
+/// use tiger::HashFiles;
+///
+/// let cancel = Arc::new(AtomicBool::new(false));
+/// let results = HashFiles::new()
+///     .threads(4)
+///     .follow_symlinks(false)
+///     .cancel_flag(cancel.clone())
+///     .run(&["a.iso", "b.iso"]);
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct HashFiles {
+    threads: usize,
+    no_follow: bool,
+    cancel: Option<Arc<AtomicBool>>,
+}
+
+impl HashFiles {
+    /// Create builder with the default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hash on a dedicated pool of `threads` threads instead of the global
+    /// rayon pool, zero restores the default
+    ///
+    /// This bounds the number of files open at the same time.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Whether to follow symbolic links, when disabled a path naming a
+    /// symbolic link fails with an `InvalidInput` error
+    ///
+    /// On Unix the file is opened with `O_NOFOLLOW`. Elsewhere the path is
+    /// checked before it is opened, so a symbolic link created in between
+    /// is followed.
+    pub fn follow_symlinks(mut self, follow: bool) -> Self {
+        self.no_follow = !follow;
+        self
+    }
+
+    /// Stop hashing as soon as `cancel` is set
+    ///
+    /// Files not completely hashed at that point fail with an error of kind
+    /// `Other`, already computed digests are still returned.
+    pub fn cancel_flag(mut self, cancel: Arc<AtomicBool>) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Hash the files at `paths`
+    pub fn run<I>(&self, paths: I) -> Vec<(PathBuf, io::Result<[u8; 24]>)>
+        where I: IntoIterator, I::Item: AsRef<Path>
+    {
+        let paths: Vec<PathBuf> = paths.into_iter()
+            .map(|p| p.as_ref().to_path_buf())
+            .collect();
+        let hash_all = || {
+            paths.into_par_iter()
+                .map(|path| {
+                    let sum = self.hash_file(&path);
+                    (path, sum)
+                })
+                .collect()
+        };
+        let pool = match self.threads {
+            0 => None,
+            n => ThreadPoolBuilder::new().num_threads(n).build().ok(),
+        };
+        match pool {
+            Some(pool) => pool.install(hash_all),
+            // fall back to the global pool if no threads could be spawned
+            None => hash_all(),
+        }
+    }
+
+    fn hash_file(&self, path: &Path) -> io::Result<[u8; 24]> {
+        self.check_cancel()?;
+        let reader = Cancellable { inner: self.open(path)?, files: self };
+        digest_reader::<Tiger, _, _>(reader, u64::MAX, |_| ()).map(to_array)
+    }
+
+    #[cfg(unix)]
+    fn open(&self, path: &Path) -> io::Result<File> {
+        use std::os::unix::fs::OpenOptionsExt;
+
+        let mut options = OpenOptions::new();
+        options.read(true);
+        if !self.no_follow {
+            return options.open(path);
+        }
+        // the error for a symbolic link differs between systems, so the
+        // path is only inspected after opening failed
+        options.custom_flags(libc::O_NOFOLLOW).open(path).map_err(|err| {
+            if is_symlink(path) { symlink_error() } else { err }
+        })
+    }
+
+    #[cfg(not(unix))]
+    fn open(&self, path: &Path) -> io::Result<File> {
+        if self.no_follow && is_symlink(path) {
+            return Err(symlink_error());
+        }
+        OpenOptions::new().read(true).open(path)
+    }
+
+    fn check_cancel(&self) -> io::Result<()> {
+        match self.cancel {
+            Some(ref cancel) if cancel.load(Ordering::Relaxed) => {
+                Err(io::Error::new(io::ErrorKind::Other, "hashing cancelled"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn is_symlink(path: &Path) -> bool {
+    fs::symlink_metadata(path).map(|m| m.file_type().is_symlink()).unwrap_or(false)
+}
+
+fn symlink_error() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "symbolic link not followed")
+}
+
+/// Reader failing once the cancel flag is set
+struct Cancellable<'a, R> {
+    inner: R,
+    files: &'a HashFiles,
+}
+
+impl<'a, R: Read> Read for Cancellable<'a, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.files.check_cancel()?;
+        self.inner.read(buf)
+    }
+}
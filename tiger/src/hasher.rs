@@ -0,0 +1,40 @@
+use core::hash::Hasher;
+use state::read_u64;
+use digest::{Digest, Input};
+use Tiger;
+
+/// Adapter implementing `core::hash::Hasher` with Tiger
+///
+/// `finish` returns the first 8 bytes of the Tiger digest of all written
+/// data interpreted as a little-endian integer. This allows to derive
+/// stable content identifiers from any type implementing `Hash`, e.g. with
+/// `BuildHasherDefault<TigerHasher>`.
+///
+/// Tiger is considerably slower than SipHash, which is used by the standard
+/// library by default, so this adapter should not be used for hash tables
+/// unless their hash values have to be reproducible. Note that the data
+/// passed to `Hasher` by `Hash` implementations may depend on the platform,
+/// e.g. for `usize` values.
+#[derive(Clone, Default)]
+pub struct TigerHasher {
+    hasher: Tiger,
+}
+
+impl TigerHasher {
+    /// Create new hasher instance
+    pub const fn new() -> Self {
+        TigerHasher { hasher: Tiger::new() }
+    }
+}
+
+impl Hasher for TigerHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        Input::input(&mut self.hasher, bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        read_u64(&self.hasher.clone().result())
+    }
+}
+
+impl_opaque_debug!(TigerHasher);
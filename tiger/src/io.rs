@@ -0,0 +1,232 @@
+//! Hashing of data read through `std::io`
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use digest::Digest;
+use digest::generic_array::GenericArray;
+use {to_array, Tiger};
+
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Reader which hashes all bytes read through it with `D`
+///
+/// Useful for hashing a download while it is written to disk. Only bytes
+/// actually returned by the inner reader are hashed.
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// use std::io::{self, Read};
+/// use tiger::{HashingReader, Tiger};
+///
+/// let mut reader = HashingReader::<_, Tiger>::new(&b"abc"[..]);
+/// let mut sink = Vec::new();
+/// io::copy(&mut reader, &mut sink).unwrap();
+/// assert_eq!(reader.finalize()[..], tiger::hash(b"abc")[..]);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct HashingReader<R: Read, D: Digest> {
+    inner: R,
+    hasher: D,
+}
+
+impl<R: Read, D: Digest> HashingReader<R, D> {
+    /// Wrap `inner` with a new hasher
+    pub fn new(inner: R) -> Self {
+        HashingReader { inner, hasher: D::new() }
+    }
+
+    /// Wrap `inner` with the given hasher, e.g. one which already processed
+    /// a prefix of the data
+    pub fn with_hasher(inner: R, hasher: D) -> Self {
+        HashingReader { inner, hasher }
+    }
+
+    /// Get a reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner reader
+    ///
+    /// Bytes read directly from it are not hashed.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Split into the inner reader and the hasher
+    pub fn into_parts(self) -> (R, D) {
+        (self.inner, self.hasher)
+    }
+
+    /// Retrieve the hash of all bytes read so far
+    pub fn finalize(self) -> GenericArray<u8, D::OutputSize> {
+        self.hasher.result()
+    }
+}
+
+impl<R: Read, D: Digest> Read for HashingReader<R, D> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.input(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// Writer which hashes all bytes written through it with `D`
+///
+/// The counterpart of `HashingReader`, e.g. for hashing a file while it is
+/// copied to disk or sent over a socket. Only bytes accepted by the inner
+/// writer are hashed, so the digest matches what was actually written even
+/// after a short or failed write.
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// use std::io::{self, Write};
+/// use tiger::{HashingWriter, Tiger};
+///
+/// let mut writer = HashingWriter::<_, Tiger>::new(Vec::new());
+/// io::copy(&mut &b"abc"[..], &mut writer).unwrap();
+/// assert_eq!(writer.get_ref(), b"abc");
+/// assert_eq!(writer.finalize()[..], tiger::hash(b"abc")[..]);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct HashingWriter<W: Write, D: Digest> {
+    inner: W,
+    hasher: D,
+}
+
+impl<W: Write, D: Digest> HashingWriter<W, D> {
+    /// Wrap `inner` with a new hasher
+    pub fn new(inner: W) -> Self {
+        HashingWriter { inner, hasher: D::new() }
+    }
+
+    /// Wrap `inner` with the given hasher, e.g. one which already processed
+    /// a prefix of the data
+    pub fn with_hasher(inner: W, hasher: D) -> Self {
+        HashingWriter { inner, hasher }
+    }
+
+    /// Get a reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner writer
+    ///
+    /// Bytes written directly to it are not hashed.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwrap the inner writer, discarding the hasher
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Split into the inner writer and the hasher
+    pub fn into_parts(self) -> (W, D) {
+        (self.inner, self.hasher)
+    }
+
+    /// Retrieve the hash of all bytes written so far
+    pub fn finalize(self) -> GenericArray<u8, D::OutputSize> {
+        self.hasher.result()
+    }
+}
+
+impl<W: Write, D: Digest> Write for HashingWriter<W, D> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.input(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Compute the Tiger digest of all data read from `reader`
+///
+/// Reads are done with a 64 KiB buffer, short reads and interrupted reads
+/// are handled, so `reader` does not need to be buffered.
+pub fn hash_reader<R: Read>(reader: R) -> io::Result<[u8; 24]> {
+    digest_reader::<Tiger, _, _>(reader, u64::MAX, |_| ()).map(to_array)
+}
+
+/// Compute the Tiger digest of the file at `path`, see `hash_reader`
+pub fn hash_file<P: AsRef<Path>>(path: P) -> io::Result<[u8; 24]> {
+    hash_reader(File::open(path)?)
+}
+
+/// Compute the Tiger digest of all data read from `reader`, calling
+/// `progress` with the number of bytes hashed so far every `interval` bytes
+///
+/// Progress is reported after every read which brings the count at least
+/// `interval` bytes past the previous report, so with an interval below
+/// the 64 KiB buffer size reports may be less frequent. When the end of
+/// `reader` is reached `progress` is called with the total length, unless
+/// that value was just reported.
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// let data = vec![0u8; 1 << 20];
+/// let mut reports = Vec::new();
+/// let sum = tiger::hash_reader_with_progress(&data[..], 256 * 1024, |done| {
+///     reports.push(done);
+/// }).unwrap();
+/// assert_eq!(sum, tiger::hash(&data));
+/// assert_eq!(reports.last(), Some(&(1 << 20)));
+/// # }
+/// ```
+pub fn hash_reader_with_progress<R, F>(reader: R, interval: u64, progress: F)
+    -> io::Result<[u8; 24]>
+    where R: Read, F: FnMut(u64)
+{
+    digest_reader::<Tiger, _, _>(reader, interval, progress).map(to_array)
+}
+
+/// Compute the Tiger digest of the file at `path` with progress reports,
+/// see `hash_reader_with_progress`
+pub fn hash_file_with_progress<P, F>(path: P, interval: u64, progress: F)
+    -> io::Result<[u8; 24]>
+    where P: AsRef<Path>, F: FnMut(u64)
+{
+    hash_reader_with_progress(File::open(path)?, interval, progress)
+}
+
+/// Hash all data read from `reader` with `D`, reporting progress as
+/// described for `hash_reader_with_progress`
+pub(crate) fn digest_reader<D, R, F>(mut reader: R, interval: u64, mut progress: F)
+    -> io::Result<GenericArray<u8, D::OutputSize>>
+    where D: Digest, R: Read, F: FnMut(u64)
+{
+    let mut hasher = D::new();
+    let mut buf = ::std::vec::from_elem(0u8, BUFFER_SIZE);
+    let (mut done, mut reported) = (0u64, 0u64);
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => {
+                hasher.input(&buf[..n]);
+                done += n as u64;
+                if done - reported >= interval {
+                    progress(done);
+                    reported = done;
+                }
+            }
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+            Err(e) => return Err(e),
+        }
+    }
+    if done != reported || done == 0 {
+        progress(done);
+    }
+    Ok(hasher.result())
+}
@@ -0,0 +1,283 @@
+//! Key derivation and mask generation functions built on top of Tiger
+#[cfg(feature = "hkdf")]
+use core::fmt;
+#[cfg(all(feature = "hkdf", feature = "std"))]
+use std::error;
+use digest::generic_array::GenericArray;
+#[cfg(feature = "hkdf")]
+use digest::generic_array::typenum::U24;
+use digest::generic_array::typenum::Unsigned;
+#[cfg(any(feature = "pbkdf2", feature = "hkdf"))]
+use hmac::Mac;
+#[cfg(any(feature = "pbkdf2", feature = "hkdf"))]
+use HmacTiger;
+use digest::{Digest, XofReader};
+use Tiger;
+
+#[cfg(any(feature = "pbkdf2", feature = "hkdf"))]
+const OUTPUT_SIZE: usize = 24;
+
+/// The error type for requests of too much output from a key derivation
+/// function
+#[cfg(feature = "hkdf")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InvalidOutputLength;
+
+#[cfg(feature = "hkdf")]
+impl fmt::Display for InvalidOutputLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid output length")
+    }
+}
+
+#[cfg(all(feature = "hkdf", feature = "std"))]
+impl error::Error for InvalidOutputLength {
+    fn description(&self) -> &str {
+        "invalid output length"
+    }
+}
+
+/// Block counter encoded as a big-endian 32-bit integer
+///
+/// # Panics
+///
+/// If `i` does not fit into 32 bits.
+pub(crate) fn counter(i: u64) -> [u8; 4] {
+    assert!(i < 1 << 32, "counter overflow");
+    let i = i as u32;
+    [(i >> 24) as u8, (i >> 16) as u8, (i >> 8) as u8, i as u8]
+}
+
+/// Fill `out` with a mask generated from `seed` by MGF1 (PKCS #1, RFC 8017)
+/// with Tiger as the underlying hash function
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// let mut mask = [0u8; 100];
+/// tiger::mgf1_tiger(b"seed", &mut mask);
+/// # }
+/// ```
+pub fn mgf1_tiger(seed: &[u8], out: &mut [u8]) {
+    // MGF1 is KDF1 without the optional info
+    kdf1_tiger(seed, b"", out);
+}
+
+/// Counter-mode key derivation function KDF1 or KDF2 of ISO 18033-2
+/// generic over the hash function `D`, reading the derived key as a stream
+///
+/// Output block `i` is `H(secret || i || info)` with `i` a big-endian
+/// 32-bit counter starting at 0 for KDF1 and at 1 for KDF2, which is also
+/// the key derivation function of ANSI X9.63 with `info` as shared info.
+/// ISO 18033-2 itself has no `info`, pass an empty slice for it. KDF1 with
+/// empty `info` is MGF1. At most `2^32` output blocks can be read for KDF1
+/// and `2^32 - 1` for KDF2, reading beyond the range of the counter panics.
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// use tiger::CounterKdf;
+/// use tiger::digest::XofReader;
+///
+/// let mut kdf = CounterKdf::<tiger::Tiger>::kdf2(b"shared secret", b"");
+/// let (mut enc_key, mut mac_key) = ([0u8; 16], [0u8; 24]);
+/// kdf.read(&mut enc_key);
+/// kdf.read(&mut mac_key);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CounterKdf<'a, D: Digest + Clone = Tiger> {
+    hasher: D,
+    info: &'a [u8],
+    counter: u64,
+    block: GenericArray<u8, D::OutputSize>,
+    pos: usize,
+}
+
+impl<'a, D: Digest + Clone> CounterKdf<'a, D> {
+    fn new(secret: &[u8], info: &'a [u8], counter: u64) -> Self {
+        let mut hasher = D::new();
+        hasher.input(secret);
+        Self::from_hasher(hasher, info, counter)
+    }
+
+    /// Counter mode over `hasher`, which has already absorbed the secret
+    pub(crate) fn from_hasher(hasher: D, info: &'a [u8], counter: u64) -> Self {
+        CounterKdf {
+            hasher,
+            info,
+            counter,
+            block: GenericArray::default(),
+            pos: D::OutputSize::to_usize(),
+        }
+    }
+
+    /// KDF1, with the counter starting at 0
+    pub fn kdf1(secret: &[u8], info: &'a [u8]) -> Self {
+        Self::new(secret, info, 0)
+    }
+
+    /// KDF2, with the counter starting at 1
+    pub fn kdf2(secret: &[u8], info: &'a [u8]) -> Self {
+        Self::new(secret, info, 1)
+    }
+
+    fn next_block(&mut self) {
+        let mut hasher = self.hasher.clone();
+        hasher.input(counter(self.counter));
+        hasher.input(self.info);
+        self.block = hasher.result();
+        self.counter += 1;
+        self.pos = 0;
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<'a> CounterKdf<'a, Tiger> {
+    /// Overwrite the hasher and the current output block with zeros
+    pub(crate) fn wipe(&mut self) {
+        use zeroize::Zeroize;
+
+        self.hasher.zeroize();
+        self.block.zeroize();
+        self.pos.zeroize();
+    }
+}
+
+impl<'a, D: Digest + Clone> XofReader for CounterKdf<'a, D> {
+    fn read(&mut self, buffer: &mut [u8]) {
+        let size = D::OutputSize::to_usize();
+        let mut buffer = buffer;
+        while !buffer.is_empty() {
+            if self.pos == size {
+                self.next_block();
+            }
+            let n = core::cmp::min(size - self.pos, buffer.len());
+            let (l, r) = {buffer}.split_at_mut(n);
+            l.copy_from_slice(&self.block[self.pos..self.pos + n]);
+            self.pos += n;
+            buffer = r;
+        }
+    }
+}
+
+impl<'a, D: Digest + Clone> core::fmt::Debug for CounterKdf<'a, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "CounterKdf {{ ... }}")
+    }
+}
+
+/// Fill `out` with a key derived from `secret` and `info` by KDF1 (ISO
+/// 18033-2) with Tiger, see `CounterKdf`
+pub fn kdf1_tiger(secret: &[u8], info: &[u8], out: &mut [u8]) {
+    CounterKdf::<Tiger>::kdf1(secret, info).read(out);
+}
+
+/// Fill `out` with a key derived from `secret` and `info` by KDF2 (ISO
+/// 18033-2, ANSI X9.63) with Tiger, see `CounterKdf`
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// let mut key = [0u8; 32];
+/// tiger::kdf2_tiger(b"shared secret", b"context", &mut key);
+/// # }
+/// ```
+pub fn kdf2_tiger(secret: &[u8], info: &[u8], out: &mut [u8]) {
+    CounterKdf::<Tiger>::kdf2(secret, info).read(out);
+}
+
+/// Derive key from `password` and `salt` into `out` using PBKDF2 (RFC 2898)
+/// with HMAC-Tiger as the pseudorandom function
+///
+/// `iterations` must not be zero.
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// let mut key = [0u8; 32];
+/// tiger::pbkdf2_tiger(b"password", b"salt", 4096, &mut key);
+/// # }
+/// ```
+#[cfg(feature = "pbkdf2")]
+pub fn pbkdf2_tiger(password: &[u8], salt: &[u8], iterations: u32,
+    out: &mut [u8])
+{
+    assert!(iterations != 0, "PBKDF2 requires at least one iteration");
+    let prf = HmacTiger::new_varkey(password)
+        .expect("HMAC accepts keys of any length");
+
+    for (i, chunk) in out.chunks_mut(OUTPUT_SIZE).enumerate() {
+        let mut mac = prf.clone();
+        mac.input(salt);
+        mac.input(&counter(i as u64 + 1));
+        let mut u = mac.result().code();
+        let mut t = u;
+        for _ in 1..iterations {
+            let mut mac = prf.clone();
+            mac.input(&u);
+            u = mac.result().code();
+            for (a, b) in t.iter_mut().zip(u.iter()) {
+                *a ^= *b;
+            }
+        }
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+}
+
+/// HKDF-Extract step (RFC 5869) with HMAC-Tiger, returning the pseudorandom
+/// key derived from input keying material `ikm` and an optional `salt`
+///
+/// An empty `salt` is equivalent to a salt of 24 zero bytes.
+#[cfg(feature = "hkdf")]
+pub fn hkdf_tiger_extract(salt: &[u8], ikm: &[u8]) -> GenericArray<u8, U24> {
+    let mut mac = HmacTiger::new_varkey(salt)
+        .expect("HMAC accepts keys of any length");
+    mac.input(ikm);
+    mac.result().code()
+}
+
+/// HKDF-Expand step (RFC 5869) with HMAC-Tiger, filling `okm` with output
+/// keying material derived from pseudorandom key `prk` and context `info`
+///
+/// At most `255 * 24` bytes of output can be produced.
+#[cfg(feature = "hkdf")]
+pub fn hkdf_tiger_expand(prk: &[u8], info: &[u8], okm: &mut [u8])
+    -> Result<(), InvalidOutputLength>
+{
+    if okm.len() > 255 * OUTPUT_SIZE { return Err(InvalidOutputLength); }
+
+    let prf = HmacTiger::new_varkey(prk)
+        .expect("HMAC accepts keys of any length");
+    let mut prev: Option<GenericArray<u8, U24>> = None;
+    for (i, chunk) in okm.chunks_mut(OUTPUT_SIZE).enumerate() {
+        let mut mac = prf.clone();
+        if let Some(ref t) = prev {
+            mac.input(t);
+        }
+        mac.input(info);
+        mac.input(&[i as u8 + 1]);
+        let t = mac.result().code();
+        chunk.copy_from_slice(&t[..chunk.len()]);
+        prev = Some(t);
+    }
+    Ok(())
+}
+
+/// Derive `okm` from input keying material `ikm`, `salt` and context `info`
+/// using HKDF (RFC 5869) with HMAC-Tiger, i.e. `hkdf_tiger_extract`
+/// followed by `hkdf_tiger_expand`
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// let mut okm = [0u8; 42];
+/// tiger::hkdf_tiger(b"salt", b"secret", b"context", &mut okm).unwrap();
+/// # }
+/// ```
+#[cfg(feature = "hkdf")]
+pub fn hkdf_tiger(salt: &[u8], ikm: &[u8], info: &[u8], okm: &mut [u8])
+    -> Result<(), InvalidOutputLength>
+{
+    hkdf_tiger_expand(&hkdf_tiger_extract(salt, ikm), info, okm)
+}
@@ -0,0 +1,116 @@
+//! Envelope MAC with Tiger, the keyed hashing used before HMAC
+use crypto_mac::{InvalidKeyLength, Mac, MacResult};
+use digest::Input;
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::U24;
+use {FixedOutputInto, Tiger};
+
+const MAX_KEY_LEN: usize = 64;
+
+/// The envelope ("sandwich") MAC `Tiger(pad(K) || M || K)` with Tiger
+///
+/// `pad(K)` is the key followed by the Tiger padding of a message of the
+/// key's length, i.e. the key is hashed as if it was a complete message
+/// and the message starts on a fresh block, as RFC 1828 specifies for
+/// MD5. The key is appended again after the message. Keys may be up to 64
+/// bytes long, `new` takes a 24 byte key.
+///
+/// Compatibility with existing deployments is unverified: no legacy
+/// implementation was available, the construction follows the RFC 1828
+/// layout and the test vectors only check it against the Tiger reference
+/// implementation applied to `pad(K) || M || K`.
+///
+/// **Prefer HMAC (`HmacTiger`) for anything new.** The envelope method is
+/// only provided for interoperability with legacy systems. Its security
+/// argument relies on stronger assumptions on the compression function
+/// than HMAC, key recovery attacks faster than brute force are known for
+/// the MD5 instance, and keyed hashes of this kind were never
+/// standardized with Tiger. The key is kept in the MAC state, with the
+/// `zeroize` feature it is wiped together with the inner hashers on drop.
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// use tiger::KeyedTiger;
+/// use tiger::crypto_mac::Mac;
+///
+/// let mut mac = KeyedTiger::new_varkey(b"secret").unwrap();
+/// mac.input(b"message");
+/// let code = mac.result().code();
+///
+/// let mut mac = KeyedTiger::new_varkey(b"secret").unwrap();
+/// mac.input(b"message");
+/// assert!(mac.verify(&code).is_ok());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct KeyedTiger {
+    // the state after processing pad(K), restored by reset
+    init: Tiger,
+    hasher: Tiger,
+    key: [u8; MAX_KEY_LEN],
+    key_len: usize,
+}
+
+impl KeyedTiger {
+    fn with_key(key: &[u8]) -> Self {
+        debug_assert!(key.len() <= MAX_KEY_LEN);
+        let mut padded = [0u8; 2 * MAX_KEY_LEN];
+        padded[..key.len()].copy_from_slice(key);
+        padded[key.len()] = 0x01;
+        let len = if key.len() < 56 { MAX_KEY_LEN } else { 2 * MAX_KEY_LEN };
+        let bits = (key.len() as u64) << 3;
+        padded[len - 8..len].copy_from_slice(&bits.to_le_bytes());
+
+        let mut init = Tiger::default();
+        init.input(&padded[..len]);
+        let mut buf = [0u8; MAX_KEY_LEN];
+        buf[..key.len()].copy_from_slice(key);
+        KeyedTiger { hasher: init.clone(), init, key: buf, key_len: key.len() }
+    }
+
+    /// Overwrite the key and both inner hashers with zeros
+    #[cfg(feature = "zeroize")]
+    pub(crate) fn wipe(&mut self) {
+        use zeroize::Zeroize;
+
+        self.init.zeroize();
+        self.hasher.zeroize();
+        self.key.zeroize();
+        self.key_len.zeroize();
+    }
+}
+
+impl Mac for KeyedTiger {
+    type OutputSize = U24;
+    type KeySize = U24;
+
+    fn new(key: &GenericArray<u8, U24>) -> Self {
+        Self::with_key(key)
+    }
+
+    fn new_varkey(key: &[u8]) -> Result<Self, InvalidKeyLength> {
+        if key.len() > MAX_KEY_LEN {
+            return Err(InvalidKeyLength);
+        }
+        Ok(Self::with_key(key))
+    }
+
+    fn input(&mut self, data: &[u8]) {
+        self.hasher.input(data);
+    }
+
+    fn reset(&mut self) {
+        self.hasher = self.init.clone();
+    }
+
+    fn result(mut self) -> MacResult<U24> {
+        self.hasher.input(&self.key[..self.key_len]);
+        let mut out = GenericArray::default();
+        self.hasher.fixed_result_into(&mut out);
+        MacResult::new(out)
+    }
+}
+
+impl_opaque_debug!(KeyedTiger);
+
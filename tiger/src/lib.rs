@@ -0,0 +1,630 @@
+//! An implementation of the [Tiger][1] cryptographic hash algorithm.
+//!
+//! Tiger was designed by Ross Anderson and Eli Biham in 1995 for efficiency
+//! on 64-bit platforms. This crate provides two variants:
+//!
+//! * `Tiger`, the original algorithm, padding messages with a `0x01` byte
+//! * `Tiger2`, which only differs by the MD4-style `0x80` padding byte
+//!
+//! Both share the same buffering and compression code, the padding is
+//! selected by the markers of the [`variant`](variant/index.html) module.
+//!
+//! Additionally the standard truncations of the Tiger digest are available:
+//!
+//! * `Tiger160`, the first 160 bits (20 bytes) of the Tiger digest
+//! * `Tiger128`, the first 128 bits (16 bytes) of the Tiger digest
+//!
+//! `VarTiger` implements `VariableOutput` for truncations selected at run
+//! time.
+//!
+//! Messages whose length is not a multiple of 8 bits are finalized with
+//! `result_bits`, which takes the trailing partial byte and its bit count.
+//! The bit-level API is self-consistent, but its bit order is not verified
+//! against published bit-oriented vectors.
+//!
+//! Tiger with more than the standard three passes can be computed with
+//! `TigerP`.
+//!
+//! The Tiger Tree Hash (TTH) used by file sharing protocols is available in
+//! the [`tth`](tth/index.html) module. The underlying THEX tree
+//! construction can be used with other hash functions through the
+//! [`thex`](thex/index.html) module.
+//!
+//! TTH values are usually exchanged as unpadded Base32 strings, see
+//! `encode_base32` and `decode_base32`, or as part of
+//! [`magnet`](magnet/index.html) links. Complete trees are exchanged with
+//! legacy peers in the THEX XML + DIME format provided by the
+//! [`dime`](dime/index.html) module.
+//!
+//! `ChunkedHasher` (requires `std`) implements the simpler flat scheme of
+//! per-chunk digests and a digest over them.
+//!
+//! The [`merkle`](merkle/index.html) module (requires `alloc`) builds trees
+//! over arbitrary items with inclusion proofs.
+//!
+//! With the `bitprint` feature enabled `bitprint_reader` and
+//! `bitprint_file` compute the SHA-1 digest and TTH root of a file in a
+//! single pass, formatted as `urn:bitprint:` URN.
+//!
+//! `mgf1_tiger` implements the PKCS #1 mask generation function with Tiger,
+//! the same construction is available as the extendable-output function
+//! `TigerXof`. The related KDF1 and KDF2 of ISO 18033-2 are provided by
+//! `kdf1_tiger`, `kdf2_tiger` and, for other hash functions, `CounterKdf`.
+//!
+//! With the `hmac` feature enabled the `HmacTiger` type alias provides
+//! HMAC-TIGER192 as used by a number of legacy protocols, and the `pbkdf2`
+//! and `hkdf` features add `pbkdf2_tiger` and `hkdf_tiger` on top of it.
+//!
+//! The `keyed` feature adds `KeyedTiger`, the envelope MAC of pre-HMAC
+//! systems, which should not be used for new designs.
+//!
+//! Digests can be converted into `TigerDigest`, which implements hex
+//! formatting and parsing, and with the `subtle` feature `ConstantTimeEq`.
+//! Use `verify` to check a Tiger digest without timing side channels and
+//! `hash_digest` to print a digest without allocating.
+//!
+//! With the `alloc` feature (enabled by `std`) `hash_hex`, `hash_hex_upper`
+//! and the `to_hex`, `to_hex_upper` and `to_base32` methods of
+//! `TigerDigest` return owned strings.
+//!
+//! Historic values printed by the reference implementation as three
+//! big-endian words can be matched with `LegacyOutput`.
+//!
+//! The bare compression function is available as `compress` for tooling
+//! which handles buffering and padding itself, `TigerCore` takes whole
+//! blocks without buffering them but still applies the padding. Many short
+//! messages can be hashed faster with the interleaved four-lane
+//! implementation in the [`multi`](multi/index.html) module.
+//!
+//! The state of a hasher can be exported with `serialize_state` and later
+//! restored with `deserialize_state`, e.g. to resume hashing of a large
+//! file in another session:
+//!
+//! ```rust
+//! # extern crate tiger;
+//! # fn main() {
+//! use tiger::{Tiger, Digest};
+//!
+//! let mut hasher = Tiger::new();
+//! hasher.input(b"first part, ");
+//! let state = hasher.serialize_state();
+//!
+//! let mut hasher = Tiger::deserialize_state(&state).unwrap();
+//! hasher.input(b"second part");
+//! assert_eq!(hasher.result(), Tiger::digest(b"first part, second part"));
+//! # }
+//! ```
+//!
+//! With the `serde` feature enabled the hasher types implement `Serialize`
+//! and `Deserialize`, which allows to persist an in-progress hash.
+//!
+//! With the `hasher` feature enabled `TigerHasher` adapts Tiger to the
+//! `core::hash::Hasher` trait.
+//!
+//! `HashingReader` and `HashingWriter` (require `std`) hash data while it
+//! is read from another reader or written to another writer, `hash_reader`
+//! and `hash_file` hash a whole reader or file.
+//!
+//! `hash_reader_with_progress` and `hash_file_with_progress`, and their
+//! counterparts in the `tth` module, additionally report the number of
+//! bytes hashed to a callback, e.g. to drive a progress bar.
+//!
+//! With the `async` feature enabled `AsyncHashingReader`,
+//! `AsyncHashingWriter` and `hash_async_reader` do the same for
+//! `futures-io` readers and writers. The `async-tokio` feature adds
+//! implementations of the Tokio traits.
+//!
+//! The `mmap` feature adds `hash_file_mmap` which hashes a memory-mapped
+//! file without copying it through a buffer.
+//!
+//! With the `capi` feature enabled the [`capi`](capi/index.html) module
+//! exports `tiger_init`, `tiger_update`, `tiger_final` and `tiger_hash` C
+//! functions, the `tiger-capi` crate builds them into a C library.
+//!
+//! With the `wasm` feature enabled the [`wasm`](wasm/index.html) module
+//! exports Tiger and TTH hashing to JavaScript through `wasm-bindgen`.
+//!
+//! With the `zeroize` feature enabled the hasher types implement `Zeroize`
+//! and wipe their internal state on drop.
+//!
+//! With the `parallel` feature enabled tree hashes of large inputs can be
+//! computed on the rayon thread pool, see `Thex::hash_slice_parallel` and
+//! `Thex::hash_file_parallel`, and `hash_files` hashes many files
+//! concurrently.
+//!
+//! With the `small-tables` feature enabled the 8 KiB of S-box constants are
+//! not stored in the binary, instead they are generated into RAM on first
+//! use. This is meant for targets where flash is scarcer than RAM, the
+//! one-time generation costs about as much as hashing 100 KiB of data.
+//!
+//! With the `compact` feature enabled the rounds of the compression
+//! function are compiled as loops instead of being fully unrolled, which
+//! reduces code size at the cost of speed.
+//!
+//! On 32-bit targets a compression function working on 32-bit halves of the
+//! state words is used automatically, unless one of the two features above
+//! is enabled.
+//!
+//! With the `prefetch` feature enabled, on x86-64 the S-box entries of
+//! every round are requested with prefetch instructions as soon as their
+//! indices are known (not combined with `small-tables` or `compact`).
+//! Whether this helps depends on the CPU, so measure first.
+//!
+//! With the `constant-time` feature enabled the S-box lookups scan the
+//! whole tables with masks instead of indexing them, so no memory access
+//! depends on the message or the chaining variables. This protects keyed
+//! uses such as HMAC against cache-timing attacks, but makes hashing about
+//! a hundred times slower. The feature takes precedence over all features
+//! above.
+//!
+//! The `hazmat` feature adds `with_iv` constructors to the hashers, which
+//! start from chaining variables other than the Tiger initial values. They
+//! are meant for cryptanalysis and other experiments, the resulting digests
+//! are not interoperable with any other Tiger implementation.
+//!
+//! With the `trace` feature enabled the [`trace`](trace/index.html) module
+//! reports the chaining variables after every round to a callback, e.g. to
+//! debug ports of Tiger to other languages.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate tiger;
+//! # fn main() {
+//! use tiger::{Tiger, Digest};
+//!
+//! // create a Tiger hasher instance
+//! let mut hasher = Tiger::new();
+//!
+//! // process input message
+//! hasher.input(b"abc");
+//!
+//! // acquire hash digest in the form of GenericArray,
+//! // which in this case is equivalent to [u8; 24]
+//! let result = hasher.result();
+//! assert_eq!(result[..], hex!("
+//!     2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93
+//! ")[..]);
+//! # }
+//! ```
+//!
+//! For one-off hashing of a buffer `hash` and `hash2` return the Tiger and
+//! Tiger2 digests as plain arrays.
+//!
+//! Also see [RustCrypto/hashes][2] readme.
+//!
+//! [1]: https://www.cs.technion.ac.il/~biham/Reports/Tiger/
+//! [2]: https://github.com/RustCrypto/hashes
+#![no_std]
+#![cfg_attr(not(any(feature = "avx2", feature = "capi", feature = "prefetch",
+    feature = "mmap")), forbid(unsafe_code))]
+#![cfg_attr(any(feature = "avx2", feature = "capi", feature = "prefetch",
+    feature = "mmap"), deny(unsafe_code))]
+#![doc(html_logo_url =
+    "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png")]
+#[macro_use] extern crate opaque_debug;
+#[macro_use] pub extern crate digest;
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "async")]
+extern crate futures_io;
+#[cfg(all(feature = "parallel", unix))]
+extern crate libc;
+#[cfg(feature = "mmap")]
+extern crate memmap2;
+#[cfg(feature = "parallel")]
+extern crate rayon;
+#[cfg(feature = "keyed")]
+pub extern crate crypto_mac;
+#[cfg(feature = "hmac")]
+pub extern crate hmac;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(feature = "bitprint")]
+extern crate sha1;
+#[cfg(feature = "zeroize")]
+extern crate zeroize;
+#[cfg(feature = "subtle")]
+extern crate subtle;
+#[cfg(feature = "async-tokio")]
+extern crate tokio;
+#[cfg(feature = "wasm")]
+extern crate wasm_bindgen;
+#[cfg(feature = "simd")]
+extern crate wide;
+
+pub use digest::Digest;
+use digest::{Input, BlockInput, FixedOutput, VariableOutput, Reset};
+use digest::InvalidOutputSize;
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::{Unsigned, U3, U16, U20, U24, U64};
+
+#[cfg(all(feature = "avx2", target_arch = "x86_64",
+    not(feature = "small-tables")))]
+#[cfg_attr(feature = "avx2", allow(unsafe_code))]
+mod avx2;
+#[cfg(feature = "async")]
+mod async_io;
+mod backend;
+mod base32;
+#[cfg(feature = "bitprint")]
+mod bitprint;
+#[cfg(feature = "capi")]
+#[cfg_attr(feature = "capi", allow(unsafe_code))]
+pub mod capi;
+#[cfg(feature = "std")]
+mod chunked;
+#[cfg(not(feature = "small-tables"))]
+mod consts;
+#[cfg(feature = "std")]
+pub mod dime;
+#[cfg(feature = "parallel")]
+mod files;
+#[cfg(feature = "hasher")]
+mod hasher;
+#[cfg(feature = "std")]
+mod io;
+mod kdf;
+#[cfg(feature = "keyed")]
+mod keyed;
+#[cfg(feature = "std")]
+pub mod magnet;
+#[cfg(feature = "mmap")]
+#[cfg_attr(feature = "mmap", allow(unsafe_code))]
+mod mmap;
+#[macro_use]
+mod macros;
+#[cfg(any(test, feature = "constant-time"))]
+mod masked;
+#[cfg(feature = "alloc")]
+pub mod merkle;
+pub mod multi;
+#[cfg(all(any(test, all(target_pointer_width = "32",
+    not(feature = "constant-time"))),
+    not(any(feature = "small-tables", feature = "compact"))))]
+mod narrow;
+mod output;
+#[cfg(all(feature = "prefetch", target_arch = "x86_64",
+    not(any(feature = "small-tables", feature = "compact",
+        feature = "constant-time"))))]
+#[cfg_attr(feature = "prefetch", allow(unsafe_code))]
+mod prefetch;
+#[cfg(feature = "small-tables")]
+mod sboxes;
+#[cfg(feature = "small-tables")]
+use sboxes as consts;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "simd")]
+mod simd;
+mod snapshot;
+mod state;
+mod tables;
+mod tiger;
+pub mod thex;
+#[cfg(feature = "trace")]
+pub mod trace;
+pub mod tth;
+mod unbuffered;
+pub mod variant;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "zeroize")]
+mod wipe;
+mod xof;
+
+use state::TigerState;
+use tiger::TigerEngine;
+
+pub use base32::{encode_base32, decode_base32, InvalidBase32, BASE32_LEN};
+#[cfg(feature = "bitprint")]
+pub use bitprint::{bitprint_file, bitprint_reader, Bitprint, BITPRINT_LEN};
+#[cfg(feature = "std")]
+pub use chunked::{ChunkedDigest, ChunkedHasher, DEFAULT_CHUNK_SIZE};
+#[cfg(feature = "parallel")]
+pub use files::{hash_files, HashFiles};
+#[cfg(feature = "hasher")]
+pub use hasher::TigerHasher;
+#[cfg(feature = "async")]
+pub use async_io::{
+    hash_async_reader, AsyncHashingReader, AsyncHashingWriter, HashAsyncReader,
+};
+#[cfg(feature = "std")]
+pub use io::{
+    hash_file, hash_file_with_progress, hash_reader, hash_reader_with_progress,
+    HashingReader, HashingWriter,
+};
+pub use kdf::{kdf1_tiger, kdf2_tiger, mgf1_tiger, CounterKdf};
+#[cfg(feature = "keyed")]
+pub use keyed::KeyedTiger;
+#[cfg(feature = "mmap")]
+pub use mmap::hash_file_mmap;
+pub use output::{hash_digest, verify, InvalidHex, LegacyOutput, TigerDigest};
+#[cfg(feature = "alloc")]
+pub use output::{hash_hex, hash_hex_upper};
+pub use snapshot::{InvalidState, STATE_SIZE};
+pub use tiger::{FixedOutputInto, MAX_MESSAGE_LEN};
+pub use unbuffered::TigerCore;
+pub use xof::{TigerXof, TigerXofReader};
+#[cfg(feature = "pbkdf2")]
+pub use kdf::pbkdf2_tiger;
+#[cfg(feature = "hkdf")]
+pub use kdf::{
+    hkdf_tiger, hkdf_tiger_extract, hkdf_tiger_expand, InvalidOutputLength,
+};
+
+/// Size of a Tiger message block in bytes
+pub const BLOCK_SIZE: usize = 64;
+
+/// Size of a Tiger digest in bytes
+pub const DIGEST_SIZE: usize = 24;
+
+/// Block size of the Tiger hashers as `typenum` number, see `BLOCK_SIZE`
+pub type BlockSize = U64;
+
+/// Block of the Tiger compression function
+pub type Block = GenericArray<u8, BlockSize>;
+
+/// Digest of `Tiger`, `Tiger2` and the other full size hashers as returned
+/// by the `digest` traits
+pub type Output = GenericArray<u8, U24>;
+
+/// Digest of the full size hashers as plain array, as returned by `hash`
+pub type TigerOutput = [u8; DIGEST_SIZE];
+
+/// The three-pass Tiger compression function
+///
+/// Updates the chaining variables `state` with every block of `blocks` in
+/// sequence. No buffering, padding or length processing is performed, so
+/// the message is expected to be already split into padded blocks. The
+/// Tiger initial state is `[0x0123456789ABCDEF, 0xFEDCBA9876543210,
+/// 0xF096A5B4C3B2E187]`.
+pub fn compress(state: &mut [u64; 3], blocks: &[Block]) {
+    let mut s = TigerState::from_words(*state);
+    s.process_blocks(blocks, 3);
+    *state = s.words();
+}
+
+impl_tiger!(Tiger, U24, variant::Tiger, "The Tiger hasher");
+impl_tiger!(Tiger2, U24, variant::Tiger2, "The Tiger2 hasher");
+impl_tiger!(Tiger160, U20, variant::Tiger,
+    "The Tiger/160 hasher, i.e. Tiger truncated to 160 bits");
+impl_tiger!(Tiger128, U16, variant::Tiger,
+    "The Tiger/128 hasher, i.e. Tiger truncated to 128 bits");
+
+/// Compute the Tiger digest of `data`
+///
+/// ```rust
+/// # #[macro_use] extern crate hex_literal;
+/// # extern crate tiger;
+/// # fn main() {
+/// assert_eq!(tiger::hash(b"abc"),
+///     hex!("2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93"));
+/// # }
+/// ```
+pub fn hash<B: AsRef<[u8]>>(data: B) -> TigerOutput {
+    to_array(Tiger::digest(data.as_ref()))
+}
+
+/// Compute the Tiger2 digest of `data`
+pub fn hash2<B: AsRef<[u8]>>(data: B) -> TigerOutput {
+    to_array(Tiger2::digest(data.as_ref()))
+}
+
+fn to_array(digest: Output) -> TigerOutput {
+    let mut out = [0u8; 24];
+    out.copy_from_slice(&digest);
+    out
+}
+
+/// The Tiger hasher with output size selected at run time
+///
+/// The output is the Tiger digest truncated to between 1 and 24 bytes, so
+/// the sizes 16, 20 and 24 are equivalent to `Tiger128`, `Tiger160` and
+/// `Tiger` respectively.
+#[derive(Clone)]
+pub struct VarTiger {
+    engine: TigerEngine<variant::Tiger, U3>,
+    output_size: usize,
+}
+
+impl BlockInput for VarTiger {
+    type BlockSize = BlockSize;
+}
+
+impl Input for VarTiger {
+    fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+        self.engine.input(input.as_ref());
+    }
+}
+
+impl VariableOutput for VarTiger {
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        if output_size == 0 || output_size > U24::to_usize() {
+            return Err(InvalidOutputSize);
+        }
+        Ok(VarTiger { engine: Default::default(), output_size })
+    }
+
+    fn output_size(&self) -> usize {
+        self.output_size
+    }
+
+    fn variable_result<F: FnOnce(&[u8])>(mut self, f: F) {
+        let out = self.engine.finalize();
+        f(&out[..self.output_size]);
+    }
+}
+
+impl Reset for VarTiger {
+    fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+impl_opaque_debug!(VarTiger);
+impl_write!(VarTiger);
+
+/// HMAC-TIGER192, i.e. HMAC instantiated with `Tiger`
+///
+/// The HMAC key is padded to the 64 byte Tiger block size, longer keys are
+/// hashed first.
+#[cfg(feature = "hmac")]
+pub type HmacTiger = hmac::Hmac<Tiger>;
+
+/// The Tiger hasher with a configurable number of passes `P`
+///
+/// The Tiger paper recommends at least three passes (the number used by
+/// `Tiger`), but explicitly allows more of them for an extra security margin.
+/// As in the reference implementation every pass after the third one uses
+/// the multiplier 9. `P` must be at least 3.
+///
+/// ```rust
+/// # #[macro_use] extern crate hex_literal;
+/// # extern crate tiger;
+/// # fn main() {
+/// use tiger::{TigerP, Digest};
+/// use tiger::digest::generic_array::typenum::U4;
+///
+/// let result = TigerP::<U4>::digest(b"");
+/// assert_eq!(result[..], hex!("
+///     24cc78a7f6ff3546e7984e59695ca13d804e0b686e255194
+/// ")[..]);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TigerP<P: Unsigned = U3> {
+    engine: TigerEngine<variant::Tiger, P>,
+}
+
+impl<P: Unsigned> Default for TigerP<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Unsigned> TigerP<P> {
+    /// Create new hasher instance, which can also be used in constant
+    /// expressions
+    pub const fn new() -> Self {
+        assert!(P::USIZE >= 3, "Tiger requires at least 3 passes");
+        TigerP { engine: TigerEngine::new() }
+    }
+
+    /// Create hasher starting from the chaining variables `a`, `b` and `c`
+    /// instead of the Tiger initial values, see `Tiger::with_iv`
+    #[cfg(feature = "hazmat")]
+    pub const fn with_iv(a: u64, b: u64, c: u64) -> Self {
+        assert!(P::USIZE >= 3, "Tiger requires at least 3 passes");
+        TigerP { engine: TigerEngine::with_iv(a, b, c) }
+    }
+
+    /// Create hasher from chaining variables `state` obtained after
+    /// compressing the first `processed_bytes` of a message with `P` passes
+    ///
+    /// # Panics
+    ///
+    /// If `processed_bytes` is not a multiple of the block size or exceeds
+    /// `MAX_MESSAGE_LEN`.
+    pub fn from_midstate(state: [u64; 3], processed_bytes: u64) -> Self {
+        TigerP { engine: TigerEngine::from_midstate(state, processed_bytes) }
+    }
+
+    /// Export complete hasher state into a compact binary snapshot
+    pub fn serialize_state(&self) -> [u8; STATE_SIZE] {
+        self.engine.serialize_state()
+    }
+
+    /// Restore hasher from a snapshot created by `serialize_state`
+    pub fn deserialize_state(state: &[u8]) -> Result<Self, InvalidState> {
+        TigerEngine::deserialize_state(state).map(|engine| TigerP { engine })
+    }
+
+    /// Retrieve the digest of a message ending with the first `bits` bits of
+    /// `last`, see `Tiger::result_bits`
+    pub fn result_bits(mut self, last: u8, bits: u8) -> GenericArray<u8, U24> {
+        self.engine.finalize_bits(last, bits)
+    }
+
+    /// Process `data` and return the hasher, see `Tiger::chain`
+    pub fn chain<B: AsRef<[u8]>>(mut self, data: B) -> Self {
+        self.engine.input(data.as_ref());
+        self
+    }
+
+    /// Same as `chain`, see `Tiger::chain_update`
+    pub fn chain_update<B: AsRef<[u8]>>(self, data: B) -> Self {
+        self.chain(data)
+    }
+
+    /// Retrieve the digest, the same as `fixed_result`
+    pub fn finalize(self) -> GenericArray<u8, U24> {
+        self.fixed_result()
+    }
+
+    /// Retrieve the digest and reset the hasher, see `Tiger::finalize_reset`
+    pub fn finalize_reset(&mut self) -> GenericArray<u8, U24> {
+        let out = self.engine.finalize();
+        self.engine.reset();
+        out
+    }
+
+    /// Write the digest to `out` and reset the hasher, see
+    /// `Tiger::finalize_into`
+    pub fn finalize_into(&mut self, out: &mut TigerOutput) {
+        self.engine.finalize_into(out);
+        self.engine.reset();
+    }
+}
+
+impl<P: Unsigned> FixedOutputInto for TigerP<P> {
+    fn fixed_result_into(&mut self, out: &mut Output) {
+        self.engine.finalize_into(out);
+        self.engine.reset();
+    }
+}
+
+impl<P: Unsigned> BlockInput for TigerP<P> {
+    type BlockSize = BlockSize;
+}
+
+impl<P: Unsigned> Input for TigerP<P> {
+    fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+        self.engine.input(input.as_ref());
+    }
+}
+
+impl<P: Unsigned> FixedOutput for TigerP<P> {
+    type OutputSize = U24;
+
+    fn fixed_result(mut self) -> GenericArray<u8, Self::OutputSize> {
+        self.engine.finalize()
+    }
+}
+
+impl<P: Unsigned> Reset for TigerP<P> {
+    fn reset(&mut self) {
+        self.engine.reset();
+    }
+}
+
+impl<P: Unsigned> core::fmt::Debug for TigerP<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "TigerP<{}> {{ ... }}", P::to_usize())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<P: Unsigned> std::io::Write for TigerP<P> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Input::input(self, buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,323 @@
+#[cfg(not(feature = "small-tables"))]
+macro_rules! sbox {
+    ($t:ident, $i:expr) => { $crate::consts::SBOXES.$t[$i] };
+}
+
+#[cfg(feature = "small-tables")]
+macro_rules! sbox {
+    ($t:ident, $i:expr) => { $crate::consts::$t.get($i) };
+}
+
+macro_rules! round {
+    ($a:expr, $b:expr, $c:expr, $x:expr, $mul:expr) => {
+        $c ^= $x;
+        let c = $c.0;
+        $a -= Wrapping(
+            sbox!(T1, (c & 0xff) as usize) ^
+            sbox!(T2, ((c >> 16) & 0xff) as usize) ^
+            sbox!(T3, ((c >> 32) & 0xff) as usize) ^
+            sbox!(T4, ((c >> 48) & 0xff) as usize)
+        );
+        $b += Wrapping(
+            sbox!(T4, ((c >> 8) & 0xff) as usize) ^
+            sbox!(T3, ((c >> 24) & 0xff) as usize) ^
+            sbox!(T2, ((c >> 40) & 0xff) as usize) ^
+            sbox!(T1, ((c >> 56) & 0xff) as usize)
+        );
+        $b *= Wrapping($mul);
+    };
+}
+
+// Hint the S-box entries for a round whose `c` value is already known
+#[cfg(all(feature = "prefetch", target_arch = "x86_64",
+    not(any(feature = "small-tables", feature = "compact",
+        feature = "constant-time"))))]
+macro_rules! prefetch {
+    ($c:expr) => { ::prefetch::sboxes(($c).0) };
+}
+
+#[cfg(all(not(feature = "compact"), not(all(feature = "prefetch",
+    target_arch = "x86_64", not(feature = "small-tables"),
+    not(feature = "constant-time")))))]
+// unused on 32-bit targets and with `constant-time`, see the `narrow` and
+// `masked` modules
+#[cfg_attr(any(feature = "constant-time", all(target_pointer_width = "32",
+    not(feature = "small-tables"))), allow(unused_macros))]
+macro_rules! prefetch {
+    ($c:expr) => {};
+}
+
+#[cfg(not(feature = "compact"))]
+#[cfg_attr(any(feature = "constant-time", all(target_pointer_width = "32",
+    not(feature = "small-tables"))), allow(unused_macros))]
+macro_rules! pass {
+    ($a:expr, $b:expr, $c:expr, $x:expr, $mul:expr) => {
+        round!($a, $b, $c, $x[0], $mul);
+        prefetch!($a ^ $x[1]);
+        round!($b, $c, $a, $x[1], $mul);
+        prefetch!($b ^ $x[2]);
+        round!($c, $a, $b, $x[2], $mul);
+        prefetch!($c ^ $x[3]);
+        round!($a, $b, $c, $x[3], $mul);
+        prefetch!($a ^ $x[4]);
+        round!($b, $c, $a, $x[4], $mul);
+        prefetch!($b ^ $x[5]);
+        round!($c, $a, $b, $x[5], $mul);
+        prefetch!($c ^ $x[6]);
+        round!($a, $b, $c, $x[6], $mul);
+        prefetch!($a ^ $x[7]);
+        round!($b, $c, $a, $x[7], $mul);
+    };
+}
+
+macro_rules! key_schedule {
+    ($x:expr) => {
+        $x[0] -= $x[7] ^ Wrapping(0xA5A5_A5A5_A5A5_A5A5);
+        $x[1] ^= $x[0];
+        $x[2] += $x[1];
+        $x[3] -= $x[2] ^ ((!$x[1]) << 19);
+        $x[4] ^= $x[3];
+        $x[5] += $x[4];
+        $x[6] -= $x[5] ^ ((!$x[4]) >> 23);
+        $x[7] ^= $x[6];
+        $x[0] += $x[7];
+        $x[1] -= $x[0] ^ ((!$x[7]) << 19);
+        $x[2] ^= $x[1];
+        $x[3] += $x[2];
+        $x[4] -= $x[3] ^ ((!$x[2]) >> 23);
+        $x[5] ^= $x[4];
+        $x[6] += $x[5];
+        $x[7] -= $x[6] ^ Wrapping(0x0123_4567_89AB_CDEF);
+    };
+}
+
+#[cfg(not(feature = "compact"))]
+#[cfg_attr(any(feature = "constant-time", all(target_pointer_width = "32",
+    not(feature = "small-tables"))), allow(unused_macros))]
+macro_rules! compress {
+    ($a:expr, $b:expr, $c:expr, $x:expr, $passes:expr) => {
+        let (aa, bb, cc) = ($a, $b, $c);
+
+        pass!($a, $b, $c, $x, 5);
+        key_schedule!($x);
+        pass!($c, $a, $b, $x, 7);
+        key_schedule!($x);
+        pass!($b, $c, $a, $x, 9);
+
+        for _ in 3..$passes {
+            key_schedule!($x);
+            pass!($a, $b, $c, $x, 9);
+            let t = $a;
+            $a = $c;
+            $c = $b;
+            $b = t;
+        }
+
+        $a ^= aa;
+        $b -= bb;
+        $c += cc;
+    };
+}
+
+// Same as above, but with rounds and passes as loops instead of being
+// unrolled, which makes the compression function several times smaller
+#[cfg(feature = "compact")]
+#[cfg_attr(feature = "constant-time", allow(unused_macros))]
+macro_rules! compress {
+    ($a:expr, $b:expr, $c:expr, $x:expr, $passes:expr) => {
+        let (aa, bb, cc) = ($a, $b, $c);
+        let mut s = [$a, $b, $c];
+
+        for pass in 0..$passes {
+            if pass > 0 {
+                key_schedule!($x);
+            }
+            // the first three passes start at rotations (a, b, c),
+            // (c, a, b) and (b, c, a), additional ones rename the
+            // variables instead
+            let (r, mul) = match pass {
+                0 => (0, 5),
+                1 => (2, 7),
+                2 => (1, 9),
+                _ => (0, 9),
+            };
+            for i in 0..8 {
+                let (ia, ib, ic) = ((r + i) % 3, (r + i + 1) % 3, (r + i + 2) % 3);
+                round!(s[ia], s[ib], s[ic], $x[i], mul);
+            }
+            if pass >= 3 {
+                s = [s[2], s[0], s[1]];
+            }
+        }
+
+        $a = s[0] ^ aa;
+        $b = s[1] - bb;
+        $c = s[2] + cc;
+    };
+}
+
+macro_rules! impl_tiger {
+    ($state:ident, $output_size:ident, $padding:ty, $doc:expr) => {
+        #[doc=$doc]
+        #[derive(Clone)]
+        pub struct $state {
+            engine: TigerEngine<$padding, U3>,
+        }
+
+        impl Default for $state {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl $state {
+            /// Create new hasher instance, which can also be used in
+            /// constant expressions, e.g. to initialize a `static`
+            pub const fn new() -> Self {
+                $state { engine: TigerEngine::new() }
+            }
+
+            /// Create hasher starting from the chaining variables `a`, `b`
+            /// and `c` instead of the Tiger initial values
+            ///
+            /// Only meant for research, e.g. cryptanalysis of modified
+            /// variants or domain separation experiments: the digests do
+            /// not match those of any other Tiger implementation. Resetting
+            /// the hasher restores the standard initial values.
+            #[cfg(feature = "hazmat")]
+            pub const fn with_iv(a: u64, b: u64, c: u64) -> Self {
+                $state { engine: TigerEngine::with_iv(a, b, c) }
+            }
+
+            /// Create hasher from chaining variables `state` obtained after
+            /// compressing the first `processed_bytes` of a message, e.g.
+            /// with `compress`
+            ///
+            /// # Panics
+            ///
+            /// If `processed_bytes` is not a multiple of the block size or exceeds
+            /// `MAX_MESSAGE_LEN`.
+            pub fn from_midstate(state: [u64; 3], processed_bytes: u64)
+                -> Self
+            {
+                $state {
+                    engine: TigerEngine::from_midstate(state, processed_bytes),
+                }
+            }
+
+            /// Export complete hasher state into a compact binary snapshot,
+            /// which can be stored to resume hashing later
+            pub fn serialize_state(&self) -> [u8; STATE_SIZE] {
+                self.engine.serialize_state()
+            }
+
+            /// Restore hasher from a snapshot created by `serialize_state`
+            ///
+            /// Snapshots of other variants or of an unknown format version
+            /// are rejected.
+            pub fn deserialize_state(state: &[u8])
+                -> Result<Self, InvalidState>
+            {
+                TigerEngine::deserialize_state(state)
+                    .map(|engine| $state { engine })
+            }
+
+            /// Retrieve the digest of a message whose length is not a
+            /// multiple of 8 bits, the input so far followed by the first
+            /// `bits` bits of `last`
+            ///
+            /// `Tiger` takes the bits of `last` starting with the least
+            /// significant one, `Tiger2` starting with the most significant
+            /// one, matching their padding bytes. Other bits of `last` are
+            /// ignored, with `bits` equal to zero this is the same as
+            /// `result`. This bit order is a convention of this crate which
+            /// has not been checked against published bit-oriented test
+            /// vectors, other implementations may differ.
+            ///
+            /// # Panics
+            ///
+            /// If `bits` is 8 or more.
+            pub fn result_bits(mut self, last: u8, bits: u8)
+                -> GenericArray<u8, $output_size>
+            {
+                let out = self.engine.finalize_bits(last, bits);
+                GenericArray::clone_from_slice(&out[..$output_size::to_usize()])
+            }
+
+            /// Process `data` and return the hasher, for building digests
+            /// fluently, e.g. `Tiger::new().chain(a).chain(b).finalize()`
+            pub fn chain<B: AsRef<[u8]>>(mut self, data: B) -> Self {
+                self.engine.input(data.as_ref());
+                self
+            }
+
+            /// Same as `chain`, under the name used by newer versions of
+            /// the `digest` traits
+            pub fn chain_update<B: AsRef<[u8]>>(self, data: B) -> Self {
+                self.chain(data)
+            }
+
+            /// Retrieve the digest, the same as `fixed_result`
+            pub fn finalize(self) -> GenericArray<u8, $output_size> {
+                self.fixed_result()
+            }
+
+            /// Retrieve the digest and reset the hasher for the next
+            /// message
+            ///
+            /// Unlike `Digest::result_reset` the hasher is not cloned, so
+            /// keeping one instance around for many short messages is as
+            /// cheap as hashing them with fresh instances.
+            pub fn finalize_reset(&mut self) -> GenericArray<u8, $output_size> {
+                let out = self.engine.finalize();
+                self.engine.reset();
+                GenericArray::clone_from_slice(&out[..$output_size::to_usize()])
+            }
+
+            /// Write the digest directly to `out` and reset the hasher
+            ///
+            /// This avoids copying the returned digest, e.g. when it is
+            /// stored into a larger packet buffer.
+            pub fn finalize_into(&mut self, out: &mut [u8; $output_size::USIZE]) {
+                self.engine.finalize_into(out);
+                self.engine.reset();
+            }
+        }
+
+        impl FixedOutputInto for $state {
+            fn fixed_result_into(&mut self, out: &mut GenericArray<u8, $output_size>) {
+                self.engine.finalize_into(out);
+                self.engine.reset();
+            }
+        }
+
+        impl BlockInput for $state {
+            type BlockSize = BlockSize;
+        }
+
+        impl Input for $state {
+            fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+                self.engine.input(input.as_ref());
+            }
+        }
+
+        impl FixedOutput for $state {
+            type OutputSize = $output_size;
+
+            fn fixed_result(mut self) -> GenericArray<u8, Self::OutputSize> {
+                let out = self.engine.finalize();
+                let n = Self::OutputSize::to_usize();
+                GenericArray::clone_from_slice(&out[..n])
+            }
+        }
+
+        impl Reset for $state {
+            fn reset(&mut self) {
+                self.engine.reset();
+            }
+        }
+
+        impl_opaque_debug!($state);
+        impl_write!($state);
+    }
+}
@@ -0,0 +1,175 @@
+//! Magnet links for TTH roots, i.e. `magnet:?xt=urn:tree:tiger:...` URIs
+//! used by DC++, ADC and Gnutella clients.
+//!
+//! ```rust
+//! # extern crate tiger;
+//! # fn main() {
+//! use tiger::Digest;
+//! use tiger::magnet::Magnet;
+//! use tiger::tth::TigerTree;
+//!
+//! let magnet = Magnet::new(TigerTree::digest(b""))
+//!     .name("empty file.txt")
+//!     .size(0);
+//! let uri = magnet.to_string();
+//! assert_eq!(uri, "magnet:?xt=urn:tree:tiger:\
+//!     LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ&xl=0&dn=empty%20file.txt");
+//! assert_eq!(uri.parse::<Magnet>().unwrap(), magnet);
+//! # }
+//! ```
+use core::fmt;
+use core::str::FromStr;
+use std::error;
+use std::string::String;
+use std::vec::Vec;
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::U24;
+use base32::{encode_base32, decode_base32, BASE32_LEN};
+
+const SCHEME: &str = "magnet:?";
+// `urn:tree:tiger:` is used by DC++, the other two forms by Gnutella clients
+const TTH_URNS: [&str; 3] = [
+    "urn:tree:tiger:", "urn:tree:tiger/:", "urn:tree:tiger/1024:",
+];
+
+/// The error type for parsing of magnet links
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InvalidMagnet;
+
+impl fmt::Display for InvalidMagnet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid TTH magnet link")
+    }
+}
+
+impl error::Error for InvalidMagnet {
+    fn description(&self) -> &str {
+        "invalid TTH magnet link"
+    }
+}
+
+/// Magnet link pointing to a file by its TTH root
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Magnet {
+    /// TTH root of the file (`xt` parameter)
+    pub root: GenericArray<u8, U24>,
+    /// Display name of the file (`dn` parameter)
+    pub name: Option<String>,
+    /// Size of the file in bytes (`xl` parameter)
+    pub size: Option<u64>,
+}
+
+impl Magnet {
+    /// Create magnet link for the given TTH root
+    pub fn new(root: GenericArray<u8, U24>) -> Self {
+        Magnet { root, name: None, size: None }
+    }
+
+    /// Set display name of the file
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set size of the file in bytes
+    pub fn size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+fn is_unreserved(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"-._~".contains(&b)
+}
+
+fn hex_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn percent_decode(s: &str) -> Result<String, InvalidMagnet> {
+    let s = s.as_bytes();
+    let mut out = Vec::with_capacity(s.len());
+    let mut i = 0;
+    while i < s.len() {
+        match s[i] {
+            b'+' => out.push(b' '),
+            b'%' => {
+                if i + 2 >= s.len() { return Err(InvalidMagnet); }
+                let hi = hex_value(s[i + 1]).ok_or(InvalidMagnet)?;
+                let lo = hex_value(s[i + 2]).ok_or(InvalidMagnet)?;
+                out.push((hi << 4) | lo);
+                i += 2;
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8(out).map_err(|_| InvalidMagnet)
+}
+
+impl fmt::Display for Magnet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut buf = [0u8; BASE32_LEN];
+        let root = encode_base32(&self.root, &mut buf);
+        write!(f, "{}xt={}{}", SCHEME, TTH_URNS[0], root)?;
+        if let Some(size) = self.size {
+            write!(f, "&xl={}", size)?;
+        }
+        if let Some(ref name) = self.name {
+            f.write_str("&dn=")?;
+            for &b in name.as_bytes() {
+                if is_unreserved(b) {
+                    write!(f, "{}", b as char)?;
+                } else {
+                    write!(f, "%{:02X}", b)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Magnet {
+    type Err = InvalidMagnet;
+
+    fn from_str(s: &str) -> Result<Self, InvalidMagnet> {
+        if !s.starts_with(SCHEME) { return Err(InvalidMagnet); }
+
+        let mut root = None;
+        let mut name = None;
+        let mut size = None;
+        for param in s[SCHEME.len()..].split('&') {
+            let mut kv = param.splitn(2, '=');
+            let key = kv.next().unwrap_or("");
+            let value = kv.next().ok_or(InvalidMagnet)?;
+            match key {
+                "xt" => {
+                    let value = percent_decode(value)?;
+                    for urn in TTH_URNS.iter() {
+                        let value = value.as_bytes();
+                        let is_tth = value.len() >= urn.len() &&
+                            value[..urn.len()].eq_ignore_ascii_case(urn.as_bytes());
+                        if is_tth {
+                            let root32 = core::str::from_utf8(&value[urn.len()..])
+                                .map_err(|_| InvalidMagnet)?;
+                            root = Some(decode_base32(root32)
+                                .map_err(|_| InvalidMagnet)?);
+                        }
+                    }
+                }
+                "xl" => size = Some(value.parse().map_err(|_| InvalidMagnet)?),
+                "dn" => name = Some(percent_decode(value)?),
+                // other parameters (trackers, other hashes, etc.) are ignored
+                _ => (),
+            }
+        }
+
+        let root = root.ok_or(InvalidMagnet)?;
+        Ok(Magnet { root, name, size })
+    }
+}
@@ -0,0 +1,120 @@
+//! Compression function without secret-dependent memory accesses, enabled
+//! by the `constant-time` feature
+//!
+//! The Tiger S-boxes are the output of a randomized generation procedure,
+//! so unlike the AES S-box they have no compact boolean circuit which
+//! could be evaluated bitsliced. Instead every round reads all 1024
+//! entries of the four tables and selects the eight needed ones with
+//! masks, so the sequence of addresses does not depend on the data. This
+//! is about two orders of magnitude slower than the table lookups of the
+//! default implementation.
+use core::hint::black_box;
+use core::num::Wrapping;
+use state::read_u64;
+use Block;
+
+/// All ones if `a == b`, zero otherwise, computed without branches
+#[inline(always)]
+fn eq_mask(a: u64, b: u64) -> u64 {
+    let d = a ^ b;
+    ((d | d.wrapping_neg()) >> 63).wrapping_sub(1)
+}
+
+/// XOR of the S-box entries of the even and of the odd bytes of `c`
+#[inline(always)]
+fn lookup(c: u64) -> (u64, u64) {
+    let mut idx = [0u64; 8];
+    for (k, i) in idx.iter_mut().enumerate() {
+        *i = (c >> (8 * k)) & 0xff;
+    }
+    let (mut even, mut odd) = (0, 0);
+    for i in 0..256 {
+        let m = |k: usize| eq_mask(i as u64, idx[k]);
+        let (t1, t2, t3, t4) = (sbox!(T1, i), sbox!(T2, i), sbox!(T3, i), sbox!(T4, i));
+        even ^= (t1 & m(0)) ^ (t2 & m(2)) ^ (t3 & m(4)) ^ (t4 & m(6));
+        odd ^= (t4 & m(1)) ^ (t3 & m(3)) ^ (t2 & m(5)) ^ (t1 & m(7));
+    }
+    // discourages the compiler from turning the masked selection back into
+    // loads, black_box gives no guarantee, see tests/timing.rs for a
+    // measurement of the result
+    (black_box(even), black_box(odd))
+}
+
+fn round(s: &mut [Wrapping<u64>; 3], r: usize, x: Wrapping<u64>, mul: u64) {
+    let (a, b, c) = (r % 3, (r + 1) % 3, (r + 2) % 3);
+    s[c] ^= x;
+    let (even, odd) = lookup(s[c].0);
+    s[a] -= Wrapping(even);
+    s[b] += Wrapping(odd);
+    s[b] *= Wrapping(mul);
+}
+
+/// Compress `blocks` in sequence with `passes` passes, see
+/// `TigerState::process_blocks`
+pub fn compress<'a, I>(state: &mut [u64; 3], blocks: I, passes: usize)
+    where I: IntoIterator<Item = &'a Block>
+{
+    #[cfg(feature = "small-tables")]
+    ::sboxes::init();
+    let mut s = [Wrapping(state[0]), Wrapping(state[1]), Wrapping(state[2])];
+    for block in blocks {
+        let mut x = [Wrapping(0u64); 8];
+        for (w, chunk) in x.iter_mut().zip(block.chunks_exact(8)) {
+            *w = Wrapping(read_u64(chunk));
+        }
+        let saved = s;
+
+        // same structure as the `compact` variant of `compress!`
+        for pass in 0..passes {
+            if pass > 0 {
+                key_schedule!(x);
+            }
+            let (r, mul) = match pass {
+                0 => (0, 5),
+                1 => (2, 7),
+                2 => (1, 9),
+                _ => (0, 9),
+            };
+            for (i, &x) in x.iter().enumerate() {
+                round(&mut s, r + i, x, mul);
+            }
+            if pass >= 3 {
+                s = [s[2], s[0], s[1]];
+            }
+        }
+
+        s[0] ^= saved[0];
+        s[1] -= saved[1];
+        s[2] += saved[2];
+    }
+    *state = [s[0].0, s[1].0, s[2].0];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compress, eq_mask};
+    use Block;
+
+    #[test]
+    fn masks() {
+        assert_eq!(eq_mask(7, 7), !0);
+        assert_eq!(eq_mask(0, 0), !0);
+        assert_eq!(eq_mask(0, 255), 0);
+        assert_eq!(eq_mask(128, 0), 0);
+    }
+
+    #[test]
+    fn matches_tables() {
+        let mut blocks = [Block::default(); 3];
+        let mut seed = 0x2545_F491_4F6C_DD1Du64;
+        for b in blocks.iter_mut().flat_map(|b| b.iter_mut()) {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            *b = (seed >> 56) as u8;
+        }
+        let mut expected = [1, 2, 3];
+        ::compress(&mut expected, &blocks);
+        let mut state = [1, 2, 3];
+        compress(&mut state, blocks.iter(), 3);
+        assert_eq!(state, expected);
+    }
+}
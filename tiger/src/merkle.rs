@@ -0,0 +1,237 @@
+//! Merkle trees over arbitrary items with inclusion proofs
+//!
+//! Items are hashed as `H(0x00 || item)` and nodes as
+//! `H(0x01 || left || right)`, with nodes lacking a sibling promoted to the
+//! next level unchanged: the hashing rules of [THEX](../thex/index.html),
+//! but over a list of items of any size instead of a message split into
+//! 1024 byte leaves. The tree over the 1024 byte blocks of a message thus
+//! has its TTH as root.
+//!
+//! A `Proof` holds the siblings on the path from an item to the root, which
+//! is all a peer needs to check a single item against a trusted root.
+//!
+//! ```rust
+//! # extern crate tiger;
+//! # fn main() {
+//! use tiger::merkle::MerkleTree;
+//!
+//! let items = [&b"alpha"[..], b"beta", b"gamma"];
+//! let tree: MerkleTree = MerkleTree::new(&items);
+//! let proof = tree.proof(2).unwrap();
+//! assert!(proof.verify(tree.root(), b"gamma"));
+//! assert!(!proof.verify(tree.root(), b"delta"));
+//! # }
+//! ```
+use core::fmt;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::error;
+use digest::Digest;
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::Unsigned;
+use thex::Thex;
+use Tiger;
+
+type Output<D> = GenericArray<u8, <D as Digest>::OutputSize>;
+
+const LEAF_PREFIX: u8 = 0x00;
+
+/// Hash of a single item, i.e. of a leaf of the tree
+pub fn leaf_hash<D: Digest>(item: &[u8]) -> Output<D> {
+    let mut leaf = D::new();
+    leaf.input([LEAF_PREFIX]);
+    leaf.input(item);
+    leaf.result()
+}
+
+/// Balanced Merkle tree generic over the underlying hash function `D`
+///
+/// All levels of the tree are kept in memory, which takes about twice the
+/// size of the leaf hashes.
+#[derive(Clone, Debug)]
+pub struct MerkleTree<D: Digest + Clone = Tiger> {
+    // levels[0] holds the leaves, the last level the root
+    levels: Vec<Vec<Output<D>>>,
+}
+
+impl<D: Digest + Clone> PartialEq for MerkleTree<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.levels[0] == other.levels[0]
+    }
+}
+
+impl<D: Digest + Clone> Eq for MerkleTree<D> {}
+
+impl<D: Digest + Clone> MerkleTree<D> {
+    /// Build the tree over `items`, an empty list is treated as a single
+    /// empty item as in THEX
+    pub fn new<I>(items: I) -> Self
+        where I: IntoIterator, I::Item: AsRef<[u8]>
+    {
+        let leaves = items.into_iter().map(|item| leaf_hash::<D>(item.as_ref()));
+        Self::from_leaves(leaves.collect())
+    }
+
+    /// Build the tree over already hashed leaves, see `leaf_hash`
+    pub fn from_leaves(mut leaves: Vec<Output<D>>) -> Self {
+        if leaves.is_empty() {
+            leaves.push(leaf_hash::<D>(b""));
+        }
+        let mut levels = Vec::new();
+        levels.push(leaves);
+        while levels[levels.len() - 1].len() > 1 {
+            let next = {
+                let last = &levels[levels.len() - 1];
+                last.chunks(2).map(|pair| match pair.len() {
+                    2 => Thex::<D>::node_hash(&pair[0], &pair[1]),
+                    _ => pair[0].clone(),
+                }).collect()
+            };
+            levels.push(next);
+        }
+        MerkleTree { levels }
+    }
+
+    /// Root of the tree
+    pub fn root(&self) -> &Output<D> {
+        &self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Number of leaves, at least one
+    pub fn leaf_count(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Number of levels including the leaves and the root
+    pub fn depth(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Hash of the `index`-th leaf, `None` if `index` is out of range
+    pub fn leaf(&self, index: usize) -> Option<&Output<D>> {
+        self.levels[0].get(index)
+    }
+
+    /// Build the inclusion proof of the `index`-th item, `None` if `index`
+    /// is out of range
+    pub fn proof(&self, index: usize) -> Option<Proof<D>> {
+        if index >= self.leaf_count() { return None; }
+
+        let mut path = Vec::new();
+        let mut i = index;
+        for level in self.levels[..self.levels.len() - 1].iter() {
+            if let Some(sibling) = level.get(i ^ 1) {
+                path.push(sibling.clone());
+            }
+            i >>= 1;
+        }
+        Some(Proof { index: index as u64, count: self.leaf_count() as u64, path })
+    }
+}
+
+/// The error type for decoding a `Proof`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InvalidProof;
+
+impl fmt::Display for InvalidProof {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid Merkle proof encoding")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for InvalidProof {
+    fn description(&self) -> &str {
+        "invalid Merkle proof encoding"
+    }
+}
+
+/// Inclusion proof of one item of a `MerkleTree`
+///
+/// Only the siblings on the path to the root are stored, promoted nodes
+/// have none, so a proof for one of `n` items holds at most
+/// `ceil(log2(n))` hashes.
+#[derive(Clone, Debug)]
+pub struct Proof<D: Digest + Clone = Tiger> {
+    index: u64,
+    count: u64,
+    path: Vec<Output<D>>,
+}
+
+impl<D: Digest + Clone> PartialEq for Proof<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.count == other.count
+            && self.path == other.path
+    }
+}
+
+impl<D: Digest + Clone> Eq for Proof<D> {}
+
+impl<D: Digest + Clone> Proof<D> {
+    /// Create a proof from its parts, see `Thex::verify_node` for the
+    /// meaning of `path`
+    pub fn from_parts(index: u64, count: u64, path: Vec<Output<D>>) -> Self {
+        Proof { index, count, path }
+    }
+
+    /// Position of the proven item
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Number of items in the tree
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Sibling nodes from the bottom up
+    pub fn path(&self) -> &[Output<D>] {
+        &self.path
+    }
+
+    /// Check that `item` is part of the tree with the given `root`
+    pub fn verify(&self, root: &Output<D>, item: &[u8]) -> bool {
+        self.verify_leaf(root, &leaf_hash::<D>(item))
+    }
+
+    /// Check that the leaf hash `leaf` is part of the tree with the given
+    /// `root`
+    pub fn verify_leaf(&self, root: &Output<D>, leaf: &Output<D>) -> bool {
+        Thex::<D>::verify_node(root, leaf, self.index, self.count, &self.path)
+    }
+
+    /// Encode the proof as the little-endian 64-bit index and count
+    /// followed by the path
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let size = D::OutputSize::to_usize();
+        let mut out = Vec::with_capacity(16 + size * self.path.len());
+        out.extend_from_slice(&self.index.to_le_bytes());
+        out.extend_from_slice(&self.count.to_le_bytes());
+        for node in self.path.iter() {
+            out.extend_from_slice(node);
+        }
+        out
+    }
+
+    /// Decode a proof encoded by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, InvalidProof> {
+        let size = D::OutputSize::to_usize();
+        if bytes.len() < 16 || (bytes.len() - 16) % size != 0 {
+            return Err(InvalidProof);
+        }
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&bytes[..8]);
+        let index = u64::from_le_bytes(word);
+        word.copy_from_slice(&bytes[8..16]);
+        let count = u64::from_le_bytes(word);
+        // a tree of 2^64 - 1 items is at most 64 levels deep
+        let path_len = (bytes.len() - 16) / size;
+        if index >= count || path_len > 64 {
+            return Err(InvalidProof);
+        }
+        let path = bytes[16..].chunks(size)
+            .map(GenericArray::clone_from_slice)
+            .collect();
+        Ok(Proof { index, count, path })
+    }
+}
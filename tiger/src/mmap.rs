@@ -0,0 +1,29 @@
+//! Hashing of memory-mapped files
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use digest::Digest;
+use memmap2::Mmap;
+use {hash, to_array, Tiger};
+
+/// Compute the Tiger digest of the file at `path` by mapping it into memory
+///
+/// The mapped file is hashed as one slice, so all full blocks are compressed
+/// directly from the page cache without read calls or intermediate copies.
+/// For large files this is usually faster than `hash_file`.
+///
+/// The file must not be modified while it is hashed: concurrent writes make
+/// the result unspecified and truncation may raise `SIGBUS` on Unix.
+pub fn hash_file_mmap<P: AsRef<Path>>(path: P) -> io::Result<[u8; 24]> {
+    let file = File::open(path)?;
+    if file.metadata()?.len() == 0 {
+        // empty files can not be mapped on all platforms
+        return Ok(hash(b""));
+    }
+    // The mapping is only read and dropped before returning, concurrent
+    // modifications are documented above.
+    let map = unsafe { Mmap::map(&file)? };
+    let mut hasher = Tiger::new();
+    hasher.input(&map[..]);
+    Ok(to_array(hasher.result()))
+}
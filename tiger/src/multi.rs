@@ -0,0 +1,170 @@
+//! Hashing of four independent messages at once
+//!
+//! The Tiger round function is a chain of dependent S-box lookups, so a
+//! single message state leaves most of the execution units of a modern CPU
+//! idle. Interleaving the rounds of four independent states allows the
+//! lookups of different messages to be executed in parallel, which
+//! noticeably increases throughput when many short messages of similar
+//! length have to be hashed, e.g. by file indexers or password verifiers.
+//!
+//! ```rust
+//! # extern crate tiger;
+//! # fn main() {
+//! let digests = tiger::multi::hash4([b"a", b"bc", b"def", b""]);
+//! assert_eq!(digests[2], tiger::hash(b"def"));
+//! # }
+//! ```
+use core::cmp;
+use core::num::Wrapping;
+use state::{read_u64, write_u64s, TigerState};
+use backend;
+use {compress, Block};
+
+pub use backend::{backend, force_backend, Backend, UnsupportedBackend};
+
+macro_rules! round4 {
+    ($a:expr, $b:expr, $c:expr, $x:expr, $i:expr, $mul:expr) => {
+        round!($a[0], $b[0], $c[0], $x[0][$i], $mul);
+        round!($a[1], $b[1], $c[1], $x[1][$i], $mul);
+        round!($a[2], $b[2], $c[2], $x[2][$i], $mul);
+        round!($a[3], $b[3], $c[3], $x[3][$i], $mul);
+    };
+}
+
+macro_rules! pass4 {
+    ($a:expr, $b:expr, $c:expr, $x:expr, $mul:expr) => {
+        round4!($a, $b, $c, $x, 0, $mul);
+        round4!($b, $c, $a, $x, 1, $mul);
+        round4!($c, $a, $b, $x, 2, $mul);
+        round4!($a, $b, $c, $x, 3, $mul);
+        round4!($b, $c, $a, $x, 4, $mul);
+        round4!($c, $a, $b, $x, 5, $mul);
+        round4!($a, $b, $c, $x, 6, $mul);
+        round4!($b, $c, $a, $x, 7, $mul);
+    };
+}
+
+macro_rules! key_schedule4 {
+    ($x:expr) => {
+        for x in $x.iter_mut() {
+            key_schedule!(x);
+        }
+    };
+}
+
+/// The three-pass Tiger compression function applied to four independent
+/// states, lane `i` of `states` is updated with `blocks[i]`
+///
+/// See `tiger::compress` for details. The implementation is selected at
+/// run time, see `Backend`. With the `avx2` feature enabled an AVX2
+/// implementation is used on x86-64 CPUs supporting it. Note that gather
+/// instructions are slow on many CPUs, so the portable implementation is
+/// often faster; enable the feature only if benchmarks on the target
+/// hardware show an improvement. Otherwise the `simd` feature selects an
+/// implementation based on the portable vector types of the `wide` crate,
+/// which helps mostly on targets such as AArch64 or POWER where the
+/// interleaved scalar code runs short of registers.
+pub fn compress4(states: &mut [[u64; 3]; 4], blocks: [&Block; 4]) {
+    backend::compress4(states, blocks);
+}
+
+#[cfg_attr(feature = "constant-time", allow(dead_code))]
+pub(crate) fn compress4_soft(states: &mut [[u64; 3]; 4], blocks: [&Block; 4]) {
+    #[cfg(feature = "small-tables")]
+    ::sboxes::init();
+    let mut x = [[Wrapping(0u64); 8]; 4];
+    for (x, block) in x.iter_mut().zip(blocks.iter()) {
+        for (w, chunk) in x.iter_mut().zip(block.chunks_exact(8)) {
+            *w = Wrapping(read_u64(chunk));
+        }
+    }
+    let mut a = [Wrapping(0u64); 4];
+    let mut b = [Wrapping(0u64); 4];
+    let mut c = [Wrapping(0u64); 4];
+    for (i, s) in states.iter().enumerate() {
+        a[i] = Wrapping(s[0]);
+        b[i] = Wrapping(s[1]);
+        c[i] = Wrapping(s[2]);
+    }
+    let (aa, bb, cc) = (a, b, c);
+
+    pass4!(a, b, c, x, 5);
+    key_schedule4!(x);
+    pass4!(c, a, b, x, 7);
+    key_schedule4!(x);
+    pass4!(b, c, a, x, 9);
+
+    for (i, s) in states.iter_mut().enumerate() {
+        s[0] = (a[i] ^ aa[i]).0;
+        s[1] = (b[i] - bb[i]).0;
+        s[2] = (c[i] + cc[i]).0;
+    }
+}
+
+/// Message split into complete blocks and the padded tail (one or two
+/// blocks)
+struct Lane<'a> {
+    blocks: &'a [u8],
+    tail: [Block; 2],
+    tail_len: usize,
+}
+
+impl<'a> Lane<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        let n = data.len() - data.len() % 64;
+        let (blocks, rest) = data.split_at(n);
+        let mut tail = [Block::default(), Block::default()];
+        tail[0][..rest.len()].copy_from_slice(rest);
+        tail[0][rest.len()] = 0x01;
+        let tail_len = if rest.len() + 1 > 56 { 2 } else { 1 };
+        tail[tail_len - 1][56..]
+            .copy_from_slice(&((data.len() as u64) << 3).to_le_bytes());
+        Lane { blocks, tail, tail_len }
+    }
+
+    fn len(&self) -> usize {
+        self.blocks.len() / 64 + self.tail_len
+    }
+
+    fn block(&self, i: usize) -> &Block {
+        let n = self.blocks.len() / 64;
+        if i < n {
+            Block::from_slice(&self.blocks[64 * i..64 * (i + 1)])
+        } else {
+            &self.tail[i - n]
+        }
+    }
+}
+
+/// Compute the Tiger digests of four messages at once
+///
+/// Blocks are processed in the interleaved mode as long as all messages
+/// have blocks left, so the speed-up is largest for messages of similar
+/// length.
+pub fn hash4(data: [&[u8]; 4]) -> [[u8; 24]; 4] {
+    let lanes = [
+        Lane::new(data[0]), Lane::new(data[1]),
+        Lane::new(data[2]), Lane::new(data[3]),
+    ];
+    let common = lanes.iter().map(Lane::len).fold(usize::MAX, cmp::min);
+
+    let mut states = [TigerState::new().words(); 4];
+    for i in 0..common {
+        let blocks = [
+            lanes[0].block(i), lanes[1].block(i),
+            lanes[2].block(i), lanes[3].block(i),
+        ];
+        compress4(&mut states, blocks);
+    }
+
+    let mut out = [[0u8; 24]; 4];
+    for ((lane, state), out) in lanes.iter().zip(states.iter_mut())
+        .zip(out.iter_mut())
+    {
+        for i in common..lane.len() {
+            compress(state, core::slice::from_ref(lane.block(i)));
+        }
+        write_u64s(&state[..], out);
+    }
+    out
+}
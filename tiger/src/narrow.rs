@@ -0,0 +1,203 @@
+//! Compression function for 32-bit targets
+//!
+//! Every 64-bit word is kept as two 32-bit halves, so additions and
+//! subtractions are done with explicit carries, the multiplications by 5, 7
+//! and 9 with shifts and the S-box indices are taken from the half which
+//! contains their byte. The S-boxes are stored as pairs of halves, so a
+//! lookup loads two adjacent 32-bit words instead of a 64-bit one. This
+//! avoids the 64-bit shifts and the register pressure of the generic code
+//! on Cortex-M, MIPS32 and similar cores.
+use consts::TABLES;
+use state::read_u64;
+use Block;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Word {
+    lo: u32,
+    hi: u32,
+}
+
+impl Word {
+    #[inline(always)]
+    const fn new(w: u64) -> Self {
+        Word { lo: w as u32, hi: (w >> 32) as u32 }
+    }
+
+    #[inline(always)]
+    fn get(self) -> u64 {
+        (u64::from(self.hi) << 32) | u64::from(self.lo)
+    }
+
+    #[inline(always)]
+    fn add(self, o: Word) -> Word {
+        let (lo, carry) = self.lo.overflowing_add(o.lo);
+        Word { lo, hi: self.hi.wrapping_add(o.hi).wrapping_add(carry as u32) }
+    }
+
+    #[inline(always)]
+    fn sub(self, o: Word) -> Word {
+        let (lo, borrow) = self.lo.overflowing_sub(o.lo);
+        Word { lo, hi: self.hi.wrapping_sub(o.hi).wrapping_sub(borrow as u32) }
+    }
+
+    #[inline(always)]
+    fn xor(self, o: Word) -> Word {
+        Word { lo: self.lo ^ o.lo, hi: self.hi ^ o.hi }
+    }
+
+    #[inline(always)]
+    fn not(self) -> Word {
+        Word { lo: !self.lo, hi: !self.hi }
+    }
+
+    /// Shift left by `n` bits, `0 < n < 32`
+    #[inline(always)]
+    fn shl(self, n: u32) -> Word {
+        Word { lo: self.lo << n, hi: (self.hi << n) | (self.lo >> (32 - n)) }
+    }
+
+    /// Shift right by `n` bits, `0 < n < 32`
+    #[inline(always)]
+    fn shr(self, n: u32) -> Word {
+        Word { lo: (self.lo >> n) | (self.hi << (32 - n)), hi: self.hi >> n }
+    }
+
+    #[inline(always)]
+    fn mul(self, m: u32) -> Word {
+        match m {
+            5 => self.shl(2).add(self),
+            7 => self.shl(3).sub(self),
+            _ => self.shl(3).add(self),
+        }
+    }
+}
+
+const fn split(t: &[u64; 256]) -> [Word; 256] {
+    let mut out = [Word { lo: 0, hi: 0 }; 256];
+    let mut i = 0;
+    while i < 256 {
+        out[i] = Word::new(t[i]);
+        i += 1;
+    }
+    out
+}
+
+#[allow(non_snake_case)]
+struct SBoxes {
+    T1: [Word; 256],
+    T2: [Word; 256],
+    T3: [Word; 256],
+    T4: [Word; 256],
+}
+
+static SBOXES: SBoxes = SBoxes {
+    T1: split(&TABLES[0]),
+    T2: split(&TABLES[1]),
+    T3: split(&TABLES[2]),
+    T4: split(&TABLES[3]),
+};
+
+#[inline(always)]
+fn round(a: &mut Word, b: &mut Word, c: &mut Word, x: Word, mul: u32) {
+    *c = c.xor(x);
+    let (lo, hi) = (c.lo as usize, c.hi as usize);
+    let s = &SBOXES;
+    let even = s.T1[lo & 0xff].xor(s.T2[(lo >> 16) & 0xff])
+        .xor(s.T3[hi & 0xff]).xor(s.T4[(hi >> 16) & 0xff]);
+    let odd = s.T4[(lo >> 8) & 0xff].xor(s.T3[lo >> 24])
+        .xor(s.T2[(hi >> 8) & 0xff]).xor(s.T1[hi >> 24]);
+    *a = a.sub(even);
+    *b = b.add(odd).mul(mul);
+}
+
+#[inline(always)]
+fn pass(a: &mut Word, b: &mut Word, c: &mut Word, x: &[Word; 8], mul: u32) {
+    round(a, b, c, x[0], mul);
+    round(b, c, a, x[1], mul);
+    round(c, a, b, x[2], mul);
+    round(a, b, c, x[3], mul);
+    round(b, c, a, x[4], mul);
+    round(c, a, b, x[5], mul);
+    round(a, b, c, x[6], mul);
+    round(b, c, a, x[7], mul);
+}
+
+#[inline(always)]
+fn key_schedule(x: &mut [Word; 8]) {
+    x[0] = x[0].sub(x[7].xor(Word::new(0xA5A5_A5A5_A5A5_A5A5)));
+    x[1] = x[1].xor(x[0]);
+    x[2] = x[2].add(x[1]);
+    x[3] = x[3].sub(x[2].xor(x[1].not().shl(19)));
+    x[4] = x[4].xor(x[3]);
+    x[5] = x[5].add(x[4]);
+    x[6] = x[6].sub(x[5].xor(x[4].not().shr(23)));
+    x[7] = x[7].xor(x[6]);
+    x[0] = x[0].add(x[7]);
+    x[1] = x[1].sub(x[0].xor(x[7].not().shl(19)));
+    x[2] = x[2].xor(x[1]);
+    x[3] = x[3].add(x[2]);
+    x[4] = x[4].sub(x[3].xor(x[2].not().shr(23)));
+    x[5] = x[5].xor(x[4]);
+    x[6] = x[6].add(x[5]);
+    x[7] = x[7].sub(x[6].xor(Word::new(0x0123_4567_89AB_CDEF)));
+}
+
+/// Compress `blocks` in sequence with `passes` passes, see
+/// `TigerState::process_blocks`
+pub fn compress<'a, I>(state: &mut [u64; 3], blocks: I, passes: usize)
+    where I: IntoIterator<Item = &'a Block>
+{
+    let (mut a, mut b, mut c) =
+        (Word::new(state[0]), Word::new(state[1]), Word::new(state[2]));
+    for block in blocks {
+        let mut x = [Word { lo: 0, hi: 0 }; 8];
+        for (w, chunk) in x.iter_mut().zip(block.chunks_exact(8)) {
+            *w = Word::new(read_u64(chunk));
+        }
+        let (aa, bb, cc) = (a, b, c);
+
+        pass(&mut a, &mut b, &mut c, &x, 5);
+        key_schedule(&mut x);
+        pass(&mut c, &mut a, &mut b, &x, 7);
+        key_schedule(&mut x);
+        pass(&mut b, &mut c, &mut a, &x, 9);
+
+        for _ in 3..passes {
+            key_schedule(&mut x);
+            pass(&mut a, &mut b, &mut c, &x, 9);
+            let t = a;
+            a = c;
+            c = b;
+            b = t;
+        }
+
+        a = a.xor(aa);
+        b = b.sub(bb);
+        c = c.add(cc);
+    }
+    *state = [a.get(), b.get(), c.get()];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compress;
+    use state::TigerState;
+    use Block;
+
+    #[test]
+    fn matches_64bit() {
+        let mut blocks = [Block::default(); 5];
+        let mut seed = 0x9E37_79B9_7F4A_7C15u64;
+        for b in blocks.iter_mut().flat_map(|b| b.iter_mut()) {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            *b = (seed >> 56) as u8;
+        }
+        for passes in 3..6 {
+            let mut expected = TigerState::new();
+            expected.process_blocks(blocks.iter(), passes);
+            let mut state = TigerState::new().words();
+            compress(&mut state, blocks.iter(), passes);
+            assert_eq!(state, expected.words());
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::error;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::string::ToString;
+#[cfg(feature = "alloc")]
+use base32::{encode_base32, BASE32_LEN};
+use state::read_u64;
+use digest::Digest;
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::U24;
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConstantTimeEq};
+use Tiger;
+
+/// The error type for parsing of hex encoded digests
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InvalidHex;
+
+impl fmt::Display for InvalidHex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid hex digest")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for InvalidHex {
+    fn description(&self) -> &str {
+        "invalid hex digest"
+    }
+}
+
+/// A 24 byte Tiger (or TTH) digest
+///
+/// The digest is formatted as lowercase hex by `Display` and `LowerHex`,
+/// and `FromStr` accepts 48 hex digits of any case.
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// use tiger::{Tiger, TigerDigest, Digest};
+///
+/// let digest = TigerDigest::from(Tiger::digest(b"abc"));
+/// let hex = digest.to_string();
+/// assert_eq!(hex, "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93");
+/// assert_eq!(hex.parse::<TigerDigest>().unwrap(), digest);
+/// # }
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TigerDigest(pub [u8; 24]);
+
+impl TigerDigest {
+    /// Digest bytes
+    pub fn as_bytes(&self) -> &[u8; 24] {
+        &self.0
+    }
+
+    /// Encode digest as lowercase hex string
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> String {
+        self.to_string()
+    }
+
+    /// Encode digest as uppercase hex string
+    #[cfg(feature = "alloc")]
+    pub fn to_hex_upper(&self) -> String {
+        let mut hex = String::with_capacity(48);
+        let _ = fmt::Write::write_fmt(&mut hex, format_args!("{:X}", self));
+        hex
+    }
+
+    /// Encode digest as unpadded Base32 string, see `encode_base32`
+    #[cfg(feature = "alloc")]
+    pub fn to_base32(&self) -> String {
+        let mut buf = [0u8; BASE32_LEN];
+        String::from(encode_base32(GenericArray::from_slice(&self.0), &mut buf))
+    }
+
+    /// View digest in the representation printed by the reference
+    /// `testtiger.c`, see `LegacyOutput`
+    pub fn legacy(&self) -> LegacyOutput {
+        LegacyOutput(*self)
+    }
+
+    /// Encode digest as uppercase hex string of the three state words, the
+    /// representation printed by the reference `testtiger.c`
+    #[cfg(feature = "alloc")]
+    pub fn to_legacy_hex(&self) -> String {
+        self.legacy().to_string()
+    }
+}
+
+/// A Tiger digest formatted like the reference implementation's test
+/// program
+///
+/// `testtiger.c` prints the three 64-bit state words as uppercase hex
+/// numbers separated by spaces. As the digest byte string stores each word
+/// in little-endian order, the bytes of every word appear reversed compared
+/// to the `TigerDigest` formatting. Many historic Tiger values were
+/// published in this form. `FromStr` accepts the three words with or
+/// without separating whitespace, in any case.
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// use tiger::{Tiger, TigerDigest, LegacyOutput, Digest};
+///
+/// let digest = TigerDigest::from(Tiger::digest(b""));
+/// let legacy = "24F0130C63AC9332 16166E76B1BB925F F373DE2D49584E7A";
+/// assert_eq!(digest.legacy().to_string(), legacy);
+/// assert_eq!(legacy.parse::<LegacyOutput>().unwrap().0, digest);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LegacyOutput(pub TigerDigest);
+
+impl fmt::Display for LegacyOutput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (a, b, c): (u64, u64, u64) = self.0.into();
+        write!(f, "{:016X} {:016X} {:016X}", a, b, c)
+    }
+}
+
+impl FromStr for LegacyOutput {
+    type Err = InvalidHex;
+
+    fn from_str(s: &str) -> Result<Self, InvalidHex> {
+        let mut words = s.split_whitespace();
+        let (first, rest) = (words.next(), (words.next(), words.next()));
+        let hex: [&str; 3] = match (first, rest) {
+            (Some(w), (None, None)) if w.len() == 48 && w.is_char_boundary(16)
+                && w.is_char_boundary(32) => [&w[..16], &w[16..32], &w[32..]],
+            (Some(a), (Some(b), Some(c))) if words.next().is_none() => [a, b, c],
+            _ => return Err(InvalidHex),
+        };
+        let mut out = [0u8; 24];
+        for (chunk, word) in out.chunks_mut(8).zip(hex.iter()) {
+            let word = word.as_bytes();
+            if word.len() != 16 { return Err(InvalidHex); }
+            for (b, pair) in chunk.iter_mut().rev().zip(word.chunks(2)) {
+                *b = (hex_value(pair[0])? << 4) | hex_value(pair[1])?;
+            }
+        }
+        Ok(LegacyOutput(TigerDigest(out)))
+    }
+}
+
+impl From<TigerDigest> for LegacyOutput {
+    fn from(digest: TigerDigest) -> Self {
+        LegacyOutput(digest)
+    }
+}
+
+impl From<LegacyOutput> for TigerDigest {
+    fn from(legacy: LegacyOutput) -> Self {
+        legacy.0
+    }
+}
+
+impl AsRef<[u8]> for TigerDigest {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<GenericArray<u8, U24>> for TigerDigest {
+    fn from(digest: GenericArray<u8, U24>) -> Self {
+        let mut out = [0u8; 24];
+        out.copy_from_slice(&digest);
+        TigerDigest(out)
+    }
+}
+
+impl From<[u8; 24]> for TigerDigest {
+    fn from(digest: [u8; 24]) -> Self {
+        TigerDigest(digest)
+    }
+}
+
+impl From<TigerDigest> for [u8; 24] {
+    fn from(digest: TigerDigest) -> Self {
+        digest.0
+    }
+}
+
+impl From<TigerDigest> for GenericArray<u8, U24> {
+    fn from(digest: TigerDigest) -> Self {
+        GenericArray::clone_from_slice(&digest.0)
+    }
+}
+
+/// The three little-endian 64-bit words of the digest, i.e. the final
+/// values of the Tiger chaining variables `a`, `b` and `c`
+impl From<TigerDigest> for (u64, u64, u64) {
+    fn from(digest: TigerDigest) -> Self {
+        let d = &digest.0;
+        (read_u64(&d[..8]), read_u64(&d[8..16]), read_u64(&d[16..]))
+    }
+}
+
+/// The first 16 bytes of the digest as a little-endian integer
+impl From<TigerDigest> for u128 {
+    fn from(digest: TigerDigest) -> Self {
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest.0[..16]);
+        u128::from_le_bytes(bytes)
+    }
+}
+
+/// The first 8 bytes of the digest as a little-endian integer
+impl From<TigerDigest> for u64 {
+    fn from(digest: TigerDigest) -> Self {
+        read_u64(&digest.0)
+    }
+}
+
+impl fmt::LowerHex for TigerDigest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for b in self.0.iter() {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::UpperHex for TigerDigest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for b in self.0.iter() {
+            write!(f, "{:02X}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TigerDigest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl fmt::Debug for TigerDigest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TigerDigest({:x})", self)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl ConstantTimeEq for TigerDigest {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0[..].ct_eq(&other.0[..])
+    }
+}
+
+/// Compare two byte strings in time independent of their content, only
+/// their lengths are not kept secret
+fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() { return false; }
+    let diff = a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    // prevent the compiler from short-cutting the loop above
+    core::hint::black_box(diff) == 0
+}
+
+/// Check in constant time that `expected` is the Tiger digest of `data`
+///
+/// ```rust
+/// # #[macro_use] extern crate hex_literal;
+/// # extern crate tiger;
+/// # fn main() {
+/// let expected = hex!("2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93");
+/// assert!(tiger::verify(&expected, b"abc"));
+/// assert!(!tiger::verify(&expected, b"abd"));
+/// # }
+/// ```
+pub fn verify<B: AsRef<[u8]>>(expected: &[u8], data: B) -> bool {
+    ct_eq(expected, &Tiger::digest(data.as_ref()))
+}
+
+/// Compute the Tiger digest of `data` as lowercase hex string
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// assert_eq!(tiger::hash_hex(b"abc"),
+///     "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93");
+/// # }
+/// ```
+#[cfg(feature = "alloc")]
+pub fn hash_hex<B: AsRef<[u8]>>(data: B) -> String {
+    hash_digest(data).to_hex()
+}
+
+/// Compute the Tiger digest of `data` as uppercase hex string
+#[cfg(feature = "alloc")]
+pub fn hash_hex_upper<B: AsRef<[u8]>>(data: B) -> String {
+    hash_digest(data).to_hex_upper()
+}
+
+/// Compute the Tiger digest of `data` as `TigerDigest`
+///
+/// This is the allocation-free counterpart of `hash_hex`, the digest is
+/// formatted as lowercase hex by `{}` and `{:x}` and as uppercase hex by
+/// `{:X}`, e.g. directly into a `core::fmt::Write` buffer.
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// let digest = tiger::hash_digest(b"abc");
+/// assert_eq!(format!("{}", digest),
+///     "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93");
+/// assert_eq!(format!("{:X}", digest),
+///     "2AAB1484E8C158F2BFB8C5FF41B57A525129131C957B5F93");
+/// # }
+/// ```
+pub fn hash_digest<B: AsRef<[u8]>>(data: B) -> TigerDigest {
+    TigerDigest::from(Tiger::digest(data.as_ref()))
+}
+
+fn hex_value(b: u8) -> Result<u8, InvalidHex> {
+    match b {
+        b'0'..=b'9' => Ok(b - b'0'),
+        b'a'..=b'f' => Ok(b - b'a' + 10),
+        b'A'..=b'F' => Ok(b - b'A' + 10),
+        _ => Err(InvalidHex),
+    }
+}
+
+impl FromStr for TigerDigest {
+    type Err = InvalidHex;
+
+    fn from_str(s: &str) -> Result<Self, InvalidHex> {
+        let s = s.as_bytes();
+        if s.len() != 48 { return Err(InvalidHex); }
+
+        let mut out = [0u8; 24];
+        for (b, pair) in out.iter_mut().zip(s.chunks(2)) {
+            *b = (hex_value(pair[0])? << 4) | hex_value(pair[1])?;
+        }
+        Ok(TigerDigest(out))
+    }
+}
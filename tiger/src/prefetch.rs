@@ -0,0 +1,29 @@
+//! Software prefetching of S-box lookups on x86-64
+//!
+//! The lookups of a Tiger round depend on the result of the previous round,
+//! so out-of-order execution can not start loading the table entries early.
+//! Once a round has updated `a`, the indices used by the next round are
+//! known and the corresponding cache lines are requested here.
+use core::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+use consts::SBOXES;
+
+/// Prefetch the eight S-box entries indexed by the bytes of `c`
+#[inline(always)]
+pub fn sboxes(c: u64) {
+    let t = &SBOXES;
+    let entries = [
+        &t.T1[(c & 0xff) as usize],
+        &t.T4[((c >> 8) & 0xff) as usize],
+        &t.T2[((c >> 16) & 0xff) as usize],
+        &t.T3[((c >> 24) & 0xff) as usize],
+        &t.T3[((c >> 32) & 0xff) as usize],
+        &t.T2[((c >> 40) & 0xff) as usize],
+        &t.T4[((c >> 48) & 0xff) as usize],
+        &t.T1[((c >> 56) & 0xff) as usize],
+    ];
+    for &entry in entries.iter() {
+        // SSE is part of the x86-64 baseline and prefetching has no
+        // observable effect besides timing, whatever the address
+        unsafe { _mm_prefetch::<_MM_HINT_T0>(entry as *const u64 as *const i8) };
+    }
+}
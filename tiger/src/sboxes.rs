@@ -0,0 +1,116 @@
+//! S-boxes generated on first use, enabled by the `small-tables` feature
+//!
+//! Instead of storing the 8 KiB of S-box constants in the binary, the
+//! tables are derived with the generation procedure from the Tiger paper,
+//! see the `tables` module, and kept in RAM. The procedure runs 1707
+//! compression function calls, all further uses of the tables cost one
+//! atomic load per block.
+//!
+//! Tables are stored as 32-bit halves in atomics, which keeps this module
+//! free of unsafe code and usable on targets without 64-bit atomics.
+//! Concurrent first uses may generate the tables more than once, which is
+//! harmless as every run stores the same values.
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use tables::{byte, key_schedule, seed_block, PASSES};
+
+pub struct SBox([AtomicU32; 512]);
+
+// a `const` item can be repeated in array expressions, unlike a call
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO: AtomicU32 = AtomicU32::new(0);
+
+impl SBox {
+    const fn new() -> Self {
+        SBox([ZERO; 512])
+    }
+
+    #[inline(always)]
+    pub fn get(&self, i: usize) -> u64 {
+        let lo = self.0[2 * i].load(Ordering::Relaxed);
+        let hi = self.0[2 * i + 1].load(Ordering::Relaxed);
+        u64::from(lo) | (u64::from(hi) << 32)
+    }
+
+    fn set(&self, i: usize, w: u64) {
+        self.0[2 * i].store(w as u32, Ordering::Relaxed);
+        self.0[2 * i + 1].store((w >> 32) as u32, Ordering::Relaxed);
+    }
+}
+
+pub static T1: SBox = SBox::new();
+pub static T2: SBox = SBox::new();
+pub static T3: SBox = SBox::new();
+pub static T4: SBox = SBox::new();
+
+static READY: AtomicBool = AtomicBool::new(false);
+
+/// Make sure the S-boxes are generated, must be called before any lookup
+#[inline(always)]
+pub fn init() {
+    if !READY.load(Ordering::Acquire) {
+        generate();
+    }
+}
+
+/// Same procedure as `tables::generate`, but working on the atomics in
+/// place, so that the tables are never held on the stack
+#[cold]
+fn generate() {
+    let t = [&T1, &T2, &T3, &T4];
+    for i in 0..256 {
+        let w = 0x0101_0101_0101_0101 * i as u64;
+        for sbox in t.iter() {
+            sbox.set(i, w);
+        }
+    }
+
+    let block = seed_block();
+    let mut state = [
+        0x0123_4567_89AB_CDEF, 0xFEDC_BA98_7654_3210, 0xF096_A5B4_C3B2_E187,
+    ];
+    let mut abc = 2;
+    for _ in 0..PASSES {
+        for i in 0..256 {
+            for sbox in t.iter() {
+                abc += 1;
+                if abc == 3 {
+                    abc = 0;
+                    state = compress(&t, state, block);
+                }
+                for col in 0..8 {
+                    let shift = 8 * col;
+                    let mask = 0xff << shift;
+                    let j = ((state[abc] >> shift) & 0xff) as usize;
+                    let (wi, wj) = (sbox.get(i), sbox.get(j));
+                    sbox.set(i, (wi & !mask) | (wj & mask));
+                    sbox.set(j, (wj & !mask) | (wi & mask));
+                }
+            }
+        }
+    }
+    READY.store(true, Ordering::Release);
+}
+
+/// See `tables::compress`
+fn compress(t: &[&SBox; 4], state: [u64; 3], mut x: [u64; 8]) -> [u64; 3] {
+    let mut s = state;
+    for n in 0..24 {
+        if n == 8 || n == 16 {
+            x = key_schedule(x);
+        }
+        let mul = [5, 7, 9][n / 8];
+        let (a, b, c) = (n % 3, (n + 1) % 3, (n + 2) % 3);
+        s[c] ^= x[n % 8];
+        let w = s[c];
+        s[a] = s[a].wrapping_sub(t[0].get(byte(w, 0)) ^ t[1].get(byte(w, 2))
+            ^ t[2].get(byte(w, 4)) ^ t[3].get(byte(w, 6)));
+        s[b] = s[b].wrapping_add(t[3].get(byte(w, 1)) ^ t[2].get(byte(w, 3))
+            ^ t[1].get(byte(w, 5)) ^ t[0].get(byte(w, 7)));
+        s[b] = s[b].wrapping_mul(mul);
+    }
+    [
+        s[0] ^ state[0],
+        s[1].wrapping_sub(state[1]),
+        s[2].wrapping_add(state[2]),
+    ]
+}
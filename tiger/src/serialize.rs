@@ -0,0 +1,211 @@
+//! Serde support for the Tiger hashers
+//!
+//! A hasher is serialized as a struct with the fields `state` (the three
+//! chaining words), `length` (number of processed message bytes) and
+//! `buffer` (the `length % 64` buffered message bytes). The padding and
+//! number of passes are part of the hasher type and are not serialized.
+use core::fmt;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::ser::SerializeStruct;
+use serde::de::{self, Visitor, SeqAccess, MapAccess};
+use digest::generic_array::typenum::Unsigned;
+use tiger::{Snapshot, TigerEngine};
+use {Tiger, Tiger2, Tiger160, Tiger128, TigerP};
+
+const NAME: &str = "Tiger";
+const FIELDS: &[&str] = &["state", "length", "buffer"];
+
+struct Bytes<'a>(&'a [u8]);
+
+impl<'a> Serialize for Bytes<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S)
+        -> Result<S::Ok, S::Error>
+    {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// Buffered bytes of a partial block
+struct Buffer {
+    data: [u8; 64],
+    len: usize,
+}
+
+struct BufferVisitor;
+
+impl<'de> Visitor<'de> for BufferVisitor {
+    type Value = Buffer;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("at most 63 bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Buffer, E> {
+        if v.len() >= 64 { return Err(E::invalid_length(v.len(), &self)); }
+        let mut data = [0u8; 64];
+        data[..v.len()].copy_from_slice(v);
+        Ok(Buffer { data, len: v.len() })
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A)
+        -> Result<Buffer, A::Error>
+    {
+        let mut data = [0u8; 64];
+        let mut len = 0;
+        while let Some(b) = seq.next_element()? {
+            if len == 63 { return Err(de::Error::invalid_length(64, &self)); }
+            data[len] = b;
+            len += 1;
+        }
+        Ok(Buffer { data, len })
+    }
+}
+
+impl<'de> Deserialize<'de> for Buffer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D)
+        -> Result<Self, D::Error>
+    {
+        deserializer.deserialize_bytes(BufferVisitor)
+    }
+}
+
+enum Field { State, Length, Buffer }
+
+struct FieldVisitor;
+
+impl<'de> Visitor<'de> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("`state`, `length` or `buffer`")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Field, E> {
+        match v {
+            "state" => Ok(Field::State),
+            "length" => Ok(Field::Length),
+            "buffer" => Ok(Field::Buffer),
+            _ => Err(E::unknown_field(v, FIELDS)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D)
+        -> Result<Self, D::Error>
+    {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+fn snapshot<E: de::Error>(state: [u64; 3], length: u64, buffer: Buffer)
+    -> Result<Snapshot, E>
+{
+    if buffer.len as u64 != length % 64 {
+        return Err(E::custom("buffer length does not match message length"));
+    }
+    Ok(Snapshot { state, length, buffer: buffer.data })
+}
+
+struct SnapshotVisitor;
+
+impl<'de> Visitor<'de> for SnapshotVisitor {
+    type Value = Snapshot;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Tiger hasher state")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A)
+        -> Result<Snapshot, A::Error>
+    {
+        let state = seq.next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let length = seq.next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let buffer = seq.next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        snapshot(state, length, buffer)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A)
+        -> Result<Snapshot, A::Error>
+    {
+        let mut state = None;
+        let mut length = None;
+        let mut buffer = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::State => {
+                    if state.is_some() {
+                        return Err(de::Error::duplicate_field("state"));
+                    }
+                    state = Some(map.next_value()?);
+                }
+                Field::Length => {
+                    if length.is_some() {
+                        return Err(de::Error::duplicate_field("length"));
+                    }
+                    length = Some(map.next_value()?);
+                }
+                Field::Buffer => {
+                    if buffer.is_some() {
+                        return Err(de::Error::duplicate_field("buffer"));
+                    }
+                    buffer = Some(map.next_value()?);
+                }
+            }
+        }
+        let state = state.ok_or_else(|| de::Error::missing_field("state"))?;
+        let length = length.ok_or_else(|| de::Error::missing_field("length"))?;
+        let buffer = buffer.ok_or_else(|| de::Error::missing_field("buffer"))?;
+        snapshot(state, length, buffer)
+    }
+}
+
+impl Serialize for Snapshot {
+    fn serialize<S: Serializer>(&self, serializer: S)
+        -> Result<S::Ok, S::Error>
+    {
+        let mut s = serializer.serialize_struct(NAME, FIELDS.len())?;
+        s.serialize_field("state", &self.state)?;
+        s.serialize_field("length", &self.length)?;
+        s.serialize_field("buffer", &Bytes(self.buffered()))?;
+        s.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Snapshot {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D)
+        -> Result<Self, D::Error>
+    {
+        deserializer.deserialize_struct(NAME, FIELDS, SnapshotVisitor)
+    }
+}
+
+macro_rules! impl_serde {
+    ($state:ident $(<$p:ident: $bound:ident>)*) => {
+        impl$(<$p: $bound>)* Serialize for $state$(<$p>)* {
+            fn serialize<S: Serializer>(&self, serializer: S)
+                -> Result<S::Ok, S::Error>
+            {
+                self.engine.snapshot().serialize(serializer)
+            }
+        }
+
+        impl<'de, $($p: $bound)*> Deserialize<'de> for $state$(<$p>)* {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D)
+                -> Result<Self, D::Error>
+            {
+                let snapshot = Snapshot::deserialize(deserializer)?;
+                Ok($state { engine: TigerEngine::from_snapshot(&snapshot) })
+            }
+        }
+    }
+}
+
+impl_serde!(Tiger);
+impl_serde!(Tiger2);
+impl_serde!(Tiger160);
+impl_serde!(Tiger128);
+impl_serde!(TigerP<P: Unsigned>);
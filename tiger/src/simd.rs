@@ -0,0 +1,114 @@
+//! Portable SIMD implementation of the four-lane compression function
+//!
+//! Every `u64x4` vector of the `wide` crate holds one 64-bit word of each
+//! of the four lanes, the `wide` crate decides which vector instructions
+//! of the target are used for it. Portable vector instruction sets have no
+//! gather instruction, so the S-box lookups are done per lane, only the
+//! key schedule and the additions, subtractions and multiplications of the
+//! rounds are vectorized.
+use wide::u64x4;
+use Block;
+
+macro_rules! lookup {
+    ($c:expr, $t0:ident, $t1:ident, $t2:ident, $t3:ident, $shift:expr) => {{
+        let c = $c.to_array();
+        let mut out = [0u64; 4];
+        for (o, c) in out.iter_mut().zip(c.iter()) {
+            let c = c >> $shift;
+            *o = sbox!($t0, (c & 0xff) as usize)
+                ^ sbox!($t1, ((c >> 16) & 0xff) as usize)
+                ^ sbox!($t2, ((c >> 32) & 0xff) as usize)
+                ^ sbox!($t3, ((c >> 48) & 0xff) as usize);
+        }
+        u64x4::new(out)
+    }};
+}
+
+macro_rules! mul {
+    ($b:expr, 5) => { ($b << 2u32) + $b };
+    ($b:expr, 7) => { ($b << 3u32) - $b };
+    ($b:expr, 9) => { ($b << 3u32) + $b };
+}
+
+macro_rules! round_simd {
+    ($a:expr, $b:expr, $c:expr, $x:expr, $mul:tt) => {
+        $c ^= $x;
+        $a -= lookup!($c, T1, T2, T3, T4, 0);
+        $b += lookup!($c, T4, T3, T2, T1, 8);
+        $b = mul!($b, $mul);
+    };
+}
+
+macro_rules! pass_simd {
+    ($a:expr, $b:expr, $c:expr, $x:expr, $mul:tt) => {
+        round_simd!($a, $b, $c, $x[0], $mul);
+        round_simd!($b, $c, $a, $x[1], $mul);
+        round_simd!($c, $a, $b, $x[2], $mul);
+        round_simd!($a, $b, $c, $x[3], $mul);
+        round_simd!($b, $c, $a, $x[4], $mul);
+        round_simd!($c, $a, $b, $x[5], $mul);
+        round_simd!($a, $b, $c, $x[6], $mul);
+        round_simd!($b, $c, $a, $x[7], $mul);
+    };
+}
+
+#[inline(always)]
+fn key_schedule(x: &mut [u64x4; 8]) {
+    let k0 = u64x4::splat(0xA5A5_A5A5_A5A5_A5A5);
+    let k1 = u64x4::splat(0x0123_4567_89AB_CDEF);
+    x[0] -= x[7] ^ k0;
+    x[1] ^= x[0];
+    x[2] += x[1];
+    x[3] -= x[2] ^ (!x[1] << 19u32);
+    x[4] ^= x[3];
+    x[5] += x[4];
+    x[6] -= x[5] ^ (!x[4] >> 23u32);
+    x[7] ^= x[6];
+    x[0] += x[7];
+    x[1] -= x[0] ^ (!x[7] << 19u32);
+    x[2] ^= x[1];
+    x[3] += x[2];
+    x[4] -= x[3] ^ (!x[2] >> 23u32);
+    x[5] ^= x[4];
+    x[6] += x[5];
+    x[7] -= x[6] ^ k1;
+}
+
+fn word(block: &Block, i: usize) -> u64 {
+    let mut w = [0u8; 8];
+    w.copy_from_slice(&block[8 * i..8 * (i + 1)]);
+    u64::from_le_bytes(w)
+}
+
+/// See `multi::compress4`
+pub fn compress4(states: &mut [[u64; 3]; 4], blocks: [&Block; 4]) {
+    #[cfg(feature = "small-tables")]
+    ::sboxes::init();
+    let mut x = [u64x4::ZERO; 8];
+    for (i, x) in x.iter_mut().enumerate() {
+        *x = u64x4::new([
+            word(blocks[0], i), word(blocks[1], i),
+            word(blocks[2], i), word(blocks[3], i),
+        ]);
+    }
+    let lane = |j: usize| {
+        u64x4::new([states[0][j], states[1][j], states[2][j], states[3][j]])
+    };
+    let mut a = lane(0);
+    let mut b = lane(1);
+    let mut c = lane(2);
+    let (aa, bb, cc) = (a, b, c);
+
+    pass_simd!(a, b, c, x, 5);
+    key_schedule(&mut x);
+    pass_simd!(c, a, b, x, 7);
+    key_schedule(&mut x);
+    pass_simd!(b, c, a, x, 9);
+
+    let a = (a ^ aa).to_array();
+    let b = (b - bb).to_array();
+    let c = (c + cc).to_array();
+    for (i, s) in states.iter_mut().enumerate() {
+        *s = [a[i], b[i], c[i]];
+    }
+}
@@ -0,0 +1,77 @@
+//! Compact binary format of the Tiger hasher state
+//!
+//! The state is serialized into `STATE_SIZE` bytes:
+//!
+//! | Offset | Size | Content                                          |
+//! |--------|------|--------------------------------------------------|
+//! | 0      | 1    | format version, currently 1                      |
+//! | 1      | 1    | padding byte of the variant (`0x01` or `0x80`)   |
+//! | 2      | 1    | number of passes                                 |
+//! | 3      | 24   | chaining variables `a`, `b`, `c` (little-endian) |
+//! | 27     | 8    | message length in bytes (little-endian)          |
+//! | 35     | 64   | buffered bytes of the partial block, zero padded |
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error;
+use state::{read_u64, write_u64s};
+use tiger::{Snapshot, MAX_MESSAGE_LEN};
+
+/// Size of the serialized Tiger hasher state in bytes
+pub const STATE_SIZE: usize = 99;
+
+const VERSION: u8 = 1;
+
+/// The error type for deserialization of the Tiger hasher state
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InvalidState;
+
+impl fmt::Display for InvalidState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid Tiger hasher state")
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for InvalidState {
+    fn description(&self) -> &str {
+        "invalid Tiger hasher state"
+    }
+}
+
+impl Snapshot {
+    pub fn encode(&self, pad: u8, passes: u8) -> [u8; STATE_SIZE] {
+        let mut out = [0u8; STATE_SIZE];
+        out[0] = VERSION;
+        out[1] = pad;
+        out[2] = passes;
+        write_u64s(&self.state, &mut out[3..27]);
+        write_u64s(&[self.length], &mut out[27..35]);
+        let buffered = self.buffered();
+        out[35..35 + buffered.len()].copy_from_slice(buffered);
+        out
+    }
+
+    pub fn decode(bytes: &[u8], pad: u8, passes: u8)
+        -> Result<Snapshot, InvalidState>
+    {
+        if bytes.len() != STATE_SIZE || bytes[..3] != [VERSION, pad, passes] {
+            return Err(InvalidState);
+        }
+        let state = [
+            read_u64(&bytes[3..]), read_u64(&bytes[11..]), read_u64(&bytes[19..]),
+        ];
+        let length = read_u64(&bytes[27..]);
+        if length > MAX_MESSAGE_LEN {
+            return Err(InvalidState);
+        }
+        let mut buffer = [0u8; 64];
+        buffer.copy_from_slice(&bytes[35..]);
+
+        // bytes after the buffered part must be zero
+        let pos = (length % 64) as usize;
+        if buffer[pos..].iter().any(|&b| b != 0) {
+            return Err(InvalidState);
+        }
+        Ok(Snapshot { state, length, buffer })
+    }
+}
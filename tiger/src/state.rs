@@ -0,0 +1,151 @@
+use core::iter;
+use core::num::Wrapping;
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::U24;
+use Block;
+
+// initial values for TigerState
+const A: u64 = 0x0123_4567_89AB_CDEF;
+const B: u64 = 0xFEDC_BA98_7654_3210;
+const C: u64 = 0xF096_A5B4_C3B2_E187;
+
+/// Read little-endian word from the first 8 bytes of `bytes`
+pub(crate) fn read_u64(bytes: &[u8]) -> u64 {
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&bytes[..8]);
+    u64::from_le_bytes(word)
+}
+
+/// Write little-endian words into `out`, which must hold all of them
+pub(crate) fn write_u64s(words: &[u64], out: &mut [u8]) {
+    for (chunk, w) in out.chunks_exact_mut(8).zip(words) {
+        chunk.copy_from_slice(&w.to_le_bytes());
+    }
+}
+
+/// Pad the final partial block and pass the one or two resulting blocks
+/// to `compress`
+///
+/// The first `pos` bytes of `buf` are the end of the message, `pad` is the
+/// byte following them (the padding byte, possibly combined with a partial
+/// byte) and `bit_length` the message length in bits. `buf` is clobbered.
+pub(crate) fn pad_final<F>(buf: &mut [u8; 64], pos: usize, pad: u8,
+    bit_length: u64, mut compress: F)
+    where F: FnMut(&Block)
+{
+    buf[pos] = pad;
+    buf[pos + 1..].fill(0);
+
+    if pos + 1 > 56 {
+        compress(Block::from_slice(&buf[..]));
+        buf[..pos + 1].fill(0);
+    }
+
+    buf[56..].copy_from_slice(&bit_length.to_le_bytes());
+    compress(Block::from_slice(&buf[..]));
+}
+
+#[derive(Copy, Clone)]
+pub struct TigerState {
+    a: Wrapping<u64>,
+    b: Wrapping<u64>,
+    c: Wrapping<u64>,
+}
+
+impl Default for TigerState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TigerState {
+    pub const fn new() -> Self {
+        TigerState { a: Wrapping(A), b: Wrapping(B), c: Wrapping(C) }
+    }
+
+    /// State with the chaining variables `a`, `b` and `c` instead of the
+    /// Tiger initial values
+    #[cfg(feature = "hazmat")]
+    pub const fn with_iv(a: u64, b: u64, c: u64) -> Self {
+        TigerState { a: Wrapping(a), b: Wrapping(b), c: Wrapping(c) }
+    }
+
+    /// Compress `blocks` in sequence without secret-dependent memory
+    /// accesses, see the `masked` module
+    #[cfg(feature = "constant-time")]
+    pub fn process_blocks<'a, I>(&mut self, blocks: I, passes: usize)
+        where I: IntoIterator<Item = &'a Block>
+    {
+        let mut words = self.words();
+        ::masked::compress(&mut words, blocks, passes);
+        *self = Self::from_words(words);
+    }
+
+    /// Compress `blocks` in sequence with the implementation for 32-bit
+    /// targets, see the `narrow` module
+    #[cfg(all(target_pointer_width = "32", not(feature = "constant-time"),
+        not(any(feature = "small-tables", feature = "compact"))))]
+    pub fn process_blocks<'a, I>(&mut self, blocks: I, passes: usize)
+        where I: IntoIterator<Item = &'a Block>
+    {
+        let mut words = self.words();
+        ::narrow::compress(&mut words, blocks, passes);
+        *self = Self::from_words(words);
+    }
+
+    /// Compress `blocks` in sequence, the chaining variables are kept in
+    /// locals for the whole call
+    #[cfg(not(any(feature = "constant-time", all(target_pointer_width = "32",
+        not(any(feature = "small-tables", feature = "compact"))))))]
+    pub fn process_blocks<'a, I>(&mut self, blocks: I, passes: usize)
+        where I: IntoIterator<Item = &'a Block>
+    {
+        #[cfg(feature = "small-tables")]
+        ::sboxes::init();
+        let (mut a, mut b, mut c) = (self.a, self.b, self.c);
+        for block in blocks {
+            let mut x = [Wrapping(0u64); 8];
+            for (w, chunk) in x.iter_mut().zip(block.chunks_exact(8)) {
+                *w = Wrapping(read_u64(chunk));
+            }
+
+            compress!(a, b, c, x, passes);
+        }
+        self.a = a;
+        self.b = b;
+        self.c = c;
+    }
+
+    /// Pad the final partial block and compress it, see `pad_final`
+    pub fn finalize(&mut self, buf: &mut [u8; 64], pos: usize, pad: u8,
+        bit_length: u64, passes: usize)
+    {
+        pad_final(buf, pos, pad, bit_length,
+            |block| self.process_blocks(iter::once(block), passes));
+    }
+
+    pub fn words(&self) -> [u64; 3] {
+        [self.a.0, self.b.0, self.c.0]
+    }
+
+    pub fn from_words(words: [u64; 3]) -> Self {
+        TigerState {
+            a: Wrapping(words[0]),
+            b: Wrapping(words[1]),
+            c: Wrapping(words[2]),
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    pub fn wipe(&mut self) {
+        use zeroize::Zeroize;
+
+        self.a.0.zeroize();
+        self.b.0.zeroize();
+        self.c.0.zeroize();
+    }
+
+    pub fn write_output(&self, out: &mut GenericArray<u8, U24>) {
+        write_u64s(&self.words(), out);
+    }
+}
@@ -0,0 +1,132 @@
+//! Generation of the Tiger S-boxes
+//!
+//! The four S-boxes are not arbitrary constants: the Tiger paper derives
+//! them from the string below by starting with identity tables and
+//! swapping bytes of every column according to the output of the Tiger
+//! compression function, which itself uses the partially generated tables.
+//! Five passes over the tables are made.
+//!
+//! Everything here is a `const fn`, written with `while` loops and explicit
+//! wrapping arithmetic, so that the tables can be built during compilation.
+//! With the `small-tables` feature the `sboxes` module runs the same
+//! procedure on its tables in RAM instead.
+const SEED: &[u8; 64] =
+    b"Tiger - A Fast New Hash Function, by Ross Anderson and Eli Biham";
+pub(crate) const PASSES: usize = 5;
+
+#[cfg(not(feature = "small-tables"))]
+pub type Tables = [[u64; 256]; 4];
+
+/// The seed string as little-endian message block
+pub(crate) const fn seed_block() -> [u64; 8] {
+    let mut block = [0u64; 8];
+    let mut i = 0;
+    while i < 64 {
+        block[i / 8] |= (SEED[i] as u64) << (8 * (i % 8));
+        i += 1;
+    }
+    block
+}
+
+/// Generate the four S-boxes `T1`, `T2`, `T3` and `T4`
+#[cfg(not(feature = "small-tables"))]
+pub const fn generate() -> Tables {
+    let mut t = [[0u64; 256]; 4];
+    let mut i = 0;
+    while i < 256 {
+        let w = 0x0101_0101_0101_0101 * i as u64;
+        t[0][i] = w;
+        t[1][i] = w;
+        t[2][i] = w;
+        t[3][i] = w;
+        i += 1;
+    }
+
+    let block = seed_block();
+    let mut state = [
+        0x0123_4567_89AB_CDEF, 0xFEDC_BA98_7654_3210, 0xF096_A5B4_C3B2_E187,
+    ];
+    let mut abc = 2;
+    let mut pass = 0;
+    while pass < PASSES {
+        let mut i = 0;
+        while i < 256 {
+            let mut sb = 0;
+            while sb < 4 {
+                abc += 1;
+                if abc == 3 {
+                    abc = 0;
+                    state = compress(&t, state, block);
+                }
+                let mut col = 0;
+                while col < 8 {
+                    let shift = 8 * col;
+                    let mask = 0xff << shift;
+                    let j = ((state[abc] >> shift) & 0xff) as usize;
+                    let (bi, bj) = (t[sb][i] & mask, t[sb][j] & mask);
+                    t[sb][i] = (t[sb][i] & !mask) | bj;
+                    t[sb][j] = (t[sb][j] & !mask) | bi;
+                    col += 1;
+                }
+                sb += 1;
+            }
+            i += 1;
+        }
+        pass += 1;
+    }
+    t
+}
+
+pub(crate) const fn key_schedule(mut x: [u64; 8]) -> [u64; 8] {
+    x[0] = x[0].wrapping_sub(x[7] ^ 0xA5A5_A5A5_A5A5_A5A5);
+    x[1] ^= x[0];
+    x[2] = x[2].wrapping_add(x[1]);
+    x[3] = x[3].wrapping_sub(x[2] ^ ((!x[1]) << 19));
+    x[4] ^= x[3];
+    x[5] = x[5].wrapping_add(x[4]);
+    x[6] = x[6].wrapping_sub(x[5] ^ ((!x[4]) >> 23));
+    x[7] ^= x[6];
+    x[0] = x[0].wrapping_add(x[7]);
+    x[1] = x[1].wrapping_sub(x[0] ^ ((!x[7]) << 19));
+    x[2] ^= x[1];
+    x[3] = x[3].wrapping_add(x[2]);
+    x[4] = x[4].wrapping_sub(x[3] ^ ((!x[2]) >> 23));
+    x[5] ^= x[4];
+    x[6] = x[6].wrapping_add(x[5]);
+    x[7] = x[7].wrapping_sub(x[6] ^ 0x0123_4567_89AB_CDEF);
+    x
+}
+
+pub(crate) const fn byte(w: u64, k: u32) -> usize {
+    ((w >> (8 * k)) & 0xff) as usize
+}
+
+/// The three-pass compression function using tables `t`, as a loop over
+/// all 24 rounds since code size matters more than speed here
+#[cfg(not(feature = "small-tables"))]
+const fn compress(t: &Tables, state: [u64; 3], mut x: [u64; 8]) -> [u64; 3] {
+    let mut s = state;
+    let mut n = 0;
+    while n < 24 {
+        if n == 8 || n == 16 {
+            x = key_schedule(x);
+        }
+        let mul = [5, 7, 9][n / 8];
+        let (a, b, c) = (n % 3, (n + 1) % 3, (n + 2) % 3);
+        s[c] ^= x[n % 8];
+        let w = s[c];
+        s[a] = s[a].wrapping_sub(
+            t[0][byte(w, 0)] ^ t[1][byte(w, 2)] ^ t[2][byte(w, 4)] ^ t[3][byte(w, 6)]
+        );
+        s[b] = s[b].wrapping_add(
+            t[3][byte(w, 1)] ^ t[2][byte(w, 3)] ^ t[1][byte(w, 5)] ^ t[0][byte(w, 7)]
+        );
+        s[b] = s[b].wrapping_mul(mul);
+        n += 1;
+    }
+    [
+        s[0] ^ state[0],
+        s[1].wrapping_sub(state[1]),
+        s[2].wrapping_add(state[2]),
+    ]
+}
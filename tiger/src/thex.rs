@@ -0,0 +1,456 @@
+//! Generic [THEX][1] tree hash construction over any `Digest`.
+//!
+//! The message is split into 1024 byte leaves, each leaf is hashed as
+//! `H(0x00 || leaf)` and pairs of nodes are combined as
+//! `H(0x01 || left || right)` until a single root remains. A node without
+//! a sibling is promoted to the next level unchanged. The empty message is
+//! hashed as a single empty leaf.
+//!
+//! The Tiger instantiation of this construction (TTH) is available as
+//! [`TigerTree`](../tth/type.TigerTree.html).
+//!
+//! [1]: https://adc.sourceforge.io/draft-jchapweske-thex-02.html
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{io, slice};
+#[cfg(feature = "std")]
+use std::vec::Vec;
+#[cfg(feature = "parallel")]
+use std::fs::File;
+#[cfg(feature = "parallel")]
+use std::io::Read;
+#[cfg(feature = "parallel")]
+use std::path::Path;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use digest::{Digest, Input, FixedOutput, Reset};
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::U64;
+
+/// Size of a THEX leaf in bytes
+pub const LEAF_SIZE: usize = 1024;
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+// 2^64 bytes split into 1024 byte leaves can not produce deeper trees
+type MaxDepth = U64;
+
+type Output<D> = GenericArray<u8, <D as Digest>::OutputSize>;
+
+/// THEX tree hasher generic over the underlying hash function `D`
+///
+/// Leaves are never kept in memory: completed subtrees are merged as soon as
+/// possible and only one pending subtree root per tree level is kept (the
+/// "stack of subtree roots" technique). Thus the hasher has a fixed size of
+/// at most 64 node values regardless of the message length, which allows
+/// hashing of arbitrarily large files in constant memory.
+#[derive(Clone)]
+pub struct Thex<D: Digest + Clone> {
+    leaf: D,
+    leaf_len: usize,
+    leaves: u64,
+    roots: GenericArray<GenericArray<u8, D::OutputSize>, MaxDepth>,
+}
+
+impl<D: Digest + Clone> Default for Thex<D> {
+    fn default() -> Self {
+        Thex {
+            leaf: Self::leaf_hasher(),
+            leaf_len: 0,
+            leaves: 0,
+            roots: Default::default(),
+        }
+    }
+}
+
+impl<D: Digest + Clone> Thex<D> {
+    fn leaf_hasher() -> D {
+        let mut leaf = D::new();
+        leaf.input([LEAF_PREFIX]);
+        leaf
+    }
+
+    /// Compute hash of a single leaf, `data` must not exceed `LEAF_SIZE`
+    /// bytes
+    pub fn leaf_hash(data: &[u8]) -> GenericArray<u8, D::OutputSize> {
+        assert!(data.len() <= LEAF_SIZE, "THEX leaf is longer than 1024 bytes");
+        let mut leaf = Self::leaf_hasher();
+        leaf.input(data);
+        leaf.result()
+    }
+
+    /// Combine two sibling nodes into their parent node
+    pub fn node_hash(left: &[u8], right: &[u8])
+        -> GenericArray<u8, D::OutputSize>
+    {
+        let mut node = D::new();
+        node.input([NODE_PREFIX]);
+        node.input(left);
+        node.input(right);
+        node.result()
+    }
+
+    /// Verify that `node` is the `index`-th of `count` nodes on some level of
+    /// the tree with the given `root`.
+    ///
+    /// `proof` contains the sibling nodes on the path from `node` to the
+    /// root, ordered from the bottom up. Promoted nodes have no sibling, so
+    /// the expected length of the proof is derived from `index` and `count`.
+    pub fn verify_node(
+        root: &Output<D>, node: &Output<D>, index: u64, count: u64,
+        proof: &[Output<D>],
+    ) -> bool {
+        if index >= count { return false; }
+
+        let mut node = node.clone();
+        let mut index = index;
+        let mut count = count;
+        let mut proof = proof.iter();
+        while count > 1 {
+            let is_promoted = index & 1 == 0 && index + 1 == count;
+            if !is_promoted {
+                let sibling = match proof.next() {
+                    Some(sibling) => sibling,
+                    None => return false,
+                };
+                node = if index & 1 == 0 {
+                    Self::node_hash(&node, sibling)
+                } else {
+                    Self::node_hash(sibling, &node)
+                };
+            }
+            index >>= 1;
+            count = (count >> 1) + (count & 1);
+        }
+        proof.next().is_none() && node == *root
+    }
+
+    /// Verify that the leaf `data` is the `index`-th of `count` leaves of the
+    /// tree with the given `root`, see `verify_node` for details.
+    pub fn verify_leaf(
+        root: &Output<D>, data: &[u8], index: u64, count: u64,
+        proof: &[Output<D>],
+    ) -> bool {
+        data.len() <= LEAF_SIZE &&
+            Self::verify_node(root, &Self::leaf_hash(data), index, count, proof)
+    }
+
+    fn push_leaf<F>(&mut self, f: &mut F)
+        where F: FnMut(usize, &Output<D>)
+    {
+        let leaf = core::mem::replace(&mut self.leaf, Self::leaf_hasher());
+        let node = leaf.result();
+        self.leaf_len = 0;
+        f(0, &node);
+        self.push_subtree(0, node, f);
+    }
+
+    /// Append root `node` of a complete subtree with 2^`level` leaves, the
+    /// number of already processed leaves must be a multiple of 2^`level`
+    fn push_subtree<F>(&mut self, level: usize, mut node: Output<D>, f: &mut F)
+        where F: FnMut(usize, &Output<D>)
+    {
+        debug_assert!(self.leaf_len == 0);
+        debug_assert!(self.leaves & ((1 << level) - 1) == 0);
+
+        // `leaves` works as a binary counter: bit `i` is set when
+        // `roots[i]` holds the root of a complete subtree with 2^i leaves
+        let start = level;
+        let mut level = level;
+        while self.leaves & (1 << level) != 0 {
+            node = Self::node_hash(&self.roots[level], &node);
+            level += 1;
+            f(level, &node);
+        }
+        self.roots[level] = node;
+        self.leaves += 1 << start;
+    }
+
+    /// Process `input`, calling `f` with level and value of every completed
+    /// tree node
+    fn process<F>(&mut self, mut input: &[u8], f: &mut F)
+        where F: FnMut(usize, &Output<D>)
+    {
+        while !input.is_empty() {
+            let n = core::cmp::min(LEAF_SIZE - self.leaf_len, input.len());
+            let (l, r) = input.split_at(n);
+            self.leaf.input(l);
+            self.leaf_len += n;
+            input = r;
+
+            if self.leaf_len == LEAF_SIZE {
+                self.push_leaf(f);
+            }
+        }
+    }
+
+    /// Flush the last (possibly partial) leaf
+    fn finish_leaves<F>(&mut self, f: &mut F)
+        where F: FnMut(usize, &Output<D>)
+    {
+        if self.leaf_len != 0 || self.leaves == 0 {
+            self.push_leaf(f);
+        }
+    }
+
+    /// Combine pending subtree roots below `level` into the last node of
+    /// this level, which was promoted from a lower level
+    fn pending_node(&self, level: usize) -> Option<Output<D>> {
+        let mut acc: Option<Output<D>> = None;
+        for i in 0..level {
+            if self.leaves & (1 << i) == 0 { continue; }
+            acc = Some(match acc {
+                None => self.roots[i].clone(),
+                Some(node) => Self::node_hash(&self.roots[i], &node),
+            });
+        }
+        acc
+    }
+
+    fn finalize(&mut self) -> Output<D> {
+        self.finish_leaves(&mut |_, _| ());
+        self.pending_node(self.roots.len())
+            .expect("at least one leaf is present")
+    }
+}
+
+// Subtrees of 2^8 leaves (256 KiB) are hashed in parallel
+#[cfg(feature = "parallel")]
+const PARALLEL_LEVEL: usize = 8;
+#[cfg(feature = "parallel")]
+const PARALLEL_CHUNK: usize = LEAF_SIZE << PARALLEL_LEVEL;
+// Number of chunks read from a file at once (16 MiB)
+#[cfg(feature = "parallel")]
+const PARALLEL_BATCH: usize = 64;
+
+#[cfg(feature = "parallel")]
+impl<D: Digest + Clone> Thex<D> where Output<D>: Send {
+    /// Hash leaf-aligned chunks of `data` in parallel on the rayon thread
+    /// pool and merge the resulting subtree roots into the data's tree
+    /// root
+    pub fn hash_slice_parallel(data: &[u8]) -> Output<D> {
+        let mut tree = Self::default();
+        let rest = tree.process_parallel(data);
+        Input::input(&mut tree, rest);
+        tree.finalize()
+    }
+
+    /// Hash file at `path` in parallel, see `hash_slice_parallel`
+    pub fn hash_file_parallel<P: AsRef<Path>>(path: P) -> io::Result<Output<D>> {
+        let mut file = File::open(path)?;
+        let mut tree = Self::default();
+        let mut buf = std::vec::from_elem(0, PARALLEL_CHUNK * PARALLEL_BATCH);
+        loop {
+            let mut n = 0;
+            while n < buf.len() {
+                match file.read(&mut buf[n..]) {
+                    Ok(0) => break,
+                    Ok(m) => n += m,
+                    Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+                    Err(e) => return Err(e),
+                }
+            }
+            let rest = tree.process_parallel(&buf[..n]);
+            if n < buf.len() {
+                Input::input(&mut tree, rest);
+                return Ok(tree.finalize());
+            }
+        }
+    }
+
+    /// Push roots of all complete chunks of `data`, returning the remaining
+    /// data which does not fill a complete chunk
+    fn process_parallel<'a>(&mut self, data: &'a [u8]) -> &'a [u8] {
+        let (chunks, rest) = data.split_at(data.len() - data.len() % PARALLEL_CHUNK);
+        let roots: Vec<Output<D>> = chunks.par_chunks(PARALLEL_CHUNK)
+            .map(Self::digest)
+            .collect();
+        for root in roots {
+            self.push_subtree(PARALLEL_LEVEL, root, &mut |_, _| ());
+        }
+        rest
+    }
+}
+
+impl<D: Digest + Clone> Input for Thex<D> {
+    fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+        self.process(input.as_ref(), &mut |_, _| ());
+    }
+}
+
+impl<D: Digest + Clone> FixedOutput for Thex<D> {
+    type OutputSize = D::OutputSize;
+
+    fn fixed_result(mut self) -> GenericArray<u8, Self::OutputSize> {
+        self.finalize()
+    }
+}
+
+impl<D: Digest + Clone> Reset for Thex<D> {
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl<D: Digest + Clone> fmt::Debug for Thex<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Thex {{ ... }}")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D: Digest + Clone> ::std::io::Write for Thex<D> {
+    fn write(&mut self, buf: &[u8]) -> ::std::io::Result<usize> {
+        Input::input(self, buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> ::std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// THEX tree hasher which additionally records all nodes of one tree level
+///
+/// Level 0 consists of the leaf hashes, nodes on level `n` cover
+/// `LEAF_SIZE << n` bytes of the message. The recorded level is what ADC and
+/// DC++ exchange as the `tthl` block for verification of file segments.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct ThexLevel<D: Digest + Clone> {
+    tree: Thex<D>,
+    level: usize,
+    nodes: Vec<Output<D>>,
+}
+
+#[cfg(feature = "std")]
+impl<D: Digest + Clone> ThexLevel<D> {
+    /// Create new hasher which records nodes on the given tree `level`
+    pub fn new(level: usize) -> Self {
+        ThexLevel { tree: Default::default(), level, nodes: Vec::new() }
+    }
+
+    /// Tree level recorded by this hasher
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    /// Retrieve the tree root together with the recorded level
+    pub fn finalize(mut self) -> TreeLevel<D> {
+        let level = self.level;
+        {
+            let nodes = &mut self.nodes;
+            self.tree.finish_leaves(&mut |l, node| {
+                if l == level { nodes.push(node.clone()); }
+            });
+        }
+        if let Some(node) = self.tree.pending_node(level) {
+            self.nodes.push(node);
+        }
+        let root = self.tree.finalize();
+        TreeLevel { root, level, nodes: self.nodes }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D: Digest + Clone> Input for ThexLevel<D> {
+    fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+        let level = self.level;
+        let nodes = &mut self.nodes;
+        self.tree.process(input.as_ref(), &mut |l, node| {
+            if l == level { nodes.push(node.clone()); }
+        });
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D: Digest + Clone> Reset for ThexLevel<D> {
+    fn reset(&mut self) {
+        Reset::reset(&mut self.tree);
+        self.nodes.clear();
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D: Digest + Clone> fmt::Debug for ThexLevel<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ThexLevel {{ ... }}")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D: Digest + Clone> io::Write for ThexLevel<D> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Input::input(self, buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Tree root and all nodes of one level of a THEX tree
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeLevel<D: Digest + Clone> {
+    root: Output<D>,
+    level: usize,
+    nodes: Vec<Output<D>>,
+}
+
+#[cfg(feature = "std")]
+impl<D: Digest + Clone> TreeLevel<D> {
+    /// Root of the tree
+    pub fn root(&self) -> &Output<D> {
+        &self.root
+    }
+
+    /// Level of the recorded nodes
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    /// Number of message bytes covered by each recorded node (except
+    /// possibly the last one)
+    pub fn block_size(&self) -> u64 {
+        (LEAF_SIZE as u64) << self.level
+    }
+
+    /// Iterate over the recorded nodes from left to right
+    pub fn leaves<'a>(&'a self) -> slice::Iter<'a, Output<D>> {
+        self.nodes.iter()
+    }
+
+    /// Build inclusion proof for the `index`-th recorded node, which can be
+    /// checked with `Thex::verify_node` using `self.leaves().len()` as the
+    /// node count. Returns `None` if `index` is out of range.
+    pub fn proof(&self, index: usize) -> Option<Vec<Output<D>>> {
+        if index >= self.nodes.len() { return None; }
+
+        let mut proof = Vec::new();
+        let mut nodes = self.nodes.clone();
+        let mut index = index;
+        while nodes.len() > 1 {
+            if let Some(sibling) = nodes.get(index ^ 1) {
+                proof.push(sibling.clone());
+            }
+            nodes = nodes.chunks(2).map(|pair| match pair.len() {
+                2 => Thex::<D>::node_hash(&pair[0], &pair[1]),
+                _ => pair[0].clone(),
+            }).collect();
+            index /= 2;
+        }
+        Some(proof)
+    }
+
+    /// Write the recorded nodes in the binary `tthl` format, i.e. as a plain
+    /// concatenation of their values
+    pub fn write_tthl<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        for node in self.nodes.iter() {
+            w.write_all(node)?;
+        }
+        Ok(())
+    }
+}
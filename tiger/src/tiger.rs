@@ -0,0 +1,198 @@
+use core::{cmp, iter};
+use core::marker::PhantomData;
+use digest::FixedOutput;
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::{Unsigned, U24};
+use variant::Variant;
+use snapshot::{InvalidState, STATE_SIZE};
+use state::TigerState;
+use Block;
+
+/// Maximum message length in bytes
+///
+/// Tiger appends the message length as a 64-bit number of bits, so longer
+/// messages can not be represented. Hashers panic when fed more data.
+pub const MAX_MESSAGE_LEN: u64 = (1 << 61) - 1;
+
+/// Retrieve the digest into caller-provided memory
+///
+/// The generic counterpart of the inherent `finalize_into` methods, for
+/// code which is generic over the hasher. The hasher is reset afterwards,
+/// so it can be reused for the next message.
+pub trait FixedOutputInto: FixedOutput {
+    /// Write the digest to `out` and reset the hasher
+    fn fixed_result_into(&mut self, out: &mut GenericArray<u8, Self::OutputSize>);
+}
+
+/// Complete state of a Tiger hasher: chaining variables, message length in
+/// bytes and the buffered message bytes, of which there are `length % 64`
+#[derive(Clone, Copy)]
+pub struct Snapshot {
+    pub state: [u64; 3],
+    pub length: u64,
+    pub buffer: [u8; 64],
+}
+
+impl Snapshot {
+    pub fn buffered(&self) -> &[u8] {
+        &self.buffer[..(self.length % 64) as usize]
+    }
+}
+
+/// Buffering and padding shared by all Tiger variants, generic over the
+/// padding byte `Pad` and the number of passes `P`.
+///
+/// The partial block is stored inline, the number of buffered bytes is
+/// always `length_bytes % 64`.
+#[derive(Clone)]
+pub struct TigerEngine<Pad: Variant, P: Unsigned> {
+    length_bytes: u64,
+    buffer: [u8; 64],
+    state: TigerState,
+    _pad: PhantomData<Pad>,
+    _passes: PhantomData<P>,
+}
+
+impl<Pad: Variant, P: Unsigned> Default for TigerEngine<Pad, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Pad: Variant, P: Unsigned> TigerEngine<Pad, P> {
+    pub const fn new() -> Self {
+        TigerEngine {
+            length_bytes: 0,
+            buffer: [0; 64],
+            state: TigerState::new(),
+            _pad: PhantomData,
+            _passes: PhantomData,
+        }
+    }
+
+    #[cfg(feature = "hazmat")]
+    pub const fn with_iv(a: u64, b: u64, c: u64) -> Self {
+        TigerEngine {
+            length_bytes: 0,
+            buffer: [0; 64],
+            state: TigerState::with_iv(a, b, c),
+            _pad: PhantomData,
+            _passes: PhantomData,
+        }
+    }
+
+    fn position(&self) -> usize {
+        (self.length_bytes % 64) as usize
+    }
+
+    pub fn input(&mut self, mut input: &[u8]) {
+        let pos = self.position();
+        // The reference implementation takes the bit length mod 2^64, so
+        // longer messages would silently collide with shorter ones
+        self.length_bytes = self.length_bytes.checked_add(input.len() as u64)
+            .filter(|&len| len <= MAX_MESSAGE_LEN)
+            .expect("Tiger message length exceeds 2^64 - 1 bits");
+
+        if pos != 0 {
+            let n = cmp::min(64 - pos, input.len());
+            let (l, r) = input.split_at(n);
+            self.buffer[pos..pos + n].copy_from_slice(l);
+            input = r;
+            if pos + n < 64 { return; }
+            let block = Block::from_slice(&self.buffer);
+            self.state.process_blocks(iter::once(block), P::to_usize());
+        }
+
+        // full blocks are compressed straight from the input, only the
+        // tail is copied into the buffer
+        let mut blocks = input.chunks_exact(64);
+        self.state.process_blocks((&mut blocks).map(Block::from_slice), P::to_usize());
+        let rest = blocks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+    }
+
+    pub fn finalize(&mut self) -> GenericArray<u8, U24> {
+        self.finalize_bits(0, 0)
+    }
+
+    /// Finalize the message and write the first `out.len()` bytes of the
+    /// digest to `out`, which must not be longer than 24 bytes
+    pub fn finalize_into(&mut self, out: &mut [u8]) {
+        self.finalize_bits_into(0, 0, out);
+    }
+
+    /// Finalize a message which ends with the first `bits` bits of `last`,
+    /// see `variant::Variant::partial`
+    pub fn finalize_bits(&mut self, last: u8, bits: u8) -> GenericArray<u8, U24> {
+        let mut out = GenericArray::default();
+        self.finalize_bits_into(last, bits, &mut out);
+        out
+    }
+
+    fn finalize_bits_into(&mut self, last: u8, bits: u8, out: &mut [u8]) {
+        debug_assert!(out.len() <= 24);
+        assert!(bits < 8, "a partial byte has at most 7 bits");
+        let pos = self.position();
+        let bit_length = (self.length_bytes << 3) | u64::from(bits);
+        self.state.finalize(&mut self.buffer, pos, Pad::partial(last, bits),
+            bit_length, P::to_usize());
+
+        for (chunk, w) in out.chunks_mut(8).zip(self.state.words().iter()) {
+            chunk.copy_from_slice(&w.to_le_bytes()[..chunk.len()]);
+        }
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            state: self.state.words(),
+            length: self.length_bytes,
+            buffer: self.buffer,
+        }
+    }
+
+    pub fn from_snapshot(snapshot: &Snapshot) -> Self {
+        TigerEngine {
+            length_bytes: snapshot.length,
+            buffer: snapshot.buffer,
+            state: TigerState::from_words(snapshot.state),
+            _pad: PhantomData,
+            _passes: PhantomData,
+        }
+    }
+
+    pub fn from_midstate(state: [u64; 3], processed_bytes: u64) -> Self {
+        assert!(processed_bytes & 63 == 0,
+            "midstate must cover a whole number of blocks");
+        assert!(processed_bytes <= MAX_MESSAGE_LEN,
+            "Tiger message length exceeds 2^64 - 1 bits");
+        Self::from_snapshot(&Snapshot {
+            state,
+            length: processed_bytes,
+            buffer: [0; 64],
+        })
+    }
+
+    pub fn serialize_state(&self) -> [u8; STATE_SIZE] {
+        self.snapshot().encode(Pad::BYTE, P::to_u8())
+    }
+
+    pub fn deserialize_state(state: &[u8]) -> Result<Self, InvalidState> {
+        Snapshot::decode(state, Pad::BYTE, P::to_u8())
+            .map(|snapshot| Self::from_snapshot(&snapshot))
+    }
+
+    /// Overwrite state, length and buffered bytes with zeros
+    #[cfg(feature = "zeroize")]
+    pub fn wipe(&mut self) {
+        use zeroize::Zeroize;
+
+        self.state.wipe();
+        self.length_bytes.zeroize();
+        self.buffer.zeroize();
+    }
+
+    pub fn reset(&mut self) {
+        self.state = TigerState::new();
+        self.length_bytes = 0;
+    }
+}
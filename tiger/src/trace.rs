@@ -0,0 +1,122 @@
+//! Compression function reporting its intermediate values, enabled by the
+//! `trace` feature
+//!
+//! The callback passed to `compress` or `hash` is invoked after every round
+//! and key schedule and at the end of every block, which helps to follow
+//! the algorithm step by step or to find the first diverging value when
+//! porting it. The tracing code is a separate, straightforward
+//! implementation with table lookups, so it is slow and not constant-time
+//! even with the `constant-time` feature, and the hashers are not affected
+//! by it.
+//!
+//! ```rust
+//! # extern crate tiger;
+//! # fn main() {
+//! use tiger::trace::{self, Event};
+//!
+//! let mut rounds = 0;
+//! let digest = tiger::trace::hash(b"abc", |event| {
+//!     if let Event::Round { pass, round, state } = *event {
+//!         rounds += 1;
+//!         println!("pass {} round {}: {:016x} {:016x} {:016x}",
+//!             pass, round, state[0], state[1], state[2]);
+//!     }
+//! });
+//! assert_eq!(rounds, 24);
+//! assert_eq!(digest, tiger::hash(b"abc"));
+//! # }
+//! ```
+use core::num::Wrapping;
+use state::{pad_final, read_u64, write_u64s, TigerState};
+use variant::{self, Variant};
+use {Block, TigerOutput, MAX_MESSAGE_LEN};
+
+/// Intermediate value of the compression function
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// The variables `a`, `b` and `c` after round `round` (0 to 7) of pass
+    /// `pass`, named as in the reference implementation
+    Round { pass: usize, round: usize, state: [u64; 3] },
+    /// The message words after the key schedule preceding pass `pass`
+    KeySchedule { pass: usize, words: [u64; 8] },
+    /// The chaining variables after the feedforward at the end of a block
+    Block { state: [u64; 3] },
+}
+
+/// Compress `block` with `passes` passes like `tiger::compress`, reporting
+/// every intermediate value to `trace`
+pub fn compress<F>(state: &mut [u64; 3], block: &Block, passes: usize, mut trace: F)
+    where F: FnMut(&Event)
+{
+    compress_block(state, block, passes, &mut trace);
+}
+
+/// Compute the Tiger digest of `data`, reporting the intermediate values of
+/// every block to `trace`
+///
+/// # Panics
+///
+/// If `data` is longer than `MAX_MESSAGE_LEN`.
+pub fn hash<F>(data: &[u8], mut trace: F) -> TigerOutput
+    where F: FnMut(&Event)
+{
+    assert!(data.len() as u64 <= MAX_MESSAGE_LEN,
+        "Tiger message length exceeds 2^64 - 1 bits");
+    let mut state = TigerState::new().words();
+    let mut blocks = data.chunks_exact(64);
+    for block in &mut blocks {
+        compress_block(&mut state, Block::from_slice(block), 3, &mut trace);
+    }
+    let rest = blocks.remainder();
+    let mut buf = [0u8; 64];
+    buf[..rest.len()].copy_from_slice(rest);
+    pad_final(&mut buf, rest.len(), variant::Tiger::BYTE, (data.len() as u64) << 3,
+        |block| compress_block(&mut state, block, 3, &mut trace));
+
+    let mut out = [0u8; 24];
+    write_u64s(&state[..], &mut out);
+    out
+}
+
+fn compress_block<F>(state: &mut [u64; 3], block: &Block, passes: usize, trace: &mut F)
+    where F: FnMut(&Event)
+{
+    #[cfg(feature = "small-tables")]
+    ::sboxes::init();
+    let mut x = [Wrapping(0u64); 8];
+    for (w, chunk) in x.iter_mut().zip(block.chunks_exact(8)) {
+        *w = Wrapping(read_u64(chunk));
+    }
+    let mut s = [Wrapping(state[0]), Wrapping(state[1]), Wrapping(state[2])];
+    let saved = s;
+
+    // same structure as the `compact` variant of `compress!`
+    for pass in 0..passes {
+        if pass > 0 {
+            key_schedule!(x);
+            let mut words = [0u64; 8];
+            for (w, x) in words.iter_mut().zip(x.iter()) {
+                *w = x.0;
+            }
+            trace(&Event::KeySchedule { pass, words });
+        }
+        let (r, mul) = match pass {
+            0 => (0, 5),
+            1 => (2, 7),
+            2 => (1, 9),
+            _ => (0, 9),
+        };
+        for (i, &x) in x.iter().enumerate() {
+            let (ia, ib, ic) = ((r + i) % 3, (r + i + 1) % 3, (r + i + 2) % 3);
+            round!(s[ia], s[ib], s[ic], x, mul);
+            let state = [s[0].0, s[1].0, s[2].0];
+            trace(&Event::Round { pass, round: i, state });
+        }
+        if pass >= 3 {
+            s = [s[2], s[0], s[1]];
+        }
+    }
+
+    *state = [(s[0] ^ saved[0]).0, (s[1] - saved[1]).0, (s[2] + saved[2]).0];
+    trace(&Event::Block { state: *state });
+}
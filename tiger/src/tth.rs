@@ -0,0 +1,92 @@
+//! Tiger Tree Hash (TTH), i.e. the [THEX][1] tree hash instantiated with
+//! Tiger.
+//!
+//! ```rust
+//! # #[macro_use] extern crate hex_literal;
+//! # extern crate tiger;
+//! # fn main() {
+//! use tiger::Digest;
+//! use tiger::tth::TigerTree;
+//!
+//! let result = TigerTree::digest(b"");
+//! assert_eq!(result[..], hex!("
+//!     5d9ed00a030e638bdb753a6a24fb900e5a63b8e73e6c25b6
+//! ")[..]);
+//! # }
+//! ```
+//!
+//! [1]: https://adc.sourceforge.io/draft-jchapweske-thex-02.html
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::U24;
+use thex::Thex;
+#[cfg(feature = "std")]
+use std::fs::File;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+#[cfg(feature = "std")]
+use std::path::Path;
+#[cfg(feature = "std")]
+use thex::{ThexLevel, TreeLevel};
+use Tiger;
+
+pub use thex::LEAF_SIZE;
+
+/// The Tiger Tree hasher
+pub type TigerTree = Thex<Tiger>;
+
+/// The Tiger Tree hasher recording nodes of one tree level, see `ThexLevel`
+#[cfg(feature = "std")]
+pub type TigerTreeLevel = ThexLevel<Tiger>;
+
+/// Tiger Tree root and nodes of one level, see `TreeLevel`
+#[cfg(feature = "std")]
+pub type TigerTreeNodes = TreeLevel<Tiger>;
+
+/// Compute hash of a single leaf, `data` must not exceed `LEAF_SIZE` bytes
+pub fn leaf_hash(data: &[u8]) -> GenericArray<u8, U24> {
+    TigerTree::leaf_hash(data)
+}
+
+/// Combine two sibling nodes into their parent node
+pub fn node_hash(left: &[u8], right: &[u8]) -> GenericArray<u8, U24> {
+    TigerTree::node_hash(left, right)
+}
+
+/// Verify that `node` is the `index`-th of `count` nodes on some level of
+/// the Tiger Tree with the given `root`, see `Thex::verify_node`.
+pub fn verify_node(
+    root: &GenericArray<u8, U24>, node: &GenericArray<u8, U24>,
+    index: u64, count: u64, proof: &[GenericArray<u8, U24>],
+) -> bool {
+    TigerTree::verify_node(root, node, index, count, proof)
+}
+
+/// Verify that the 1 KiB `block` is the `index`-th of `count` leaves of the
+/// Tiger Tree with the given `root`, see `Thex::verify_node`.
+pub fn verify_block(
+    root: &GenericArray<u8, U24>, block: &[u8],
+    index: u64, count: u64, proof: &[GenericArray<u8, U24>],
+) -> bool {
+    TigerTree::verify_leaf(root, block, index, count, proof)
+}
+
+/// Compute the Tiger Tree root of all data read from `reader`, calling
+/// `progress` with the number of bytes hashed so far, see
+/// `hash_reader_with_progress`
+#[cfg(feature = "std")]
+pub fn hash_reader_with_progress<R, F>(reader: R, interval: u64, progress: F)
+    -> io::Result<GenericArray<u8, U24>>
+    where R: Read, F: FnMut(u64)
+{
+    ::io::digest_reader::<TigerTree, _, _>(reader, interval, progress)
+}
+
+/// Compute the Tiger Tree root of the file at `path` with progress reports,
+/// see `hash_reader_with_progress`
+#[cfg(feature = "std")]
+pub fn hash_file_with_progress<P, F>(path: P, interval: u64, progress: F)
+    -> io::Result<GenericArray<u8, U24>>
+    where P: AsRef<Path>, F: FnMut(u64)
+{
+    hash_reader_with_progress(File::open(path)?, interval, progress)
+}
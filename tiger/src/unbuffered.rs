@@ -0,0 +1,104 @@
+//! Tiger without a block buffer, for callers which deliver whole blocks
+use core::marker::PhantomData;
+use digest::generic_array::GenericArray;
+use digest::generic_array::typenum::U24;
+use state::TigerState;
+use tiger::MAX_MESSAGE_LEN;
+use variant::{self, Variant};
+use Block;
+
+/// The Tiger hasher without an internal block buffer
+///
+/// `TigerCore` only holds the chaining variables and the number of bytes
+/// processed, so it is smaller than `Tiger` and never copies input. Data
+/// is passed as whole 64 byte blocks to `update_blocks`, e.g. straight from
+/// DMA-aligned receive buffers, and the final partial block is passed to
+/// `finalize`, which applies the padding of the variant `V`, i.e.
+/// `TigerCore` computes Tiger digests and `TigerCore<variant::Tiger2>`
+/// Tiger2 digests.
+///
+/// ```rust
+/// # extern crate tiger;
+/// # fn main() {
+/// use tiger::{Block, TigerCore};
+///
+/// let msg = [0x61u8; 150];
+/// let mut core: TigerCore = TigerCore::new();
+/// core.update_blocks(&[
+///     *Block::from_slice(&msg[..64]),
+///     *Block::from_slice(&msg[64..128]),
+/// ]);
+/// let result = core.finalize(&msg[128..], 150);
+/// assert_eq!(result[..], tiger::hash(&msg[..])[..]);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TigerCore<V: Variant = variant::Tiger> {
+    state: TigerState,
+    length_bytes: u64,
+    _variant: PhantomData<V>,
+}
+
+impl<V: Variant> Default for TigerCore<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Variant> TigerCore<V> {
+    /// Create new hasher instance, which can also be used in constant
+    /// expressions
+    pub const fn new() -> Self {
+        TigerCore {
+            state: TigerState::new(),
+            length_bytes: 0,
+            _variant: PhantomData,
+        }
+    }
+
+    /// Number of message bytes processed so far, always a multiple of 64
+    pub fn processed_bytes(&self) -> u64 {
+        self.length_bytes
+    }
+
+    /// Process complete message blocks
+    ///
+    /// # Panics
+    ///
+    /// If the message length exceeds `MAX_MESSAGE_LEN`.
+    pub fn update_blocks(&mut self, blocks: &[Block]) {
+        self.length_bytes = (blocks.len() as u64).checked_mul(64)
+            .and_then(|n| self.length_bytes.checked_add(n))
+            .filter(|&len| len <= MAX_MESSAGE_LEN)
+            .expect("Tiger message length exceeds 2^64 - 1 bits");
+        self.state.process_blocks(blocks, 3);
+    }
+
+    /// Process the final `tail` of the message and retrieve the digest of
+    /// the whole message of `total_len` bytes
+    ///
+    /// # Panics
+    ///
+    /// If `tail` is 64 bytes or longer, or if `total_len` is not the number
+    /// of bytes passed to `update_blocks` plus the length of `tail`.
+    pub fn finalize(mut self, tail: &[u8], total_len: u64) -> GenericArray<u8, U24> {
+        assert!(tail.len() < 64, "tail must be shorter than a block");
+        assert!(self.length_bytes.checked_add(tail.len() as u64) == Some(total_len)
+            && total_len <= MAX_MESSAGE_LEN,
+            "total length does not match the processed data");
+
+        let mut buf = [0u8; 64];
+        buf[..tail.len()].copy_from_slice(tail);
+        self.state.finalize(&mut buf, tail.len(), V::BYTE, total_len << 3, 3);
+
+        let mut out = GenericArray::default();
+        self.state.write_output(&mut out);
+        out
+    }
+}
+
+impl<V: Variant> core::fmt::Debug for TigerCore<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("TigerCore { ... }")
+    }
+}
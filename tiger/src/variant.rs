@@ -0,0 +1,52 @@
+//! The Tiger variants, which only differ in the padding byte
+//!
+//! `Tiger` appends the byte `0x01` to the message, as the original
+//! submission did, `Tiger2` appends `0x80` like MD4 and its descendants.
+//! The padding byte is followed by zeros and the 64-bit message length.
+//! Buffering, length tracking and compression are shared, so the markers
+//! of this module are only used as type parameters, e.g. of `TigerCore`.
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A Tiger variant, identified by the byte appended to the message
+///
+/// This trait is sealed, it is only implemented by the markers of this
+/// module.
+pub trait Variant: private::Sealed {
+    /// The padding byte
+    const BYTE: u8;
+
+    /// Combine the first `bits` bits of the final partial byte `last` with
+    /// the padding bit
+    fn partial(last: u8, bits: u8) -> u8;
+}
+
+macro_rules! impl_variant {
+    ($name:ident, $pad:expr, |$last:ident, $bits:ident| $partial:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Copy, Clone, Debug, Default)]
+        pub struct $name;
+
+        impl private::Sealed for $name {}
+
+        impl Variant for $name {
+            const BYTE: u8 = $pad;
+
+            fn partial($last: u8, $bits: u8) -> u8 {
+                $partial
+            }
+        }
+    }
+}
+
+// The padding byte marks the first bit after the message, so this crate
+// numbers the bits of a byte starting with the least significant one for
+// Tiger and, as MD4 does, with the most significant one for Tiger2. The
+// Tiger paper only defines byte-oriented padding, this bit order is not
+// taken from published bit-oriented vectors.
+impl_variant!(Tiger, 0x01, |last, bits| (last & ((1 << bits) - 1)) | (1 << bits),
+    "The original Tiger padding with the byte `0x01`");
+impl_variant!(Tiger2, 0x80, |last, bits| (last & !(0xff >> bits)) | (0x80 >> bits),
+    "The Tiger2 padding with the byte `0x80`");
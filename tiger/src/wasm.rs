@@ -0,0 +1,91 @@
+//! JavaScript bindings generated with `wasm-bindgen`, enabled by the `wasm`
+//! feature
+//!
+//! Exported to JavaScript are the `tiger_hex` and `tth_base32` functions
+//! and the streaming `Tiger` and `TigerTree` classes:
+//!
+//! ```js
+//! const hasher = new Tiger();
+//! hasher.update(new TextEncoder().encode("abc"));
+//! console.log(hasher.finalize_hex());
+//! ```
+use std::string::String;
+use std::vec::Vec;
+use digest::{Digest, Input};
+use wasm_bindgen::prelude::*;
+use output::TigerDigest;
+use tth::TigerTree;
+use Tiger;
+
+/// Compute the Tiger digest of `data` as lowercase hex string
+#[wasm_bindgen]
+pub fn tiger_hex(data: &[u8]) -> String {
+    ::hash_hex(data)
+}
+
+/// Compute the Tiger Tree root of `data` as unpadded Base32 string
+#[wasm_bindgen]
+pub fn tth_base32(data: &[u8]) -> String {
+    TigerDigest::from(TigerTree::digest(data)).to_base32()
+}
+
+/// Streaming Tiger hasher, `Tiger` in JavaScript
+#[wasm_bindgen(js_name = Tiger)]
+#[derive(Clone, Debug, Default)]
+pub struct WasmTiger(Tiger);
+
+#[wasm_bindgen(js_class = Tiger)]
+impl WasmTiger {
+    /// Create new hasher instance
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Process `data`
+    pub fn update(&mut self, data: &[u8]) {
+        Input::input(&mut self.0, data);
+    }
+
+    /// Retrieve the digest as bytes, the hasher can not be used afterwards
+    pub fn finalize(self) -> Vec<u8> {
+        self.0.result().to_vec()
+    }
+
+    /// Retrieve the digest as lowercase hex string, the hasher can not be
+    /// used afterwards
+    pub fn finalize_hex(self) -> String {
+        TigerDigest::from(self.0.result()).to_hex()
+    }
+}
+
+/// Streaming Tiger Tree hasher, `TigerTree` in JavaScript
+#[wasm_bindgen(js_name = TigerTree)]
+#[derive(Clone, Debug, Default)]
+pub struct WasmTigerTree(TigerTree);
+
+#[wasm_bindgen(js_class = TigerTree)]
+impl WasmTigerTree {
+    /// Create new hasher instance
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Process `data`
+    pub fn update(&mut self, data: &[u8]) {
+        Input::input(&mut self.0, data);
+    }
+
+    /// Retrieve the tree root as bytes, the hasher can not be used
+    /// afterwards
+    pub fn finalize(self) -> Vec<u8> {
+        self.0.result().to_vec()
+    }
+
+    /// Retrieve the tree root as unpadded Base32 string, the hasher can not
+    /// be used afterwards
+    pub fn finalize_base32(self) -> String {
+        TigerDigest::from(self.0.result()).to_base32()
+    }
+}
@@ -0,0 +1,73 @@
+//! Zeroize support for the Tiger hashers
+//!
+//! The chaining variables, the message length and the buffered bytes are
+//! wiped by `zeroize` and when a hasher is dropped. Note that the resulting
+//! hasher is not usable anymore and has to be reset before reuse.
+//!
+//! `HmacTiger` wraps `Tiger` hashers, so their states are wiped on drop as
+//! well, while the padded key kept by the `hmac` crate is outside of the
+//! scope of this crate. `KeyedTiger` wipes its key as well.
+use zeroize::{Zeroize, ZeroizeOnDrop};
+use digest::generic_array::typenum::Unsigned;
+use {Tiger, Tiger2, Tiger160, Tiger128, TigerP, VarTiger};
+use xof::TigerXofReader;
+#[cfg(feature = "keyed")]
+use KeyedTiger;
+
+macro_rules! impl_zeroize {
+    ($state:ident $(<$p:ident: $bound:ident>)*) => {
+        impl$(<$p: $bound>)* Zeroize for $state$(<$p>)* {
+            fn zeroize(&mut self) {
+                self.engine.wipe();
+            }
+        }
+
+        impl$(<$p: $bound>)* Drop for $state$(<$p>)* {
+            fn drop(&mut self) {
+                self.engine.wipe();
+            }
+        }
+
+        impl$(<$p: $bound>)* ZeroizeOnDrop for $state$(<$p>)* {}
+    }
+}
+
+impl_zeroize!(Tiger);
+impl_zeroize!(Tiger2);
+impl_zeroize!(Tiger160);
+impl_zeroize!(Tiger128);
+impl_zeroize!(TigerP<P: Unsigned>);
+impl_zeroize!(VarTiger);
+
+// `TigerXof` only wraps a `Tiger` hasher, which is wiped on drop, but the
+// reader additionally keeps the current output block
+impl Zeroize for TigerXofReader {
+    fn zeroize(&mut self) {
+        self.wipe();
+    }
+}
+
+impl Drop for TigerXofReader {
+    fn drop(&mut self) {
+        self.wipe();
+    }
+}
+
+impl ZeroizeOnDrop for TigerXofReader {}
+
+#[cfg(feature = "keyed")]
+impl Zeroize for KeyedTiger {
+    fn zeroize(&mut self) {
+        self.wipe();
+    }
+}
+
+#[cfg(feature = "keyed")]
+impl Drop for KeyedTiger {
+    fn drop(&mut self) {
+        self.wipe();
+    }
+}
+
+#[cfg(feature = "keyed")]
+impl ZeroizeOnDrop for KeyedTiger {}
@@ -0,0 +1,75 @@
+use digest::{Input, ExtendableOutput, XofReader, Reset};
+use kdf::CounterKdf;
+use Tiger;
+#[cfg(feature = "std")]
+use std::io;
+
+/// Extendable-output function built from Tiger in counter mode
+///
+/// Output block `i` is computed as `Tiger(message || i)` with `i` encoded as
+/// a big-endian 32-bit integer, so the output stream is identical to the
+/// MGF1 mask generated with `mgf1_tiger` from the absorbed message. At most
+/// `2^32` blocks of 24 bytes can be read, reading more panics.
+#[derive(Clone, Default)]
+pub struct TigerXof {
+    hasher: Tiger,
+}
+
+impl Input for TigerXof {
+    fn input<B: AsRef<[u8]>>(&mut self, input: B) {
+        Input::input(&mut self.hasher, input);
+    }
+}
+
+impl ExtendableOutput for TigerXof {
+    type Reader = TigerXofReader;
+
+    fn xof_result(self) -> TigerXofReader {
+        TigerXofReader::new(self.hasher)
+    }
+}
+
+impl Reset for TigerXof {
+    fn reset(&mut self) {
+        Reset::reset(&mut self.hasher);
+    }
+}
+
+impl_opaque_debug!(TigerXof);
+impl_write!(TigerXof);
+
+/// Reader state for extracting extendable output of `TigerXof`.
+///
+/// Reading more than `2^32` blocks of 24 bytes panics.
+#[derive(Clone)]
+pub struct TigerXofReader {
+    kdf: CounterKdf<'static, Tiger>,
+}
+
+impl TigerXofReader {
+    pub(crate) fn new(hasher: Tiger) -> Self {
+        // the output blocks are exactly the blocks of KDF1 without info
+        TigerXofReader { kdf: CounterKdf::from_hasher(hasher, b"", 0) }
+    }
+
+    #[cfg(feature = "zeroize")]
+    pub(crate) fn wipe(&mut self) {
+        self.kdf.wipe();
+    }
+}
+
+impl XofReader for TigerXofReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        self.kdf.read(buffer);
+    }
+}
+
+impl_opaque_debug!(TigerXofReader);
+
+#[cfg(feature = "std")]
+impl io::Read for TigerXofReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        <Self as XofReader>::read(self, buf);
+        Ok(buf.len())
+    }
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "async")]
+extern crate futures_io;
+extern crate tiger;
+#[cfg(feature = "async-tokio")]
+extern crate tokio;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use futures_io::{AsyncRead, AsyncWrite};
+use tiger::{Digest, AsyncHashingReader, AsyncHashingWriter, Tiger};
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn noop_waker() -> Waker {
+    Waker::from(Arc::new(NoopWaker))
+}
+
+fn ready<T>(poll: Poll<T>) -> T {
+    match poll {
+        Poll::Ready(v) => v,
+        Poll::Pending => panic!("in-memory I/O is never pending"),
+    }
+}
+
+fn msg() -> Vec<u8> {
+    (0..100_000u32).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn async_reader() {
+    let msg = msg();
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut reader = AsyncHashingReader::<_, Tiger>::new(&msg[..]);
+    let mut buf = [0u8; 1000];
+    let mut copy = Vec::new();
+    loop {
+        let n = ready(Pin::new(&mut reader).poll_read(&mut cx, &mut buf)).unwrap();
+        if n == 0 { break; }
+        copy.extend_from_slice(&buf[..n]);
+    }
+    assert_eq!(copy, msg);
+    assert_eq!(reader.finalize(), Tiger::digest(&msg));
+
+    let mut future = tiger::hash_async_reader(&msg[..]);
+    let result = ready(Pin::new(&mut future).poll(&mut cx)).unwrap();
+    assert_eq!(result, tiger::hash(&msg));
+}
+
+#[test]
+fn async_writer() {
+    let msg = msg();
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut writer = AsyncHashingWriter::<_, Tiger>::new(Vec::new());
+    for chunk in msg.chunks(777) {
+        let n = ready(Pin::new(&mut writer).poll_write(&mut cx, chunk)).unwrap();
+        assert_eq!(n, chunk.len());
+    }
+    ready(Pin::new(&mut writer).poll_close(&mut cx)).unwrap();
+    let (written, hasher) = writer.into_parts();
+    assert_eq!(written, msg);
+    assert_eq!(hasher.result(), Tiger::digest(&msg));
+}
+
+#[cfg(feature = "async-tokio")]
+#[test]
+fn tokio_adapters() {
+    use tokio::io::{AsyncRead as TokioRead, AsyncWrite as TokioWrite, ReadBuf};
+
+    let msg = msg();
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut reader = AsyncHashingReader::<_, Tiger>::new(&msg[..]);
+    let mut storage = [0u8; 4096];
+    loop {
+        let mut buf = ReadBuf::new(&mut storage);
+        ready(TokioRead::poll_read(Pin::new(&mut reader), &mut cx, &mut buf))
+            .unwrap();
+        if buf.filled().is_empty() { break; }
+    }
+    assert_eq!(reader.finalize(), Tiger::digest(&msg));
+
+    let mut writer = AsyncHashingWriter::<_, Tiger>::new(Vec::new());
+    ready(TokioWrite::poll_write(Pin::new(&mut writer), &mut cx, &msg)).unwrap();
+    ready(TokioWrite::poll_shutdown(Pin::new(&mut writer), &mut cx)).unwrap();
+    assert_eq!(writer.finalize(), Tiger::digest(&msg));
+}
@@ -0,0 +1,44 @@
+extern crate tiger;
+
+use tiger::{encode_base32, decode_base32, Digest, InvalidBase32, BASE32_LEN};
+use tiger::tth::TigerTree;
+
+const EMPTY_TTH: &str = "LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ";
+
+#[test]
+fn encode_tth_root() {
+    let root = TigerTree::digest(b"");
+    let mut buf = [0u8; BASE32_LEN];
+    assert_eq!(encode_base32(&root, &mut buf), EMPTY_TTH);
+
+    let root = TigerTree::digest(&[b'A'; 1025]);
+    assert_eq!(
+        encode_base32(&root, &mut buf),
+        "PZMRYHGY6LTBEH63ZWAHDORHSYTLO4LEFUIKHWY",
+    );
+}
+
+#[test]
+fn decode_roundtrip() {
+    let root = TigerTree::digest(b"");
+    assert_eq!(decode_base32(EMPTY_TTH), Ok(root));
+    assert_eq!(decode_base32(&EMPTY_TTH.to_lowercase()), Ok(root));
+
+    for i in 0..=255u8 {
+        let digest = tiger::Tiger::digest(&[i]);
+        let mut buf = [0u8; BASE32_LEN];
+        let s = encode_base32(&digest, &mut buf);
+        assert_eq!(decode_base32(s), Ok(digest));
+    }
+}
+
+#[test]
+fn decode_invalid() {
+    // wrong length
+    assert_eq!(decode_base32(&EMPTY_TTH[1..]), Err(InvalidBase32));
+    assert_eq!(decode_base32(&format!("{}=", EMPTY_TTH)), Err(InvalidBase32));
+    // characters outside of the alphabet
+    assert_eq!(decode_base32(&EMPTY_TTH.replace('Q', "1")), Err(InvalidBase32));
+    // non-zero trailing bits
+    assert_eq!(decode_base32(&EMPTY_TTH.replace("NQ", "NR")), Err(InvalidBase32));
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "bitprint")]
+extern crate sha1;
+extern crate tiger;
+
+use sha1::Sha1;
+use tiger::tth::TigerTree;
+use tiger::{encode_base32, Bitprint, Digest, BITPRINT_LEN};
+
+#[test]
+fn bitprint() {
+    let empty = Bitprint::new(b"");
+    assert_eq!(empty.to_string(), "urn:bitprint:3I42H3S6NNFQ2MSVX7XZKYAYSCX5QBYJ.\
+        LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ");
+    assert_eq!(empty.to_string().len(), BITPRINT_LEN);
+
+    let msg: Vec<u8> = (0..150_000u32).map(|i| (i % 239) as u8).collect();
+    let path = std::env::temp_dir().join("tiger-bitprint-test");
+    std::fs::write(&path, &msg).unwrap();
+    let result = tiger::bitprint_file(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    let bitprint = result.unwrap();
+    assert_eq!(bitprint, Bitprint::new(&msg));
+    assert_eq!(*bitprint.sha1(), Sha1::digest(&msg));
+    assert_eq!(*bitprint.tth(), TigerTree::digest(&msg));
+    let mut buf = [0u8; 39];
+    let urn = bitprint.to_string();
+    assert!(urn.ends_with(&format!(".{}", encode_base32(bitprint.tth(), &mut buf))));
+}
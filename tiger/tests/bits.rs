@@ -0,0 +1,100 @@
+#![no_std]
+#[macro_use]
+extern crate hex_literal;
+extern crate tiger;
+
+use tiger::{compress, Block, Digest, Tiger, Tiger2, Tiger128, TigerP};
+use tiger::digest::generic_array::typenum::U4;
+
+const IV: [u64; 3] = [
+    0x0123_4567_89AB_CDEF, 0xFEDC_BA98_7654_3210, 0xF096_A5B4_C3B2_E187,
+];
+
+fn words(digest: &[u8]) -> [u64; 3] {
+    let mut words = [0u64; 3];
+    for (w, chunk) in words.iter_mut().zip(digest.chunks(8)) {
+        for (i, &b) in chunk.iter().enumerate() {
+            *w |= u64::from(b) << (8 * i);
+        }
+    }
+    words
+}
+
+#[test]
+fn whole_bytes() {
+    assert_eq!(Tiger::new().result_bits(0xff, 0), Tiger::digest(b""));
+    assert_eq!(Tiger2::new().chain(b"abc").result_bits(0xff, 0), Tiger2::digest(b"abc"));
+    assert_eq!(Tiger128::new().chain(b"abc").result_bits(0, 0), Tiger128::digest(b"abc"));
+    assert_eq!(TigerP::<U4>::new().result_bits(0, 0), TigerP::<U4>::digest(b""));
+}
+
+#[test]
+fn padding() {
+    // 3 bits 101 after 55 bytes still fit into the same block
+    let msg = [0x5au8; 55];
+    let mut block = Block::default();
+    block[..55].copy_from_slice(&msg);
+    block[55] = 0b1101;
+    block[56..].copy_from_slice(&(55u64 * 8 + 3).to_le_bytes());
+    let mut state = IV;
+    compress(&mut state, &[block]);
+    assert_eq!(words(&Tiger::new().chain(&msg[..]).result_bits(0b0101, 3)), state);
+
+    // 7 bits after 63 bytes, the length goes into a second block
+    let msg = [0xa5u8; 63];
+    let mut blocks = [Block::default(), Block::default()];
+    blocks[0][..63].copy_from_slice(&msg);
+    blocks[0][63] = 0b1011_0111;
+    blocks[1][56..].copy_from_slice(&(63u64 * 8 + 7).to_le_bytes());
+    let mut state = IV;
+    compress(&mut state, &blocks);
+    assert_eq!(words(&Tiger2::new().chain(&msg[..]).result_bits(0b1011_0110, 7)), state);
+}
+
+/// Vectors computed with `tiger_bits` and `tiger2_bits` in
+/// `reference/tiger.c`, which pad bit by bit with the bit order of this
+/// crate, so they check the padding of partial bytes but not the choice of
+/// the bit order, for which no published vectors were available
+#[test]
+fn reference_vectors() {
+    assert_eq!(Tiger::new().result_bits(0, 1)[..],
+        hex!("766afa25b4c4d82c835b8320654c93bdac3ba9671a0c83aa")[..]);
+    assert_eq!(Tiger::new().result_bits(1, 1)[..],
+        hex!("596c890cbd9b8bcdf48fd6478c5328b13f19e39469de2bc8")[..]);
+    assert_eq!(Tiger2::new().result_bits(0, 1)[..],
+        hex!("13b0bd588f0d687bceb645dc8f0f3f3a5d311f5c8b13df52")[..]);
+    assert_eq!(Tiger2::new().result_bits(0x80, 1)[..],
+        hex!("9257cbc59a2290136351d328e552acc61dc3f69cc0734eb2")[..]);
+
+    // the first 23 bits of "abc"
+    assert_eq!(Tiger::new().chain(b"ab").result_bits(b'c', 7)[..],
+        hex!("e920c808eceecf8c2ae79e64031a55e50d0b05492f42f397")[..]);
+    assert_eq!(Tiger2::new().chain(b"ab").result_bits(b'c', 7)[..],
+        hex!("59a905976507280bf08386469ab8ec1290c25d10e5190a6e")[..]);
+
+    // 447 bits still fit before the length field, 449 bits do not
+    let msg = [b'a'; 64];
+    assert_eq!(Tiger::new().chain(&msg[..55]).result_bits(b'a', 7)[..],
+        hex!("2cfc99bf50a4d89dbd6565465a2e2c17f80f32b5e01c702d")[..]);
+    assert_eq!(Tiger::new().chain(&msg[..56]).result_bits(b'a', 1)[..],
+        hex!("0bcd5824b77bf23a185613a22ebb904bd233cc8fee28f06d")[..]);
+    assert_eq!(Tiger2::new().chain(&msg[..63]).result_bits(b'a', 7)[..],
+        hex!("fc1e3b12fbfb64fd8ee42f71b0d9070c593dfc93f8c7096d")[..]);
+}
+
+#[test]
+fn unused_bits_are_ignored() {
+    assert_eq!(Tiger::new().result_bits(0b0000_0101, 3),
+        Tiger::new().result_bits(0b1111_1101, 3));
+    assert_eq!(Tiger2::new().result_bits(0b1010_0000, 3),
+        Tiger2::new().result_bits(0b1011_1111, 3));
+    assert!(Tiger::new().result_bits(0, 1) != Tiger::new().result_bits(1, 1));
+    assert!(Tiger::new().result_bits(0, 1) != Tiger::new().result_bits(0, 2));
+    assert!(Tiger::new().result_bits(0, 1) != Tiger::digest(b""));
+}
+
+#[test]
+#[should_panic]
+fn too_many_bits() {
+    Tiger::new().result_bits(0, 8);
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "capi")]
+extern crate tiger;
+
+use std::mem;
+use std::ptr;
+use tiger::capi::{tiger_final, tiger_hash, tiger_init, tiger_update, TigerCtx};
+
+#[test]
+fn streaming() {
+    let msg: Vec<u8> = (0..1000u32).map(|i| (i * 7) as u8).collect();
+    for &step in [1, 7, 63, 64, 65, 1000].iter() {
+        let mut digest = [0u8; 24];
+        unsafe {
+            let mut ctx: TigerCtx = mem::zeroed();
+            tiger_init(&mut ctx);
+            for chunk in msg.chunks(step) {
+                assert_eq!(tiger_update(&mut ctx, chunk.as_ptr(), chunk.len()), 0);
+            }
+            tiger_update(&mut ctx, ptr::null(), 0);
+            tiger_final(&mut ctx, digest.as_mut_ptr());
+            assert_eq!(digest, tiger::hash(&msg), "step {}", step);
+
+            // the context is re-initialized by tiger_final
+            tiger_update(&mut ctx, b"abc".as_ptr(), 3);
+            tiger_final(&mut ctx, digest.as_mut_ptr());
+        }
+        assert_eq!(digest, tiger::hash(b"abc"));
+    }
+}
+
+#[test]
+fn one_shot() {
+    let mut digest = [0u8; 24];
+    unsafe {
+        assert_eq!(tiger_hash(ptr::null(), 0, digest.as_mut_ptr()), 0);
+        assert_eq!(digest, tiger::hash(b""));
+        tiger_hash(b"abc".as_ptr(), 3, digest.as_mut_ptr());
+        assert_eq!(digest, tiger::hash(b"abc"));
+        // null pointers are ignored
+        tiger_init(ptr::null_mut());
+        tiger_final(ptr::null_mut(), digest.as_mut_ptr());
+        tiger_hash(b"abc".as_ptr(), 3, ptr::null_mut());
+    }
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn too_long() {
+    // the length is checked before the data is read
+    let len = tiger::MAX_MESSAGE_LEN as usize + 1;
+    let mut digest = [0u8; 24];
+    unsafe {
+        let mut ctx: TigerCtx = mem::zeroed();
+        tiger_init(&mut ctx);
+        assert_eq!(tiger_update(&mut ctx, b"abc".as_ptr(), 3), 0);
+        assert_eq!(tiger_update(&mut ctx, b"abc".as_ptr(), len - 2), -1);
+        assert_eq!(tiger_hash(b"abc".as_ptr(), len, digest.as_mut_ptr()), -1);
+        assert_eq!(digest, [0; 24]);
+        tiger_final(&mut ctx, digest.as_mut_ptr());
+    }
+    assert_eq!(digest, tiger::hash(b"abc"));
+}
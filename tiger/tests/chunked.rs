@@ -0,0 +1,61 @@
+#![cfg(feature = "std")]
+extern crate tiger;
+
+use tiger::{ChunkedHasher, Digest, Tiger, Tiger2, DEFAULT_CHUNK_SIZE};
+use tiger::digest::{Input, Reset};
+
+fn concat_digest<D: Digest>(chunks: &[&[u8]]) -> Vec<u8> {
+    let mut hasher = D::new();
+    for chunk in chunks {
+        hasher.input(D::digest(chunk));
+    }
+    hasher.result().to_vec()
+}
+
+#[test]
+fn chunks() {
+    let msg: Vec<u8> = (0..2500u32).map(|i| i as u8).collect();
+    let parts: Vec<&[u8]> = msg.chunks(1000).collect();
+
+    let mut hasher = ChunkedHasher::<Tiger>::new(1000);
+    for piece in msg.chunks(333) {
+        hasher.input(piece);
+    }
+    let result = hasher.finalize();
+    assert_eq!(result.chunk_size(), 1000);
+    assert_eq!(result.chunks().len(), 3);
+    for (i, part) in parts.iter().enumerate() {
+        assert_eq!(*result.chunk(i).unwrap(), Tiger::digest(part));
+        assert!(result.verify_chunk(i, part));
+    }
+    assert!(result.chunk(3).is_none());
+    assert!(!result.verify_chunk(0, parts[1]));
+    assert_eq!(result.root()[..], concat_digest::<Tiger>(&parts)[..]);
+
+    // Exact multiple of the chunk size does not add an empty chunk
+    let mut hasher = ChunkedHasher::<Tiger2>::new(1250);
+    hasher.input(&msg);
+    let result = hasher.finalize();
+    assert_eq!(result.chunks().len(), 2);
+    let parts: Vec<&[u8]> = msg.chunks(1250).collect();
+    assert_eq!(result.root()[..], concat_digest::<Tiger2>(&parts)[..]);
+}
+
+#[test]
+fn empty_and_reset() {
+    let mut hasher = ChunkedHasher::<Tiger>::default();
+    assert_eq!(hasher.chunk_size(), DEFAULT_CHUNK_SIZE);
+    let empty = hasher.clone().finalize();
+    assert_eq!(empty.chunks().len(), 1);
+    assert_eq!(empty.root()[..], concat_digest::<Tiger>(&[b""])[..]);
+
+    hasher.input(b"abc");
+    hasher.reset();
+    assert_eq!(hasher.finalize(), empty);
+}
+
+#[test]
+#[should_panic]
+fn zero_chunk_size() {
+    ChunkedHasher::<Tiger>::new(0);
+}
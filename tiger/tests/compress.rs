@@ -0,0 +1,113 @@
+#![no_std]
+extern crate tiger;
+
+use tiger::{compress, variant, Block, Digest, Tiger, TigerCore};
+
+const IV: [u64; 3] = [
+    0x0123_4567_89AB_CDEF, 0xFEDC_BA98_7654_3210, 0xF096_A5B4_C3B2_E187,
+];
+
+fn digest_words(digest: &[u8]) -> [u64; 3] {
+    let mut words = [0u64; 3];
+    for (w, chunk) in words.iter_mut().zip(digest.chunks(8)) {
+        for (i, &b) in chunk.iter().enumerate() {
+            *w |= u64::from(b) << (8 * i);
+        }
+    }
+    words
+}
+
+#[test]
+fn compress_padded_message() {
+    // "abc" padded into a single block
+    let mut block = Block::default();
+    block[..3].copy_from_slice(b"abc");
+    block[3] = 0x01;
+    block[56] = 24;
+
+    let mut state = IV;
+    compress(&mut state, &[block]);
+    assert_eq!(state, digest_words(&Tiger::digest(b"abc")));
+}
+
+#[test]
+fn compress_multiple_blocks() {
+    // 64 zero bytes followed by the padding block
+    let mut blocks = [Block::default(), Block::default()];
+    blocks[1][0] = 0x01;
+    blocks[1][57] = 2;
+
+    let mut state = IV;
+    compress(&mut state, &blocks);
+    assert_eq!(state, digest_words(&Tiger::digest(&[0u8; 64][..])));
+
+    let mut state2 = IV;
+    compress(&mut state2, &blocks[..1]);
+    compress(&mut state2, &blocks[1..]);
+    assert_eq!(state, state2);
+}
+
+#[test]
+fn midstate() {
+    let mut msg = [0u8; 200];
+    for (i, b) in msg.iter_mut().enumerate() {
+        *b = (i * 7) as u8;
+    }
+    // precompute the state after a two block prefix
+    let mut blocks = [Block::default(), Block::default()];
+    blocks[0].copy_from_slice(&msg[..64]);
+    blocks[1].copy_from_slice(&msg[64..128]);
+    let mut state = IV;
+    compress(&mut state, &blocks);
+
+    for &end in [128, 129, 150, 191, 192, 200].iter() {
+        let mut hasher = Tiger::from_midstate(state, 128);
+        hasher.input(&msg[128..end]);
+        assert_eq!(hasher.result(), Tiger::digest(&msg[..end]));
+    }
+
+    let mut hasher = tiger::Tiger2::from_midstate(IV, 0);
+    hasher.input(b"abc");
+    assert_eq!(hasher.result(), tiger::Tiger2::digest(b"abc"));
+}
+
+#[test]
+#[should_panic]
+fn midstate_partial_block() {
+    Tiger::from_midstate(IV, 100);
+}
+
+#[test]
+fn unbuffered_core() {
+    let mut msg = [0u8; 200];
+    for (i, b) in msg.iter_mut().enumerate() {
+        *b = (i * 7) as u8;
+    }
+    // tails below, at and above the 56 byte padding limit
+    for &end in [0, 3, 55, 56, 63, 64, 119, 128, 200].iter() {
+        let full = end / 64 * 64;
+        let blocks: [Block; 3] = [
+            *Block::from_slice(&msg[..64]),
+            *Block::from_slice(&msg[64..128]),
+            *Block::from_slice(&msg[128..192]),
+        ];
+        let mut core: TigerCore = TigerCore::new();
+        core.update_blocks(&blocks[..full / 64]);
+        assert_eq!(core.processed_bytes(), full as u64);
+        assert_eq!(core.finalize(&msg[full..end], end as u64),
+            Tiger::digest(&msg[..end]));
+
+        let mut core = TigerCore::<variant::Tiger2>::new();
+        core.update_blocks(&blocks[..full / 64]);
+        assert_eq!(core.finalize(&msg[full..end], end as u64),
+            tiger::Tiger2::digest(&msg[..end]));
+    }
+}
+
+#[test]
+#[should_panic]
+fn unbuffered_core_wrong_length() {
+    let mut core: TigerCore = TigerCore::new();
+    core.update_blocks(&[Block::default()]);
+    core.finalize(b"abc", 3);
+}
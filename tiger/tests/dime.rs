@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+extern crate tiger;
+
+use std::io;
+use tiger::dime::{HashTree, InvalidThex};
+use tiger::tth::{self, TigerTree, TigerTreeLevel};
+use tiger::Digest;
+
+const URI: &str = "uuid:09233523-345b-4351-b623-5dfa35c95d61";
+
+fn hash_tree(data: &[u8]) -> HashTree {
+    let mut tree = TigerTreeLevel::new(0);
+    io::copy(&mut &data[..], &mut tree).unwrap();
+    HashTree::new(&tree.finalize(), data.len() as u64)
+}
+
+fn dime(tree: &HashTree) -> Vec<u8> {
+    let mut out = Vec::new();
+    tree.write_dime(URI, &mut out).unwrap();
+    out
+}
+
+#[test]
+fn structure() {
+    let data: Vec<u8> = (0..5000u32).map(|i| i as u8).collect();
+    let tree = hash_tree(&data);
+    assert_eq!(*tree.root(), TigerTree::digest(&data));
+    assert_eq!(tree.file_size(), 5000);
+    assert_eq!(tree.depth(), 4);
+    let sizes: Vec<usize> = (0..4).map(|i| tree.level(i).unwrap().len()).collect();
+    assert_eq!(sizes, [1, 2, 3, 5]);
+    assert_eq!(tree.level(3).unwrap()[4], tth::leaf_hash(&data[4096..]));
+    // the unpaired fifth leaf is promoted to the next level
+    assert_eq!(tree.level(2).unwrap()[2], tree.level(3).unwrap()[4]);
+    assert!(tree.level(4).is_none());
+
+    let empty = hash_tree(b"");
+    assert_eq!(empty.depth(), 1);
+    assert_eq!(*empty.root(), TigerTree::digest(b""));
+}
+
+#[test]
+fn round_trip() {
+    let data: Vec<u8> = (0..70_000u32).map(|i| (i % 251) as u8).collect();
+    let mut tree = hash_tree(&data);
+    let out = dime(&tree);
+    // two records, all fields padded to four bytes
+    assert_eq!(out[0], 0x0c);
+    assert_eq!(out.len() % 4, 0);
+    let xml_len = u32::from_be_bytes([out[8], out[9], out[10], out[11]]) as usize;
+    let xml = std::str::from_utf8(&out[20..20 + xml_len]).unwrap();
+    assert!(xml.contains("<file size='70000' segmentsize='1024'/>"));
+    assert!(xml.contains("depth='8'"));
+    assert_eq!(HashTree::parse_dime(&out), Ok(tree.clone()));
+
+    tree.truncate(3);
+    assert_eq!(tree.depth(), 3);
+    assert_eq!(HashTree::parse_dime(&dime(&tree)), Ok(tree.clone()));
+    tree.truncate(0);
+    assert_eq!(tree.depth(), 1);
+    assert_eq!(HashTree::parse_dime(&dime(&tree)), Ok(tree));
+
+    let empty = hash_tree(b"");
+    assert_eq!(HashTree::parse_dime(&dime(&empty)), Ok(empty));
+}
+
+#[test]
+fn invalid() {
+    let data = vec![0x55; 3000];
+    let tree = hash_tree(&data);
+    let out = dime(&tree);
+
+    assert_eq!(HashTree::parse_dime(&out[..out.len() - 4]), Err(InvalidThex));
+    assert_eq!(HashTree::parse_dime(b""), Err(InvalidThex));
+    let mut tampered = out.clone();
+    let last = tampered.len() - 1;
+    tampered[last] ^= 1;
+    assert_eq!(HashTree::parse_dime(&tampered), Err(InvalidThex));
+    let mut tampered = out.clone();
+    tampered[0] = 0x1c;
+    assert_eq!(HashTree::parse_dime(&tampered), Err(InvalidThex));
+
+    let mut sink = Vec::new();
+    assert!(tree.write_dime("uuid:'", &mut sink).is_err());
+    assert!(tree.write_dime("", &mut sink).is_err());
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "hasher")]
+extern crate tiger;
+
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hash, Hasher};
+use tiger::{Digest, Tiger, TigerHasher};
+
+#[derive(Hash)]
+struct File {
+    name: &'static str,
+    size: u32,
+}
+
+#[test]
+fn finish() {
+    let mut hasher = TigerHasher::new();
+    hasher.write(b"ab");
+    hasher.write(b"c");
+    let digest = Tiger::digest(b"abc");
+    let mut expected = 0u64;
+    for (i, &b) in digest[..8].iter().enumerate() {
+        expected |= u64::from(b) << (8 * i);
+    }
+    assert_eq!(hasher.finish(), expected);
+    // `finish` does not reset the hasher
+    assert_eq!(hasher.finish(), expected);
+}
+
+#[test]
+fn derived_hash() {
+    let id = |file: &File| {
+        let mut hasher = TigerHasher::default();
+        file.hash(&mut hasher);
+        hasher.finish()
+    };
+    let a = File { name: "a.txt", size: 1 };
+    let b = File { name: "a.txt", size: 2 };
+    assert_eq!(id(&a), id(&File { name: "a.txt", size: 1 }));
+    assert_ne!(id(&a), id(&b));
+
+    let mut map: HashMap<_, _, BuildHasherDefault<TigerHasher>> =
+        HashMap::default();
+    map.insert("a", 1);
+    map.insert("b", 2);
+    assert_eq!(map["a"], 1);
+}
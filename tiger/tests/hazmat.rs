@@ -0,0 +1,56 @@
+//! Hashers with non-standard initial values
+#![cfg(feature = "hazmat")]
+extern crate tiger;
+
+use tiger::digest::generic_array::typenum::U4;
+use tiger::{compress, Block, Digest, Tiger, Tiger2, TigerP};
+
+const IV: [u64; 3] = [
+    0x0123_4567_89AB_CDEF, 0xFEDC_BA98_7654_3210, 0xF096_A5B4_C3B2_E187,
+];
+
+#[test]
+fn standard_iv() {
+    let msg = b"The quick brown fox jumps over the lazy dog";
+    let hasher = Tiger::with_iv(IV[0], IV[1], IV[2]).chain(&msg[..]);
+    assert_eq!(hasher.finalize(), Tiger::digest(msg));
+    let hasher = Tiger2::with_iv(IV[0], IV[1], IV[2]).chain(&msg[..]);
+    assert_eq!(hasher.finalize(), Tiger2::digest(msg));
+    let hasher = TigerP::<U4>::with_iv(IV[0], IV[1], IV[2]).chain(&msg[..]);
+    assert_eq!(hasher.result(), TigerP::<U4>::digest(msg));
+}
+
+#[test]
+fn custom_iv() {
+    let iv = [1, 2, 3];
+    let msg = [0x61u8; 100];
+
+    // the same as a midstate, except that no block has been processed
+    let expected = Tiger::from_midstate(iv, 0).chain(&msg[..]).finalize();
+    let out = Tiger::with_iv(iv[0], iv[1], iv[2]).chain(&msg[..]).finalize();
+    assert_eq!(out, expected);
+    assert_ne!(out, Tiger::digest(&msg[..]));
+
+    // one full block followed by the padded tail
+    let mut state = iv;
+    compress(&mut state, &[*Block::from_slice(&msg[..64])]);
+    let mut tail = [0u8; 64];
+    tail[..36].copy_from_slice(&msg[64..]);
+    tail[36] = 0x01;
+    tail[56..].copy_from_slice(&800u64.to_le_bytes());
+    compress(&mut state, &[*Block::from_slice(&tail)]);
+    let mut words = [0u8; 24];
+    for (chunk, w) in words.chunks_mut(8).zip(state.iter()) {
+        chunk.copy_from_slice(&w.to_le_bytes());
+    }
+    assert_eq!(out[..], words[..]);
+}
+
+#[test]
+fn reset_restores_standard_iv() {
+    let mut hasher = Tiger::with_iv(1, 2, 3);
+    hasher.input(b"abc");
+    assert_ne!(hasher.finalize_reset(), Tiger::digest(b"abc"));
+    hasher.input(b"abc");
+    assert_eq!(hasher.finalize(), Tiger::digest(b"abc"));
+}
@@ -0,0 +1,77 @@
+//! HKDF-Tiger test vectors for the RFC 5869 test case inputs
+#![cfg(feature = "hkdf")]
+#![no_std]
+#[macro_use]
+extern crate hex_literal;
+extern crate tiger;
+
+use tiger::{hkdf_tiger, hkdf_tiger_extract, hkdf_tiger_expand};
+
+fn check(salt: &[u8], ikm: &[u8], info: &[u8], prk: &[u8], okm: &[u8]) {
+    assert_eq!(hkdf_tiger_extract(salt, ikm)[..], prk[..]);
+
+    let mut out = [0u8; 128];
+    let out = &mut out[..okm.len()];
+    hkdf_tiger_expand(prk, info, out).unwrap();
+    assert_eq!(out, okm);
+
+    let mut out = [0u8; 128];
+    let out = &mut out[..okm.len()];
+    hkdf_tiger(salt, ikm, info, out).unwrap();
+    assert_eq!(out, okm);
+}
+
+#[test]
+fn hkdf_basic() {
+    check(
+        &hex!("000102030405060708090a0b0c"),
+        &hex!("0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b"),
+        &hex!("f0f1f2f3f4f5f6f7f8f9"),
+        &hex!("65853a7e9ab08bc47d369206b11cbde167306d51fd41cb1d"),
+        &hex!("
+            2eb6b10145bcc3e76638c18eba97cd2681bad3c46ed3e1b97ec1f26cf806a36d
+            44bdf0cd77ca39b5fd9b
+        "),
+    );
+}
+
+#[test]
+fn hkdf_long_inputs() {
+    let mut salt = [0u8; 80];
+    let mut ikm = [0u8; 80];
+    let mut info = [0u8; 80];
+    for i in 0..80 {
+        ikm[i] = i as u8;
+        salt[i] = 0x60 + i as u8;
+        info[i] = 0xb0 + i as u8;
+    }
+    check(&salt, &ikm, &info,
+        &hex!("5ad4a5b07ec958699224acf0c64e3335d641a20cf8830dfd"),
+        &hex!("
+            91c480697903782202b39676ff0dbfa6f5c067667ff9d237c15f2ae917499bfb
+            1fc03aaf682f9129ee77f2ff8a9107678adbc3498c0344783934c9d71fa8bb38
+            7b624ec54795bba5f46af2b2e794eb0e6b61
+        "),
+    );
+}
+
+#[test]
+fn hkdf_empty_salt_and_info() {
+    check(
+        b"",
+        &hex!("0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b"),
+        b"",
+        &hex!("ae2f24d92540f25cfe7f4efcebf17dfb50e57714b63d8b34"),
+        &hex!("
+            171bc8f49a21ddd7521e420c976485d7f444763176ba0bf83fbf15b6380e2bee
+            404c507e974e21d320a6
+        "),
+    );
+}
+
+#[test]
+fn hkdf_output_length() {
+    let mut okm = [0u8; 255 * 24 + 1];
+    assert!(hkdf_tiger(b"", b"", b"", &mut okm).is_err());
+    assert!(hkdf_tiger(b"", b"", b"", &mut okm[..255 * 24]).is_ok());
+}
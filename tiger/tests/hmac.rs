@@ -0,0 +1,8 @@
+//! HMAC-Tiger test vectors for the RFC 2202 test cases
+#![cfg(feature = "hmac")]
+#![no_std]
+#[macro_use]
+extern crate crypto_mac;
+extern crate tiger;
+
+new_test!(hmac_tiger, "hmac_tiger", tiger::HmacTiger);
@@ -0,0 +1,130 @@
+#![cfg(feature = "std")]
+extern crate tiger;
+
+use std::io::{self, Read, Write};
+use tiger::{Digest, HashingReader, HashingWriter, Tiger, Tiger2};
+
+/// Reader returning at most 7 bytes per call
+struct Trickle<'a>(&'a [u8]);
+
+impl<'a> Read for Trickle<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = buf.len().min(7);
+        self.0.read(&mut buf[..n])
+    }
+}
+
+/// Writer accepting at most 7 bytes per call
+#[derive(Clone)]
+struct ShortWrites(Vec<u8>);
+
+impl Write for ShortWrites {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = buf.len().min(7);
+        self.0.write(&buf[..n])
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn hashing_reader() {
+    let msg: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+    let mut reader = HashingReader::<_, Tiger>::new(Trickle(&msg));
+    let mut copy = Vec::new();
+    io::copy(&mut reader, &mut copy).unwrap();
+    assert_eq!(copy, msg);
+    assert_eq!(reader.finalize(), Tiger::digest(&msg));
+
+    let mut prefix = Tiger2::new();
+    prefix.input(b"abc");
+    let mut reader = HashingReader::with_hasher(&b"def"[..], prefix);
+    let mut buf = [0u8; 2];
+    reader.read_exact(&mut buf).unwrap();
+    let (rest, hasher) = reader.into_parts();
+    assert_eq!(rest, b"f");
+    assert_eq!(hasher.result(), Tiger2::digest(b"abcde"));
+}
+
+#[test]
+fn hashing_writer() {
+    let msg: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+    let mut writer = HashingWriter::<_, Tiger>::new(ShortWrites(Vec::new()));
+    io::copy(&mut &msg[..], &mut writer).unwrap();
+    writer.flush().unwrap();
+    assert_eq!(writer.get_ref().0, msg);
+    assert_eq!(writer.clone().into_inner().0, msg);
+    assert_eq!(writer.finalize(), Tiger::digest(&msg));
+
+    let mut prefix = Tiger2::new();
+    prefix.input(b"abc");
+    let mut buf = [0u8; 2];
+    let mut writer = HashingWriter::with_hasher(&mut buf[..], prefix);
+    assert_eq!(writer.write(b"def").unwrap(), 2);
+    let (_, hasher) = writer.into_parts();
+    assert_eq!(hasher.result(), Tiger2::digest(b"abcde"));
+    assert_eq!(&buf, b"de");
+}
+
+#[test]
+fn hash_reader_and_file() {
+    let msg: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+    let expected = tiger::hash(&msg);
+    assert_eq!(tiger::hash_reader(Trickle(&msg[..1000])).unwrap(),
+        tiger::hash(&msg[..1000]));
+    assert_eq!(tiger::hash_reader(&msg[..]).unwrap(), expected);
+
+    let path = std::env::temp_dir().join("tiger-hash-file-test");
+    std::fs::write(&path, &msg).unwrap();
+    let result = tiger::hash_file(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(result.unwrap(), expected);
+    assert!(tiger::hash_file(&path).is_err());
+}
+
+#[test]
+fn progress() {
+    let msg: Vec<u8> = (0..300_000u32).map(|i| (i % 241) as u8).collect();
+    let mut reports = Vec::new();
+    let sum = tiger::hash_reader_with_progress(&msg[..], 100_000, |n| reports.push(n))
+        .unwrap();
+    assert_eq!(sum, tiger::hash(&msg));
+    assert_eq!(reports, [131_072, 262_144, 300_000]);
+
+    let mut reports = Vec::new();
+    let sum = tiger::hash_reader_with_progress(Trickle(&msg[..20]), 0, |n| reports.push(n))
+        .unwrap();
+    assert_eq!(sum, tiger::hash(&msg[..20]));
+    assert_eq!(reports, [7, 14, 20]);
+
+    let mut reports = Vec::new();
+    tiger::hash_reader_with_progress(&b""[..], 10, |n| reports.push(n)).unwrap();
+    assert_eq!(reports, [0]);
+
+    let path = std::env::temp_dir().join("tiger-progress-test");
+    std::fs::write(&path, &msg).unwrap();
+    let mut last = 0;
+    let root = tiger::tth::hash_file_with_progress(&path, 1 << 16, |n| last = n);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(root.unwrap(), tiger::tth::TigerTree::digest(&msg));
+    assert_eq!(last, 300_000);
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn hash_file_mmap() {
+    let msg: Vec<u8> = (0..100_000u32).map(|i| (i % 253) as u8).collect();
+    let dir = std::env::temp_dir();
+    let path = dir.join("tiger-hash-file-mmap-test");
+    let empty = dir.join("tiger-hash-file-mmap-empty");
+    std::fs::write(&path, &msg).unwrap();
+    std::fs::write(&empty, b"").unwrap();
+    let result = tiger::hash_file_mmap(&path);
+    let result_empty = tiger::hash_file_mmap(&empty);
+    std::fs::remove_file(&path).unwrap();
+    std::fs::remove_file(&empty).unwrap();
+    assert_eq!(result.unwrap(), tiger::hash(&msg));
+    assert_eq!(result_empty.unwrap(), tiger::hash(b""));
+}
@@ -0,0 +1,81 @@
+//! KDF1 and KDF2 vectors computed with the C reference implementation of
+//! Tiger
+#![no_std]
+#[macro_use]
+extern crate hex_literal;
+extern crate sha1;
+extern crate tiger;
+
+use sha1::Sha1;
+use tiger::digest::XofReader;
+use tiger::{kdf1_tiger, kdf2_tiger, mgf1_tiger, CounterKdf, Digest, Tiger};
+
+fn secret() -> [u8; 24] {
+    let mut z = [0u8; 24];
+    for (i, b) in z.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    z
+}
+
+#[test]
+fn kdf1_vectors() {
+    let mut out = [0u8; 50];
+    kdf1_tiger(b"foo", b"", &mut out);
+    let mut mask = [0u8; 50];
+    mgf1_tiger(b"foo", &mut mask);
+    assert_eq!(out[..], mask[..]);
+
+    let mut out = [0u8; 64];
+    kdf1_tiger(&secret(), b"ECIES", &mut out);
+    assert_eq!(out[..], hex!("
+        00821819e239ea2d81c69660efd844c728dd64a49a1c8c0a
+        a7f45125021b487b24c0ba242e47bd01933b5e577f4353b6
+        a6881bedde9feb5082182dc72ed887b3
+    ")[..]);
+}
+
+#[test]
+fn kdf2_vectors() {
+    let mut out = [0u8; 50];
+    kdf2_tiger(b"foo", b"", &mut out);
+    assert_eq!(out[..], hex!("
+        6fd655351db55805581fb9d60d373f3b5df545e0bcb50cd4
+        6f70953485669a9e39cea1c588dbf2847321ba3f8e393873
+        df66
+    ")[..]);
+
+    let mut out = [0u8; 64];
+    kdf2_tiger(&secret(), b"ECIES", &mut out);
+    assert_eq!(out[..], hex!("
+        a7f45125021b487b24c0ba242e47bd01933b5e577f4353b6
+        a6881bedde9feb5082182dc72ed887b3270397c9838b76cf
+        ef611b35b925a6fa1e3f8395996c307c
+    ")[..]);
+}
+
+#[test]
+fn streaming() {
+    let mut expected = [0u8; 100];
+    kdf2_tiger(b"secret", b"info", &mut expected);
+    for &step in [1, 5, 23, 24, 25, 100].iter() {
+        let mut kdf = CounterKdf::<Tiger>::kdf2(b"secret", b"info");
+        let mut out = [0u8; 100];
+        for chunk in out.chunks_mut(step) {
+            kdf.read(chunk);
+        }
+        assert_eq!(out[..], expected[..], "step {}", step);
+    }
+}
+
+#[test]
+fn other_digest() {
+    // the first KDF2 block is H(secret || 00000001 || info)
+    let mut out = [0u8; 20];
+    CounterKdf::<Sha1>::kdf2(b"secret", b"info").read(&mut out);
+    let mut hasher = Sha1::new();
+    hasher.input(b"secret");
+    hasher.input([0, 0, 0, 1]);
+    hasher.input(b"info");
+    assert_eq!(out[..], hasher.result()[..]);
+}
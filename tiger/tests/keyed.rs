@@ -0,0 +1,61 @@
+//! Envelope MAC vectors computed with the C reference implementation of
+//! Tiger over `pad(K) || M || K` as laid out by RFC 1828
+//!
+//! These vectors were not produced by a legacy envelope MAC implementation,
+//! so they do not establish compatibility with one.
+#![cfg(feature = "keyed")]
+#[macro_use]
+extern crate hex_literal;
+extern crate tiger;
+
+use tiger::KeyedTiger;
+use tiger::crypto_mac::{InvalidKeyLength, Mac};
+use tiger::digest::generic_array::GenericArray;
+
+fn mac(key: &[u8], msg: &[u8]) -> [u8; 24] {
+    let mut mac = KeyedTiger::new_varkey(key).unwrap();
+    mac.input(msg);
+    let mut out = [0u8; 24];
+    out.copy_from_slice(&mac.result().code());
+    out
+}
+
+#[test]
+fn vectors() {
+    // the inputs of the RFC 2202 HMAC test cases which fit the key limit
+    assert_eq!(mac(&[0x0b; 20], b"Hi There"),
+        hex!("6b5c57e8ed5dd7378e359db14c7708b42272bc2d1a939a05"));
+    assert_eq!(mac(b"Jefe", b"what do ya want for nothing?"),
+        hex!("dd12bc65b75abe7dd95841b127ca99eadd56fcb840e01349"));
+    assert_eq!(mac(&[0xaa; 20], &[0xdd; 50]),
+        hex!("d2bd9101955436d544d84bddefaa1610585ff9ef9713e394"));
+    let key: Vec<u8> = (1..26).collect();
+    assert_eq!(mac(&key, &[0xcd; 50]),
+        hex!("c2b38cc4c6fec9639c7b4103f11289b2dac12da25bb56846"));
+    assert_eq!(mac(&[0x0c; 20], b"Test With Truncation"),
+        hex!("1c718b5abfa4cccd785b7375da4620350e670cc49cb50d06"));
+    assert_eq!(mac(&[0xaa; 64], b"Test Using Larger Than Block-Size Key - Hash Key First"),
+        hex!("38f38576597b143b4db877b6964045cae4a7c309d88ac7b2"));
+    assert_eq!(mac(b"", b""),
+        hex!("60224f0b05145d00fb3c09e78522fe17fb7c64ac90af4be0"));
+    // the padded key takes two blocks
+    assert_eq!(mac(&[0x5a; 56], b"abc"),
+        hex!("8cf8c758052e6d3ab27d8a4a3fc23b4f5f288e273d4470ec"));
+}
+
+#[test]
+fn api() {
+    assert!(KeyedTiger::new_varkey(&[0; 65]).err() == Some(InvalidKeyLength));
+
+    let key = GenericArray::clone_from_slice(&[0x0b; 24]);
+    let mut fixed = KeyedTiger::new(&key);
+    fixed.input(b"Hi ");
+    fixed.input(b"There");
+    let code = fixed.result_reset().code();
+    assert_eq!(code[..], mac(&[0x0b; 24], b"Hi There")[..]);
+
+    fixed.input(b"Hi There");
+    assert!(fixed.clone().verify(&code).is_ok());
+    fixed.input(b"!");
+    assert!(fixed.verify(&code).is_err());
+}
@@ -0,0 +1,183 @@
+#![no_std]
+#[macro_use]
+extern crate digest;
+#[macro_use]
+extern crate hex_literal;
+extern crate sha1;
+extern crate tiger;
+
+use digest::dev::{one_million_a, digest_test, variable_test};
+use digest::generic_array::typenum::{U4, U5};
+
+new_test!(tiger_main, "tiger", tiger::Tiger, digest_test);
+new_test!(tiger2_main, "tiger2", tiger::Tiger2, digest_test);
+new_test!(tiger160_main, "tiger160", tiger::Tiger160, digest_test);
+new_test!(tiger128_main, "tiger128", tiger::Tiger128, digest_test);
+new_test!(tiger_variable, "tiger_variable", tiger::VarTiger, variable_test);
+new_test!(tiger_4passes_main, "tiger_4passes", tiger::TigerP<U4>, digest_test);
+new_test!(tth_main, "tth", tiger::tth::TigerTree, digest_test);
+new_test!(thex_sha1_main, "thex_sha1", tiger::thex::Thex<sha1::Sha1>, digest_test);
+
+// NESSIE sets 1 to 3 for byte-oriented implementations: sample messages,
+// strings of 0 to 127 zero bytes and 512-bit strings with a single bit set
+new_test!(tiger_nessie_set1, "nessie_set1", tiger::Tiger, digest_test);
+new_test!(tiger_nessie_set2, "nessie_set2", tiger::Tiger, digest_test);
+new_test!(tiger_nessie_set3, "nessie_set3", tiger::Tiger, digest_test);
+// 64 KiB string with byte `i` equal to `i mod 256` from the reference test
+new_test!(tiger_64k, "tiger_64k", tiger::Tiger, digest_test);
+
+#[test]
+fn tiger_1million_a() {
+    let output = include_bytes!("data/tiger_one_million_a.bin");
+    one_million_a::<tiger::Tiger>(output);
+}
+
+#[test]
+fn tiger2_1million_a() {
+    let output = include_bytes!("data/tiger2_one_million_a.bin");
+    one_million_a::<tiger::Tiger2>(output);
+}
+
+/// The bit-oriented part of NESSIE set 2, strings of 0 to 1023 zero bits
+/// whose length is not a multiple of 8
+///
+/// The published digests for these lengths were not available, the
+/// expected values were computed with `tiger_bits` of `reference/tiger.c`,
+/// so this is a regression test of the bit-level API and not a check
+/// against NESSIE. Set 3 consists of 512-bit strings only and is included
+/// completely above.
+#[test]
+fn tiger_nessie_set2_bits() {
+    let expected = include_bytes!("data/nessie_set2_bits.bin");
+    assert_eq!(expected.len(), 24 * (1024 - 128));
+    let zeros = [0u8; 128];
+    let lengths = (1..1024usize).filter(|n| n % 8 != 0);
+    for (bits, expected) in lengths.zip(expected.chunks(24)) {
+        let hasher = tiger::Tiger::new().chain(&zeros[..bits / 8]);
+        assert_eq!(hasher.result_bits(0, (bits % 8) as u8)[..], expected[..],
+            "{} zero bits", bits);
+    }
+}
+
+/// NESSIE set 4: 192 zero bits hashed iteratively 100000 times
+#[test]
+fn tiger_nessie_set4() {
+    use digest::Digest;
+
+    let expected = include_bytes!("data/nessie_set4.bin");
+    let mut digest = tiger::Tiger::digest(&[0u8; 24]);
+    for _ in 1..100_000 {
+        digest = tiger::Tiger::digest(&digest);
+    }
+    assert_eq!(digest[..], expected[..]);
+}
+
+#[test]
+fn tiger_variable_output_size() {
+    use digest::VariableOutput;
+
+    assert!(tiger::VarTiger::new(0).is_err());
+    assert!(tiger::VarTiger::new(25).is_err());
+    assert_eq!(tiger::VarTiger::new(20).unwrap().output_size(), 20);
+}
+
+// hashers can be constructed in constant expressions
+static TIGER: tiger::Tiger = tiger::Tiger::new();
+static TIGER_4PASSES: tiger::TigerP<U4> = tiger::TigerP::new();
+
+#[test]
+fn const_new() {
+    use digest::Digest;
+
+    let mut hasher = TIGER.clone();
+    hasher.input(b"abc");
+    assert_eq!(hasher.result(), tiger::Tiger::digest(b"abc"));
+
+    let hasher = TIGER_4PASSES.clone();
+    assert_eq!(hasher.result(), tiger::TigerP::<U4>::digest(b""));
+}
+
+#[test]
+fn one_shot() {
+    use digest::Digest;
+
+    let msg = [0x61u8; 100];
+    assert_eq!(tiger::hash(&msg[..])[..], tiger::Tiger::digest(&msg)[..]);
+    assert_eq!(tiger::hash2(&msg[..])[..], tiger::Tiger2::digest(&msg)[..]);
+    assert_eq!(tiger::hash("abc"), tiger::hash(b"abc"));
+}
+
+#[test]
+fn five_passes() {
+    use digest::Digest;
+
+    // extra passes after the fourth rename the state variables again
+    assert_eq!(tiger::TigerP::<U5>::digest(b"abc")[..], hex!("
+        686cf930396c8c02922d8005159f94a7864025ed8cc2e862
+    ")[..]);
+}
+
+#[test]
+fn chain() {
+    use digest::Digest;
+
+    let result = tiger::Tiger::new().chain("ab").chain(b"c").finalize();
+    assert_eq!(result, tiger::Tiger::digest(b"abc"));
+    let result = tiger::Tiger128::new().chain_update(b"abc").finalize();
+    assert_eq!(result, tiger::Tiger128::digest(b"abc"));
+    let result = tiger::TigerP::<U4>::new().chain(b"a").chain_update(b"bc");
+    assert_eq!(result.finalize(), tiger::TigerP::<U4>::digest(b"abc"));
+}
+
+#[test]
+fn sizes() {
+    use digest::{BlockInput, Digest};
+    use digest::generic_array::typenum::Unsigned;
+
+    assert_eq!(tiger::BLOCK_SIZE, <tiger::Tiger as BlockInput>::BlockSize::to_usize());
+    assert_eq!(tiger::BLOCK_SIZE, tiger::BlockSize::to_usize());
+    assert_eq!(tiger::DIGEST_SIZE, <tiger::Tiger as Digest>::OutputSize::to_usize());
+    let out: tiger::Output = tiger::Tiger::digest(b"abc");
+    let arr: tiger::TigerOutput = tiger::hash(b"abc");
+    assert_eq!(out[..], arr[..]);
+}
+
+#[test]
+fn finalize_reset() {
+    use digest::Digest;
+
+    let mut hasher = tiger::Tiger160::new();
+    for msg in [&b"abc"[..], b"", &[0x61; 100][..]].iter() {
+        hasher.input(msg);
+        assert_eq!(hasher.finalize_reset(), tiger::Tiger160::digest(msg));
+    }
+    let mut hasher = tiger::TigerP::<U4>::new().chain(b"abc");
+    assert_eq!(hasher.finalize_reset(), tiger::TigerP::<U4>::digest(b"abc"));
+    assert_eq!(hasher.finalize_reset(), tiger::TigerP::<U4>::digest(b""));
+}
+
+#[test]
+fn finalize_into() {
+    use core::convert::TryInto;
+    use digest::Digest;
+    use tiger::FixedOutputInto;
+
+    let mut packet = [0xffu8; 32];
+    let mut hasher = tiger::Tiger::new().chain(b"abc");
+    hasher.finalize_into((&mut packet[4..28]).try_into().unwrap());
+    assert_eq!(packet[4..28], tiger::hash(b"abc")[..]);
+    assert_eq!(packet[..4], [0xff; 4]);
+    assert_eq!(packet[28..], [0xff; 4]);
+
+    let mut out = [0u8; 20];
+    let mut hasher = tiger::Tiger160::new();
+    hasher.finalize_into(&mut out);
+    assert_eq!(out[..], tiger::Tiger160::digest(b"")[..]);
+
+    let mut hasher = tiger::TigerP::<U4>::new().chain(b"abc");
+    let mut out = Default::default();
+    hasher.fixed_result_into(&mut out);
+    assert_eq!(out, tiger::TigerP::<U4>::digest(b"abc"));
+    hasher.fixed_result_into(&mut out);
+    assert_eq!(out, tiger::TigerP::<U4>::digest(b""));
+}
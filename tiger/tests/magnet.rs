@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+extern crate tiger;
+
+use tiger::Digest;
+use tiger::decode_base32;
+use tiger::magnet::{Magnet, InvalidMagnet};
+use tiger::tth::TigerTree;
+
+const EMPTY_TTH: &str = "LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ";
+
+#[test]
+fn build() {
+    let root = TigerTree::digest(b"");
+    assert_eq!(
+        Magnet::new(root).to_string(),
+        format!("magnet:?xt=urn:tree:tiger:{}", EMPTY_TTH),
+    );
+    assert_eq!(
+        Magnet::new(root).size(1025).name("a b&c=d/ü.bin").to_string(),
+        format!(
+            "magnet:?xt=urn:tree:tiger:{}&xl=1025&dn=a%20b%26c%3Dd%2F%C3%BC.bin",
+            EMPTY_TTH,
+        ),
+    );
+}
+
+#[test]
+fn parse() {
+    let root = decode_base32(EMPTY_TTH).unwrap();
+    let uri = format!("magnet:?xt=urn:tree:tiger:{}&xl=10&dn=a+b%20c", EMPTY_TTH);
+    let magnet: Magnet = uri.parse().unwrap();
+    assert_eq!(magnet, Magnet::new(root).size(10).name("a b c"));
+
+    // Gnutella style URNs, lowercase Base32 and unknown parameters
+    let uri = format!(
+        "magnet:?xt=urn:sha1:ABC&xt=urn:tree:tiger/:{}&tr=udp%3A%2F%2Fx",
+        EMPTY_TTH.to_lowercase(),
+    );
+    assert_eq!(uri.parse::<Magnet>().unwrap(), Magnet::new(root));
+    let uri = format!("magnet:?xt=URN:TREE:TIGER/1024:{}", EMPTY_TTH);
+    assert_eq!(uri.parse::<Magnet>().unwrap(), Magnet::new(root));
+}
+
+#[test]
+fn parse_invalid() {
+    let invalid = [
+        String::from("http://example.com"),
+        String::from("magnet:?dn=no_root"),
+        String::from("magnet:?xt=urn:sha1:ABC"),
+        format!("magnet:?xt=urn:tree:tiger:{}A", EMPTY_TTH),
+        format!("magnet:?xt=urn:tree:tiger:{}&xl=-1", EMPTY_TTH),
+        format!("magnet:?xt=urn:tree:tiger:{}&dn=%4", EMPTY_TTH),
+        format!("magnet:?xt=urn:tree:tiger:{}&dn=%FF", EMPTY_TTH),
+        format!("magnet:?xt=urn:tree:tiger:{}&dn", EMPTY_TTH),
+    ];
+    for uri in invalid.iter() {
+        assert_eq!(uri.parse::<Magnet>(), Err(InvalidMagnet), "{}", uri);
+    }
+}
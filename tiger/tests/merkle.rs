@@ -0,0 +1,85 @@
+#![cfg(feature = "std")]
+extern crate sha1;
+extern crate tiger;
+
+use sha1::Sha1;
+use tiger::Digest;
+use tiger::merkle::{self, InvalidProof, MerkleTree, Proof};
+use tiger::tth::{TigerTree, LEAF_SIZE};
+
+fn items(n: usize) -> Vec<Vec<u8>> {
+    (0..n).map(|i| vec![i as u8; i % 7]).collect()
+}
+
+#[test]
+fn tth_root() {
+    for &len in [0, 1, LEAF_SIZE, 5 * LEAF_SIZE + 3, 16 * LEAF_SIZE].iter() {
+        let data: Vec<u8> = (0..len).map(|i| (i * 31) as u8).collect();
+        let tree: MerkleTree = MerkleTree::new(data.chunks(LEAF_SIZE));
+        assert_eq!(*tree.root(), TigerTree::digest(&data), "{} bytes", len);
+    }
+}
+
+#[test]
+fn proofs() {
+    for n in 1..40 {
+        let items = items(n);
+        let tree: MerkleTree = MerkleTree::new(&items);
+        assert_eq!(tree.leaf_count(), n);
+        for (i, item) in items.iter().enumerate() {
+            let proof = tree.proof(i).unwrap();
+            assert_eq!((proof.index(), proof.count()), (i as u64, n as u64));
+            assert!(proof.path().len() < tree.depth());
+            assert!(proof.verify(tree.root(), item));
+            assert!(proof.verify_leaf(tree.root(), tree.leaf(i).unwrap()));
+
+            let mut bad = item.clone();
+            bad.push(0);
+            assert!(!proof.verify(tree.root(), &bad));
+            if n > 1 {
+                let other = Proof::<tiger::Tiger>::from_parts(
+                    (i as u64 + 1) % n as u64, n as u64, proof.path().to_vec());
+                assert!(!other.verify(tree.root(), item));
+            }
+        }
+        assert!(tree.proof(n).is_none());
+    }
+}
+
+#[test]
+fn empty() {
+    let tree: MerkleTree = MerkleTree::new(Vec::<&[u8]>::new());
+    assert_eq!(tree.leaf_count(), 1);
+    assert_eq!(*tree.root(), TigerTree::digest(b""));
+    assert!(tree.proof(0).unwrap().path().is_empty());
+}
+
+#[test]
+fn other_digest() {
+    let items = items(5);
+    let tree = MerkleTree::<Sha1>::new(&items);
+    let proof = tree.proof(3).unwrap();
+    assert!(proof.verify(tree.root(), &items[3]));
+    assert_eq!(*tree.leaf(0).unwrap(), merkle::leaf_hash::<Sha1>(&items[0]));
+    assert_eq!(proof.to_bytes().len(), 16 + 3 * 20);
+}
+
+#[test]
+fn encoding() {
+    let items = items(11);
+    let tree: MerkleTree = MerkleTree::new(&items);
+    for i in 0..items.len() {
+        let proof = tree.proof(i).unwrap();
+        let bytes = proof.to_bytes();
+        assert_eq!(bytes.len(), 16 + 24 * proof.path().len());
+        assert_eq!(Proof::from_bytes(&bytes), Ok(proof));
+
+        assert_eq!(Proof::<tiger::Tiger>::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(InvalidProof));
+    }
+    assert_eq!(Proof::<tiger::Tiger>::from_bytes(&[0; 15]), Err(InvalidProof));
+    // index out of range
+    let mut bytes = tree.proof(0).unwrap().to_bytes();
+    bytes[0] = 11;
+    assert_eq!(Proof::<tiger::Tiger>::from_bytes(&bytes), Err(InvalidProof));
+}
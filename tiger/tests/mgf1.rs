@@ -0,0 +1,35 @@
+#![no_std]
+#[macro_use]
+extern crate hex_literal;
+extern crate tiger;
+
+use tiger::Digest;
+use tiger::mgf1_tiger;
+
+#[test]
+fn mgf1_vectors() {
+    let mut out = [0u8; 50];
+    mgf1_tiger(b"foo", &mut out);
+    assert_eq!(out[..], hex!("
+        5555a89fcef10f3eff69bba614935b017cbd7a176d74560c
+        6fd655351db55805581fb9d60d373f3b5df545e0bcb50cd4
+        6f70
+    ")[..]);
+
+    let mut seed = [0u8; 24];
+    for (i, b) in seed.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let mut out = [0u8; 5];
+    mgf1_tiger(&seed, &mut out);
+    assert_eq!(out, hex!("1d211f0326"));
+}
+
+#[test]
+fn mgf1_first_block() {
+    // the first mask block is Tiger(seed || 00000000)
+    let mut out = [0u8; 24];
+    mgf1_tiger(b"", &mut out);
+    assert_eq!(out[..], tiger::Tiger::digest(&[0; 4])[..]);
+    assert_eq!(out, hex!("605d1b8c132bf5d16f1a8bc2451733f7f0ff57fd5f49e298"));
+}
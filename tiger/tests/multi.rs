@@ -0,0 +1,64 @@
+#![no_std]
+extern crate tiger;
+
+use tiger::multi::{self, compress4, hash4, Backend};
+use tiger::{compress, Block};
+
+fn message() -> [u8; 300] {
+    let mut msg = [0u8; 300];
+    for (i, b) in msg.iter_mut().enumerate() {
+        *b = (i * 7 + 3) as u8;
+    }
+    msg
+}
+
+#[test]
+fn hash4_matches_tiger() {
+    let msg = message();
+    let lens = [0, 1, 55, 56, 63, 64, 65, 120, 128, 200, 300];
+    for &l0 in lens.iter() {
+        for &l1 in lens.iter() {
+            let data = [&msg[..l0], &msg[..l1], &msg[300 - l0..], &msg[..300 - l1]];
+            let out = hash4(data);
+            for (d, o) in data.iter().zip(out.iter()) {
+                assert_eq!(*o, tiger::hash(d));
+            }
+        }
+    }
+}
+
+#[test]
+fn compress4_matches_compress() {
+    let msg = message();
+    let mut blocks = [Block::default(); 4];
+    for (i, block) in blocks.iter_mut().enumerate() {
+        block.copy_from_slice(&msg[i * 50..i * 50 + 64]);
+    }
+    let mut states = [[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]];
+    let mut expected = states;
+    compress4(&mut states, [&blocks[0], &blocks[1], &blocks[2], &blocks[3]]);
+    for (state, block) in expected.iter_mut().zip(blocks.iter()) {
+        compress(state, core::slice::from_ref(block));
+    }
+    assert_eq!(states, expected);
+}
+
+#[test]
+fn forced_backends() {
+    let msg = message();
+    let data = [&msg[..0], &msg[..100], &msg[..200], &msg[..300]];
+    assert!(Backend::Scalar.is_available());
+    assert!(Backend::detect().is_available());
+    for &backend in Backend::ALL.iter() {
+        if !backend.is_available() {
+            assert!(multi::force_backend(backend).is_err());
+            continue;
+        }
+        multi::force_backend(backend).unwrap();
+        assert_eq!(multi::backend(), backend);
+        for (d, o) in data.iter().zip(hash4(data).iter()) {
+            assert_eq!(*o, tiger::hash(d));
+        }
+    }
+    multi::force_backend(Backend::detect()).unwrap();
+}
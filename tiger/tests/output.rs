@@ -0,0 +1,111 @@
+#![cfg(feature = "std")]
+#[cfg(feature = "subtle")]
+extern crate subtle;
+extern crate tiger;
+
+use tiger::{Digest, LegacyOutput, Tiger, TigerDigest};
+use tiger::tth::TigerTree;
+
+#[test]
+fn format() {
+    let digest = TigerDigest::from(Tiger::digest(b"abc"));
+    let lower = "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93";
+    assert_eq!(digest.to_string(), lower);
+    assert_eq!(format!("{:x}", digest), lower);
+    assert_eq!(format!("{:X}", digest), lower.to_uppercase());
+    assert_eq!(format!("{:?}", digest), format!("TigerDigest({})", lower));
+    assert_eq!(digest.as_bytes()[..], Tiger::digest(b"abc")[..]);
+
+    let root = TigerDigest::from(TigerTree::digest(b""));
+    assert_eq!(root.to_string(),
+        "5d9ed00a030e638bdb753a6a24fb900e5a63b8e73e6c25b6");
+}
+
+#[test]
+fn parse() {
+    let digest = TigerDigest::from(Tiger::digest(b"abc"));
+    let lower = "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93";
+    assert_eq!(lower.parse::<TigerDigest>(), Ok(digest));
+    assert_eq!(lower.to_uppercase().parse::<TigerDigest>(), Ok(digest));
+
+    assert!("".parse::<TigerDigest>().is_err());
+    assert!(lower[..46].parse::<TigerDigest>().is_err());
+    assert!(format!("{}00", lower).parse::<TigerDigest>().is_err());
+    assert!(lower.replace("2a", "2g").parse::<TigerDigest>().is_err());
+    // multi-byte characters must not cause a panic
+    let s = format!("{}é", &lower[..46]);
+    assert!(s.parse::<TigerDigest>().is_err());
+}
+
+#[test]
+fn legacy() {
+    // as printed by the reference testtiger.c
+    let legacy = "F258C1E88414AB2A 527AB541FFC5B8BF 935F7B951C132951";
+    let digest = TigerDigest::from(Tiger::digest(b"abc"));
+    assert_eq!(digest.to_legacy_hex(), legacy);
+    assert_eq!(digest.legacy().to_string(), legacy);
+    assert_eq!(legacy.parse::<LegacyOutput>(), Ok(LegacyOutput(digest)));
+    assert_eq!(legacy.to_lowercase().replace(' ', "").parse(), Ok(digest.legacy()));
+    assert_eq!(" F258C1E88414AB2A\t527AB541FFC5B8BF\n935F7B951C132951 ".parse(),
+        Ok(digest.legacy()));
+    assert_eq!(TigerDigest::from(digest.legacy()), digest);
+
+    assert!("".parse::<LegacyOutput>().is_err());
+    assert!(legacy[..47].parse::<LegacyOutput>().is_err());
+    assert!(format!("{} 00", legacy).parse::<LegacyOutput>().is_err());
+    assert!("F258C1E88414AB2A527AB541 FFC5B8BF935F7B951C132951".parse::<LegacyOutput>().is_err());
+    assert!(legacy.replace('F', "G").parse::<LegacyOutput>().is_err());
+}
+
+#[test]
+fn verify() {
+    let digest = Tiger::digest(b"abc");
+    assert!(tiger::verify(&digest, b"abc"));
+    assert!(tiger::verify(&digest, "abc"));
+    assert!(!tiger::verify(&digest, b"abd"));
+    assert!(!tiger::verify(&digest[..23], b"abc"));
+    assert!(!tiger::verify(b"", b"abc"));
+}
+
+#[cfg(feature = "subtle")]
+#[test]
+fn constant_time_eq() {
+    use subtle::ConstantTimeEq;
+
+    let a = TigerDigest::from(Tiger::digest(b"abc"));
+    let b = TigerDigest::from(Tiger::digest(b"abd"));
+    assert!(bool::from(a.ct_eq(&a)));
+    assert!(!bool::from(a.ct_eq(&b)));
+}
+
+#[test]
+fn conversions() {
+    let hex = "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93";
+    let digest: TigerDigest = hex.parse().unwrap();
+    assert_eq!(TigerDigest::from(Tiger::digest(b"abc")), digest);
+
+    let bytes: [u8; 24] = digest.into();
+    assert_eq!(TigerDigest::from(bytes), digest);
+    let array: tiger::digest::generic_array::GenericArray<u8, _> =
+        digest.into();
+    assert_eq!(array, Tiger::digest(b"abc"));
+
+    let words: (u64, u64, u64) = digest.into();
+    assert_eq!(words, (0xf258c1e88414ab2a, 0x527ab541ffc5b8bf,
+        0x935f7b951c132951));
+    assert_eq!(u128::from(digest), 0x527ab541ffc5b8bf_f258c1e88414ab2a);
+    assert_eq!(u64::from(digest), 0xf258c1e88414ab2a);
+}
+
+#[test]
+fn owned_strings() {
+    assert_eq!(tiger::hash_hex(b"abc"),
+        "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93");
+    assert_eq!(tiger::hash_hex_upper(b"abc"),
+        "2AAB1484E8C158F2BFB8C5FF41B57A525129131C957B5F93");
+    assert_eq!(tiger::hash_digest(b"abc").to_hex_upper(),
+        tiger::hash_hex_upper(b"abc"));
+    let root = TigerDigest::from(TigerTree::digest(b""));
+    assert_eq!(root.to_hex(), root.to_string());
+    assert_eq!(root.to_base32(), "LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ");
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "parallel")]
+extern crate tiger;
+
+use std::fs;
+use std::io::Write;
+use tiger::Digest;
+use tiger::tth::{TigerTree, LEAF_SIZE};
+
+fn message(len: usize) -> Vec<u8> {
+    (0..len).map(|i| ((i * 131 + 7) % 251) as u8).collect()
+}
+
+#[test]
+fn slice_parallel() {
+    let chunk = 256 * LEAF_SIZE;
+    let lens = [0, 1, LEAF_SIZE, chunk - 1, chunk, chunk + 1, 3 * chunk,
+        5 * chunk + 7 * LEAF_SIZE + 3];
+    for &len in lens.iter() {
+        let data = message(len);
+        assert_eq!(TigerTree::hash_slice_parallel(&data), TigerTree::digest(&data));
+    }
+}
+
+#[test]
+fn file_parallel() {
+    // larger than one read batch of 16 MiB
+    let data = message((16 << 20) + 300 * LEAF_SIZE + 5);
+    let path = std::env::temp_dir().join("tiger_file_parallel_test.bin");
+    fs::File::create(&path).unwrap().write_all(&data).unwrap();
+    let result = TigerTree::hash_file_parallel(&path);
+    fs::remove_file(&path).unwrap();
+    assert_eq!(result.unwrap(), TigerTree::digest(&data));
+}
+
+#[test]
+fn files() {
+    let dir = std::env::temp_dir().join("tiger_hash_files_test");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir(&dir).unwrap();
+    let mut paths = Vec::new();
+    for i in 0..20 {
+        let path = dir.join(format!("{}.bin", i));
+        fs::write(&path, message(i * 1000)).unwrap();
+        paths.push(path);
+    }
+    paths.insert(5, dir.join("missing"));
+
+    for &threads in [0, 1, 3].iter() {
+        let results = tiger::HashFiles::new().threads(threads).run(&paths);
+        assert_eq!(results.len(), paths.len());
+        for (i, (path, sum)) in results.into_iter().enumerate() {
+            assert_eq!(path, paths[i]);
+            if i == 5 {
+                assert_eq!(sum.unwrap_err().kind(), std::io::ErrorKind::NotFound);
+            } else {
+                let len = if i < 5 { i } else { i - 1 } * 1000;
+                assert_eq!(sum.unwrap(), tiger::hash(message(len)));
+            }
+        }
+    }
+    let results = tiger::hash_files(&paths[..2]);
+    assert_eq!(results[1].1.as_ref().unwrap(), &tiger::hash(message(1000)));
+
+    // cancelled before the start
+    let cancel = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(true));
+    let results = tiger::HashFiles::new().cancel_flag(cancel).run(&paths);
+    assert!(results.iter().all(|r| r.1.is_err()));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn files_symlinks() {
+    let dir = std::env::temp_dir().join("tiger_hash_files_symlinks_test");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir(&dir).unwrap();
+    let target = dir.join("target");
+    let link = dir.join("link");
+    fs::write(&target, b"abc").unwrap();
+    std::os::unix::fs::symlink(&target, &link).unwrap();
+
+    let followed = tiger::hash_files(&[&link]);
+    assert_eq!(followed[0].1.as_ref().unwrap(), &tiger::hash(b"abc"));
+    let results = tiger::HashFiles::new().follow_symlinks(false).run(&[&link, &target]);
+    assert_eq!(results[0].1.as_ref().unwrap_err().kind(),
+        std::io::ErrorKind::InvalidInput);
+    assert_eq!(results[1].1.as_ref().unwrap(), &tiger::hash(b"abc"));
+
+    // dangling links fail the same way, missing files as usual
+    let dangling = dir.join("dangling");
+    std::os::unix::fs::symlink(dir.join("missing"), &dangling).unwrap();
+    let results = tiger::HashFiles::new().follow_symlinks(false)
+        .run(&[&dangling, &dir.join("missing")]);
+    assert_eq!(results[0].1.as_ref().unwrap_err().kind(),
+        std::io::ErrorKind::InvalidInput);
+    assert_eq!(results[1].1.as_ref().unwrap_err().kind(),
+        std::io::ErrorKind::NotFound);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
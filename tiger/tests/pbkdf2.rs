@@ -0,0 +1,39 @@
+//! PBKDF2-HMAC-Tiger test vectors for the RFC 6070 test cases
+#![cfg(feature = "pbkdf2")]
+#![no_std]
+#[macro_use]
+extern crate hex_literal;
+extern crate tiger;
+
+use tiger::pbkdf2_tiger;
+
+fn check(password: &[u8], salt: &[u8], iterations: u32, expected: &[u8]) {
+    let mut out = [0u8; 64];
+    let out = &mut out[..expected.len()];
+    pbkdf2_tiger(password, salt, iterations, out);
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn pbkdf2_rfc6070() {
+    check(b"password", b"salt", 1,
+        &hex!("32f0fc30670eb34f8528e328300fc1ebe517f77a3a1f06c5"));
+    check(b"password", b"salt", 2,
+        &hex!("fec56ca0fa4266c06152b77fd8e9e763468b78a498890edf"));
+    check(b"password", b"salt", 4096,
+        &hex!("a058f476121edea5e1ee2301759882a62dcff199aec1d3dd"));
+    check(b"passwordPASSWORDpassword",
+        b"saltSALTsaltSALTsaltSALTsaltSALTsalt", 4096,
+        &hex!("
+            5316e693644e3a3923fba6f2613b97b22bd0e7caac8f4d2c
+            400e7cfa15154067f4438332b2b5312c
+        "));
+    check(b"pass\0word", b"sa\0lt", 4096,
+        &hex!("33012c9ea0e677eb54863ff60731daac"));
+}
+
+#[test]
+#[should_panic]
+fn pbkdf2_zero_iterations() {
+    pbkdf2_tiger(b"password", b"salt", 0, &mut [0u8; 24]);
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "std")]
+extern crate tiger;
+
+use tiger::Digest;
+use tiger::digest::Input;
+use tiger::tth::{self, TigerTree, TigerTreeLevel, LEAF_SIZE};
+
+fn message(len: usize) -> Vec<u8> {
+    (0..len).map(|i| ((i * 131 + 7) % 251) as u8).collect()
+}
+
+fn nodes(data: &[u8], level: usize) -> tth::TigerTreeNodes {
+    let mut hasher = TigerTreeLevel::new(level);
+    hasher.input(data);
+    hasher.finalize()
+}
+
+#[test]
+fn verify_blocks() {
+    for &n in [1, 2, 3, 7, 8, 13, 16, 17].iter() {
+        let data = message(n * LEAF_SIZE - 10);
+        let root = TigerTree::digest(&data);
+        let leaves = nodes(&data, 0);
+        let count = n as u64;
+
+        for (i, block) in data.chunks(LEAF_SIZE).enumerate() {
+            let proof = leaves.proof(i).unwrap();
+            let index = i as u64;
+            assert!(tth::verify_block(&root, block, index, count, &proof));
+
+            // corrupted block
+            let mut bad = block.to_vec();
+            bad[0] ^= 1;
+            assert!(!tth::verify_block(&root, &bad, index, count, &proof));
+            // wrong position
+            if n > 1 {
+                let other = (index + 1) % count;
+                assert!(!tth::verify_block(&root, block, other, count, &proof));
+            }
+            // truncated or extended proof
+            if !proof.is_empty() {
+                let short = &proof[..proof.len() - 1];
+                assert!(!tth::verify_block(&root, block, index, count, short));
+            }
+            let mut long = proof.clone();
+            long.push(root);
+            assert!(!tth::verify_block(&root, block, index, count, &long));
+        }
+        assert!(leaves.proof(n).is_none());
+        assert!(!tth::verify_block(&root, b"", count, count, &[]));
+    }
+}
+
+#[test]
+fn verify_segments() {
+    let data = message(13 * LEAF_SIZE);
+    let root = TigerTree::digest(&data);
+    let segments = nodes(&data, 2);
+    let count = segments.leaves().len() as u64;
+    assert_eq!(count, 4);
+
+    for (i, segment) in data.chunks(4 * LEAF_SIZE).enumerate() {
+        let node = TigerTree::digest(segment);
+        let proof = segments.proof(i).unwrap();
+        assert!(tth::verify_node(&root, &node, i as u64, count, &proof));
+    }
+}
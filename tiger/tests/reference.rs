@@ -0,0 +1,76 @@
+//! Differential tests against the C reference implementation, which is
+//! built by the build script with the `reference` feature
+#![cfg(feature = "reference")]
+extern crate proptest;
+extern crate tiger;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use tiger::{Tiger, Tiger2};
+
+extern "C" {
+    fn tiger(data: *const u8, len: u64, res: *mut [u64; 3]);
+    fn tiger2(data: *const u8, len: u64, res: *mut [u64; 3]);
+    fn tiger_bits(data: *const u8, bits: u64, res: *mut [u64; 3]);
+    fn tiger2_bits(data: *const u8, bits: u64, res: *mut [u64; 3]);
+}
+
+/// Run a reference function over `msg`, of which it reads `len` bytes or
+/// bits, and convert its words to the digest bytes
+fn reference(f: unsafe extern "C" fn(*const u8, u64, *mut [u64; 3]), msg: &[u8], len: u64)
+    -> [u8; 24]
+{
+    assert!(len <= 8 * msg.len() as u64);
+    let mut res = [0u64; 3];
+    unsafe { f(msg.as_ptr(), len, &mut res) };
+    let mut out = [0u8; 24];
+    for (chunk, word) in out.chunks_mut(8).zip(res.iter()) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    out
+}
+
+fn check(msg: &[u8]) {
+    let len = msg.len() as u64;
+    assert_eq!(tiger::hash(msg), reference(tiger, msg, len), "Tiger, {} bytes", len);
+    assert_eq!(tiger::hash2(msg), reference(tiger2, msg, len), "Tiger2, {} bytes", len);
+}
+
+#[test]
+fn padding_lengths() {
+    // every position of the padding byte and length field in the last two
+    // blocks, over messages of up to three blocks
+    let msg: Vec<u8> = (0..200u32).map(|i| (i * 31 + 7) as u8).collect();
+    for len in 0..msg.len() {
+        check(&msg[..len]);
+    }
+}
+
+#[test]
+fn bit_lengths() {
+    // the C functions take the bit length, the partial byte is the byte
+    // following the whole bytes
+    let msg: Vec<u8> = (0..201u32).map(|i| (i * 31 + 7) as u8).collect();
+    for bits in 0..8 * 200 {
+        let (len, rem) = (bits / 8, (bits % 8) as u8);
+        let result = Tiger::new().chain(&msg[..len]).result_bits(msg[len], rem);
+        assert_eq!(result[..], reference(tiger_bits, &msg, bits as u64)[..],
+            "Tiger, {} bits", bits);
+        let result = Tiger2::new().chain(&msg[..len]).result_bits(msg[len], rem);
+        assert_eq!(result[..], reference(tiger2_bits, &msg, bits as u64)[..],
+            "Tiger2, {} bits", bits);
+    }
+}
+
+#[test]
+fn large() {
+    let msg: Vec<u8> = (0..1 << 20u32).map(|i| (i ^ (i >> 11)) as u8).collect();
+    check(&msg);
+}
+
+proptest! {
+    #[test]
+    fn random(msg in vec(any::<u8>(), 0..2000)) {
+        check(&msg);
+    }
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "serde")]
+extern crate serde_json;
+extern crate tiger;
+
+use tiger::{Digest, Tiger, Tiger2, TigerP};
+use tiger::digest::generic_array::typenum::U4;
+
+fn message() -> Vec<u8> {
+    (0..300).map(|i| (i * 7) as u8).collect()
+}
+
+fn check_round_trip<D>()
+    where D: Digest + Clone + serde::Serialize + serde::de::DeserializeOwned
+{
+    let msg = message();
+    let expected = D::digest(&msg);
+    for &split in [0, 1, 63, 64, 65, 130, 300].iter() {
+        let mut hasher = D::new();
+        hasher.input(&msg[..split]);
+        let json = serde_json::to_string(&hasher).unwrap();
+
+        let mut restored: D = serde_json::from_str(&json).unwrap();
+        restored.input(&msg[split..]);
+        assert_eq!(restored.result(), expected);
+    }
+}
+
+#[test]
+fn round_trip() {
+    check_round_trip::<Tiger>();
+    check_round_trip::<Tiger2>();
+    check_round_trip::<TigerP<U4>>();
+}
+
+#[test]
+fn stable_format() {
+    let mut hasher = Tiger::new();
+    hasher.input(b"abc");
+    let json = serde_json::to_string(&hasher).unwrap();
+    assert_eq!(json, "{\"state\":[81985529216486895,18364758544493064720,\
+        17336226011405279623],\"length\":3,\"buffer\":[97,98,99]}");
+
+    let mut hasher = Tiger::new();
+    hasher.input(&[0u8; 65][..]);
+    let json = serde_json::to_string(&hasher).unwrap();
+    assert_eq!(json, "{\"state\":[11353263996758572207,16832021605941861732,\
+        8706668372834435675],\"length\":65,\"buffer\":[0]}");
+}
+
+#[test]
+fn invalid_state() {
+    // buffer length must be equal to `length % 64`
+    let json = "{\"state\":[1,2,3],\"length\":4,\"buffer\":[1,2,3]}";
+    assert!(serde_json::from_str::<Tiger>(json).is_err());
+    let json = "{\"state\":[1,2,3],\"length\":3}";
+    assert!(serde_json::from_str::<Tiger>(json).is_err());
+    let json = "{\"state\":[1,2,3],\"length\":3,\"buffer\":[1,2,3],\"x\":1}";
+    assert!(serde_json::from_str::<Tiger>(json).is_err());
+}
@@ -0,0 +1,89 @@
+//! Property tests for the buffering code: hashing a message in arbitrary
+//! pieces has to give the one-shot digest
+extern crate proptest;
+extern crate tiger;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use tiger::digest::Reset;
+use tiger::digest::generic_array::typenum::U4;
+use tiger::tth::TigerTree;
+use tiger::{Digest, Tiger, Tiger2, TigerP};
+
+/// Feed `msg` to `hasher` in pieces of the given sizes, cycling through
+/// `sizes` until the message is exhausted
+fn input_pieces<D: Digest>(hasher: &mut D, msg: &[u8], sizes: &[usize]) {
+    let mut rest = msg;
+    for &size in sizes.iter().cycle() {
+        // empty pieces only would never make progress
+        if rest.is_empty() || sizes.iter().all(|&s| s == 0) {
+            break;
+        }
+        let (piece, tail) = rest.split_at(size.min(rest.len()));
+        hasher.input(piece);
+        rest = tail;
+    }
+    hasher.input(rest);
+}
+
+fn check_split<D: Digest>(msg: &[u8], sizes: &[usize]) {
+    let mut hasher = D::new();
+    input_pieces(&mut hasher, msg, sizes);
+    assert_eq!(hasher.result(), D::digest(msg));
+}
+
+fn check_reset<D: Digest + Reset>(garbage: &[u8], msg: &[u8]) {
+    let mut hasher = D::new();
+    hasher.input(garbage);
+    Reset::reset(&mut hasher);
+    hasher.input(msg);
+    let first = hasher.result_reset();
+    assert_eq!(first, D::digest(msg));
+    // result_reset leaves a pristine hasher as well
+    hasher.input(msg);
+    assert_eq!(hasher.result(), first);
+}
+
+/// Piece sizes mixing empty and single byte updates with larger ones
+fn sizes() -> impl Strategy<Value = Vec<usize>> {
+    vec(prop_oneof![Just(0usize), Just(1), 1..8usize, 1..200usize], 1..16)
+}
+
+proptest! {
+    #[test]
+    fn split_invariance(msg in vec(any::<u8>(), 0..700), sizes in sizes()) {
+        check_split::<Tiger>(&msg, &sizes);
+        check_split::<Tiger2>(&msg, &sizes);
+        check_split::<TigerP<U4>>(&msg, &sizes);
+    }
+
+    #[test]
+    fn tree_split_invariance(msg in vec(any::<u8>(), 0..5000), sizes in sizes()) {
+        check_split::<TigerTree>(&msg, &sizes);
+    }
+
+    #[test]
+    fn block_boundaries(blocks in 0..3usize, offset in 0..64usize,
+        len in 0..70usize, fill in any::<u8>())
+    {
+        // single byte updates around the end of a block
+        let prefix = 64 * blocks + offset;
+        let msg: Vec<u8> = (0..prefix + len).map(|i| fill ^ i as u8).collect();
+        let mut hasher = Tiger::new();
+        hasher.input(&msg[..prefix]);
+        for b in msg[prefix..].iter() {
+            hasher.input(&[][..]);
+            hasher.input(&[*b][..]);
+        }
+        prop_assert_eq!(hasher.result(), Tiger::digest(&msg));
+    }
+
+    #[test]
+    fn reset_restores_new_state(garbage in vec(any::<u8>(), 0..300),
+        msg in vec(any::<u8>(), 0..300))
+    {
+        check_reset::<Tiger>(&garbage, &msg);
+        check_reset::<Tiger2>(&garbage, &msg);
+        check_reset::<TigerTree>(&garbage, &msg);
+    }
+}
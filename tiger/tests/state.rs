@@ -0,0 +1,101 @@
+#![no_std]
+extern crate tiger;
+
+use tiger::{Digest, Tiger, Tiger2, Tiger128, TigerP, MAX_MESSAGE_LEN, STATE_SIZE};
+use tiger::digest::generic_array::typenum::U4;
+
+fn message() -> [u8; 300] {
+    let mut msg = [0u8; 300];
+    for (i, b) in msg.iter_mut().enumerate() {
+        *b = (i * 7) as u8;
+    }
+    msg
+}
+
+#[test]
+fn resume() {
+    let msg = message();
+    for &split in [0, 1, 63, 64, 65, 130, 300].iter() {
+        let mut hasher = Tiger::new();
+        hasher.input(&msg[..split]);
+        let mut hasher = Tiger::deserialize_state(&hasher.serialize_state())
+            .unwrap();
+        hasher.input(&msg[split..]);
+        assert_eq!(hasher.result(), Tiger::digest(&msg[..]));
+
+        let mut hasher = TigerP::<U4>::new();
+        hasher.input(&msg[..split]);
+        let state = hasher.serialize_state();
+        let mut hasher = TigerP::<U4>::deserialize_state(&state).unwrap();
+        hasher.input(&msg[split..]);
+        assert_eq!(hasher.result(), TigerP::<U4>::digest(&msg[..]));
+    }
+}
+
+#[test]
+fn format() {
+    let mut hasher = Tiger::new();
+    hasher.input(&[0xaa; 67][..]);
+    let state = hasher.serialize_state();
+    assert_eq!(state.len(), STATE_SIZE);
+    assert_eq!(state[..3], [1, 0x01, 3]);
+    assert_eq!(state[27..35], [67, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(state[35..38], [0xaa; 3]);
+    assert!(state[38..].iter().all(|&b| b == 0));
+
+    let state = Tiger2::new().serialize_state();
+    assert_eq!(state[..3], [1, 0x80, 3]);
+    assert_eq!(state[3..11], [0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01]);
+}
+
+#[test]
+fn invalid_state() {
+    let state = Tiger::new().serialize_state();
+    // truncated state
+    assert!(Tiger::deserialize_state(&state[..STATE_SIZE - 1]).is_err());
+    // other variants
+    assert!(Tiger2::deserialize_state(&state).is_err());
+    assert!(TigerP::<U4>::deserialize_state(&state).is_err());
+    // truncations share the `Tiger` state
+    assert!(Tiger128::deserialize_state(&state).is_ok());
+
+    // unknown version
+    let mut bad = state;
+    bad[0] = 2;
+    assert!(Tiger::deserialize_state(&bad).is_err());
+    // bytes after the partial block must be zero
+    let mut bad = state;
+    bad[35] = 1;
+    assert!(Tiger::deserialize_state(&bad).is_err());
+}
+
+const IV: [u64; 3] = [0x0123456789ABCDEF, 0xFEDCBA9876543210, 0xF096A5B4C3B2E187];
+
+#[test]
+fn length_limit() {
+    let mut hasher = Tiger::from_midstate(IV, MAX_MESSAGE_LEN - 63);
+    hasher.input(&[0u8; 63][..]);
+    let state = hasher.serialize_state();
+    assert_eq!(state[27..35], MAX_MESSAGE_LEN.to_le_bytes());
+    let resumed = Tiger::deserialize_state(&state).unwrap();
+    assert_eq!(resumed.result(), hasher.result());
+
+    // lengths of 2^64 bits and more are rejected
+    let mut bad = Tiger::new().serialize_state();
+    bad[27..35].copy_from_slice(&(MAX_MESSAGE_LEN + 1).to_le_bytes());
+    assert!(Tiger::deserialize_state(&bad).is_err());
+}
+
+#[test]
+#[should_panic(expected = "exceeds 2^64 - 1 bits")]
+fn length_overflow() {
+    let mut hasher = Tiger::from_midstate(IV, MAX_MESSAGE_LEN - 63);
+    hasher.input(&[0u8; 63][..]);
+    hasher.input(b"x");
+}
+
+#[test]
+#[should_panic(expected = "exceeds 2^64 - 1 bits")]
+fn midstate_length_overflow() {
+    Tiger::from_midstate(IV, MAX_MESSAGE_LEN + 1);
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "std")]
+extern crate tiger;
+
+use tiger::Digest;
+use tiger::digest::Input;
+use tiger::tth::{self, TigerTree, LEAF_SIZE};
+
+/// Naive TTH which keeps all leaves of the tree in memory
+fn naive_tth(data: &[u8]) -> Vec<u8> {
+    let mut nodes: Vec<_> = if data.is_empty() {
+        vec![tth::leaf_hash(b"")]
+    } else {
+        data.chunks(LEAF_SIZE).map(tth::leaf_hash).collect()
+    };
+    while nodes.len() > 1 {
+        nodes = nodes.chunks(2).map(|pair| match pair.len() {
+            2 => tth::node_hash(&pair[0], &pair[1]),
+            _ => pair[0],
+        }).collect();
+    }
+    nodes[0].to_vec()
+}
+
+#[test]
+fn streaming_matches_naive() {
+    let data: Vec<u8> = (0..300 * LEAF_SIZE + 123)
+        .map(|i| (i * 131 + 7) as u8)
+        .collect();
+    let lens = [0, 1, LEAF_SIZE - 1, LEAF_SIZE, LEAF_SIZE + 1,
+        7 * LEAF_SIZE, 64 * LEAF_SIZE, 255 * LEAF_SIZE + 5, data.len()];
+    for &len in lens.iter() {
+        let data = &data[..len];
+        let expected = naive_tth(data);
+        assert_eq!(TigerTree::digest(data)[..], expected[..]);
+
+        // feed input in chunks not aligned to leaf boundaries
+        for &chunk in [1, 100, 1000, 3000].iter() {
+            let mut hasher = TigerTree::default();
+            for part in data.chunks(chunk) {
+                Input::input(&mut hasher, part);
+            }
+            assert_eq!(Digest::result(hasher)[..], expected[..]);
+        }
+    }
+}
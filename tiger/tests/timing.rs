@@ -0,0 +1,89 @@
+//! dudect-style timing test of the `constant-time` compression function
+//!
+//! The compression of a fixed block and of random blocks is timed in
+//! random order and Welch's t-test is applied to the two distributions,
+//! as in "Dude, is my code constant time?" (Reparaz, Balasch, Verbauwhede,
+//! 2017). Timing is noisy, so the test is ignored by default, run it with
+//! `cargo test --release --features constant-time --test timing -- --ignored`.
+//!
+//! Passing only means that no difference was found on this machine, it is
+//! no proof that the implementation is constant-time. The table lookups of
+//! the default implementation pass as well while the tables stay in the
+//! cache, so this is weak evidence.
+#![cfg(feature = "constant-time")]
+extern crate tiger;
+
+use std::time::Instant;
+use tiger::{compress, Block};
+
+/// Measurements per class
+const SAMPLES: usize = 100_000;
+/// Compressions per measurement, to exceed the timer resolution
+const BATCH: usize = 4;
+/// dudect considers larger values of |t| as evidence of a leak
+const THRESHOLD: f64 = 10.0;
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn block(&mut self) -> Block {
+        let mut block = Block::default();
+        for chunk in block.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next().to_le_bytes());
+        }
+        block
+    }
+}
+
+/// Mean and variance of `samples`
+fn stats(samples: &[f64]) -> (f64, f64) {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let var = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (n - 1.0);
+    (mean, var)
+}
+
+#[test]
+#[ignore]
+fn fixed_vs_random() {
+    let mut rng = XorShift(0x0123_4567_89AB_CDEF);
+    let fixed = [Block::default(); BATCH];
+    let mut times = [Vec::with_capacity(SAMPLES), Vec::with_capacity(SAMPLES)];
+    let mut state = [0u64; 3];
+    while times[0].len() < SAMPLES || times[1].len() < SAMPLES {
+        let class = (rng.next() & 1) as usize;
+        let mut blocks = fixed;
+        if class == 1 {
+            for block in blocks.iter_mut() {
+                *block = rng.block();
+            }
+        }
+        let start = Instant::now();
+        compress(&mut state, &blocks);
+        times[class].push(start.elapsed().as_nanos() as f64);
+    }
+
+    // like dudect, drop the outliers caused by interrupts and scheduling
+    let mut all: Vec<f64> = times.iter().flatten().cloned().collect();
+    all.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let cutoff = all[all.len() * 9 / 10];
+    let cropped: Vec<Vec<f64>> = times.iter()
+        .map(|t| t.iter().cloned().filter(|&x| x <= cutoff).collect())
+        .collect();
+
+    let (m0, v0) = stats(&cropped[0]);
+    let (m1, v1) = stats(&cropped[1]);
+    let n0 = cropped[0].len() as f64;
+    let n1 = cropped[1].len() as f64;
+    let t = (m0 - m1) / (v0 / n0 + v1 / n1).sqrt();
+    assert!(t.abs() < THRESHOLD, "t = {:.2}, fixed {:.0} ns, random {:.0} ns", t, m0, m1);
+    // keep the compressions from being optimized out
+    assert_ne!(state, [0; 3]);
+}
@@ -0,0 +1,63 @@
+//! Intermediate values reported by the `trace` module
+#![cfg(feature = "trace")]
+extern crate tiger;
+
+use tiger::trace::{self, Event};
+use tiger::Block;
+
+fn message(len: usize) -> Vec<u8> {
+    (0..len).map(|i| ((i * 131 + 7) % 251) as u8).collect()
+}
+
+#[test]
+fn hash() {
+    for &len in [0, 3, 55, 56, 64, 100, 1000].iter() {
+        let data = message(len);
+        assert_eq!(trace::hash(&data, |_| ()), tiger::hash(&data), "{}", len);
+    }
+}
+
+#[test]
+fn events() {
+    let block = *Block::from_slice(&message(64));
+    let mut events = Vec::new();
+    let mut state = [1, 2, 3];
+    trace::compress(&mut state, &block, 4, |e| events.push(*e));
+
+    // 8 rounds per pass and a key schedule before every pass but the first
+    assert_eq!(events.len(), 4 * 8 + 3 + 1);
+    match events[8] {
+        Event::KeySchedule { pass, .. } => assert_eq!(pass, 1),
+        e => panic!("unexpected {:?}", e),
+    }
+    assert_eq!(events.last(), Some(&Event::Block { state }));
+    match events[34] {
+        Event::Round { pass, round, .. } => assert_eq!((pass, round), (3, 7)),
+        e => panic!("unexpected {:?}", e),
+    }
+
+    let mut expected = [1, 2, 3];
+    tiger::compress(&mut expected, &[block]);
+    let mut three = [1, 2, 3];
+    trace::compress(&mut three, &block, 3, |_| ());
+    assert_eq!(three, expected);
+}
+
+#[test]
+fn first_round() {
+    // `c` only changes by the XOR with the first message word
+    let block = *Block::from_slice(&message(64));
+    let mut first = None;
+    let mut state = [1, 2, 3];
+    trace::compress(&mut state, &block, 3, |e| if first.is_none() {
+        first = Some(*e);
+    });
+    let x0 = u64::from_le_bytes([block[0], block[1], block[2], block[3],
+        block[4], block[5], block[6], block[7]]);
+    match first {
+        Some(Event::Round { pass: 0, round: 0, state }) => {
+            assert_eq!(state[2], 3 ^ x0);
+        }
+        e => panic!("unexpected {:?}", e),
+    }
+}
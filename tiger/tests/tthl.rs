@@ -0,0 +1,74 @@
+#![cfg(feature = "std")]
+#[macro_use]
+extern crate hex_literal;
+extern crate tiger;
+
+use tiger::Digest;
+use tiger::digest::Input;
+use tiger::tth::{self, TigerTree, TigerTreeLevel};
+
+fn message() -> Vec<u8> {
+    // 13 leaves, so that every level above the leaves has a promoted node
+    (0..12 * 1024 + 1).map(|i| ((i * 131 + 7) % 251) as u8).collect()
+}
+
+fn level(data: &[u8], n: usize) -> tth::TigerTreeNodes {
+    let mut hasher = TigerTreeLevel::new(n);
+    // feed data in uneven pieces to cross leaf boundaries
+    for chunk in data.chunks(1000) {
+        hasher.input(chunk);
+    }
+    hasher.finalize()
+}
+
+#[test]
+fn leaf_level() {
+    let data = message();
+    let nodes = level(&data, 0);
+    let leaves: Vec<_> = data.chunks(tth::LEAF_SIZE).map(tth::leaf_hash).collect();
+    assert_eq!(nodes.leaves().cloned().collect::<Vec<_>>(), leaves);
+    assert_eq!(nodes.block_size(), 1024);
+    assert_eq!(*nodes.root(), TigerTree::digest(&data));
+}
+
+#[test]
+fn upper_levels() {
+    let data = message();
+
+    let nodes = level(&data, 2);
+    let expected = [
+        hex!("fdadc013758c97a841b7bfff0bb387fc2f93690845266652"),
+        hex!("b4e25c56558b61aa12b3c96de9e4033eedda8de77e60b60d"),
+        hex!("7e0b1b1a85617fdf3b84cc83475d65e5d827500823ccb015"),
+        hex!("5beb86107ad2747114baabb7e778cb23d1fdeb7bd8c2c486"),
+    ];
+    assert_eq!(nodes.leaves().count(), expected.len());
+    for (node, exp) in nodes.leaves().zip(expected.iter()) {
+        assert_eq!(node[..], exp[..]);
+    }
+    assert_eq!(nodes.block_size(), 4096);
+
+    let nodes = level(&data, 3);
+    let mut tthl = Vec::new();
+    nodes.write_tthl(&mut tthl).unwrap();
+    assert_eq!(tthl[..], hex!("
+        1927037e5616fb73ebc5f61f86db69f64c4f93e16414d556
+        29abb6182af26b6f36c4314a2e32683238b5ef61c839d5d0
+    ")[..]);
+
+    // levels at or above the root only contain the root
+    for &n in [4, 5, 40].iter() {
+        let nodes = level(&data, n);
+        assert_eq!(nodes.leaves().collect::<Vec<_>>(), vec![nodes.root()]);
+        assert_eq!(nodes.root()[..], hex!("
+            5141eb35261a13f1fe9b3d57f888d283d93883749434a250
+        ")[..]);
+    }
+}
+
+#[test]
+fn empty_message() {
+    let nodes = TigerTreeLevel::new(0).finalize();
+    assert_eq!(nodes.leaves().collect::<Vec<_>>(), vec![nodes.root()]);
+    assert_eq!(*nodes.root(), TigerTree::digest(b""));
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "wasm")]
+extern crate tiger;
+
+use tiger::wasm::{tiger_hex, tth_base32, WasmTiger, WasmTigerTree};
+
+#[test]
+fn one_shot() {
+    assert_eq!(tiger_hex(b"abc"), "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93");
+    assert_eq!(tth_base32(b""), "LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ");
+    assert_eq!(tth_base32(b"abc"), "ASD4UJSEH5M47PDYB46KBTSQTSGDKLBHYXOMUIA");
+}
+
+#[test]
+fn streaming() {
+    let mut hasher = WasmTiger::new();
+    hasher.update(b"a");
+    hasher.update(b"bc");
+    assert_eq!(hasher.clone().finalize(), tiger::hash(b"abc"));
+    assert_eq!(hasher.finalize_hex(), tiger_hex(b"abc"));
+
+    let msg = vec![0x61; 3000];
+    let mut tree = WasmTigerTree::new();
+    for chunk in msg.chunks(700) {
+        tree.update(chunk);
+    }
+    assert_eq!(tree.clone().finalize().len(), 24);
+    assert_eq!(tree.finalize_base32(), tth_base32(&msg));
+}
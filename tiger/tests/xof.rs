@@ -0,0 +1,47 @@
+#![no_std]
+extern crate tiger;
+
+use tiger::{mgf1_tiger, TigerXof};
+use tiger::digest::{Input, ExtendableOutput, XofReader};
+
+fn message() -> [u8; 200] {
+    let mut msg = [0u8; 200];
+    for (i, b) in msg.iter_mut().enumerate() {
+        *b = (i * 7) as u8;
+    }
+    msg
+}
+
+#[test]
+fn xof_matches_mgf1() {
+    let msg = message();
+    for &len in [0, 1, 55, 56, 64, 100, 200].iter() {
+        let mut expected = [0u8; 300];
+        mgf1_tiger(&msg[..len], &mut expected);
+
+        let mut xof = TigerXof::default();
+        xof.input(&msg[..len]);
+        let mut out = [0u8; 300];
+        xof.xof_result().read(&mut out);
+        assert_eq!(out[..], expected[..]);
+    }
+}
+
+#[test]
+fn xof_chunked_read() {
+    let msg = message();
+    let mut expected = [0u8; 300];
+    mgf1_tiger(&msg, &mut expected);
+
+    for &chunk in [1, 5, 23, 24, 25, 100].iter() {
+        let mut xof = TigerXof::default();
+        xof.input(&msg[..100]);
+        xof.input(&msg[100..]);
+        let mut reader = xof.xof_result();
+        let mut out = [0u8; 300];
+        for part in out.chunks_mut(chunk) {
+            reader.read(part);
+        }
+        assert_eq!(out[..], expected[..]);
+    }
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "zeroize")]
+#![no_std]
+extern crate tiger;
+extern crate zeroize;
+
+use tiger::{Digest, Tiger, TigerXof};
+use tiger::digest::{Input, ExtendableOutput, XofReader};
+use zeroize::Zeroize;
+#[cfg(feature = "keyed")]
+use tiger::KeyedTiger;
+#[cfg(feature = "keyed")]
+use tiger::crypto_mac::Mac;
+
+#[test]
+fn zeroize_hasher() {
+    let mut hasher = Tiger::new();
+    Digest::input(&mut hasher, &[0xaa; 100][..]);
+    hasher.zeroize();
+
+    let state = hasher.serialize_state();
+    assert!(state[3..].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn zeroize_xof_reader() {
+    let mut xof = TigerXof::default();
+    xof.input(b"secret");
+    let mut reader = xof.xof_result();
+    let mut out = [0u8; 10];
+    reader.read(&mut out);
+    reader.zeroize();
+
+    // the rest of the wiped output block is not readable anymore
+    let mut rest = [0u8; 14];
+    reader.read(&mut rest);
+    assert_eq!(rest, [0u8; 14]);
+}
+
+#[test]
+#[cfg(feature = "keyed")]
+fn zeroize_keyed() {
+    let mut a = KeyedTiger::new_varkey(b"first key").unwrap();
+    a.input(b"first message");
+    let mut b = KeyedTiger::new_varkey(b"second, longer key").unwrap();
+    b.input(b"second message");
+    a.zeroize();
+    b.zeroize();
+
+    // neither the keys nor the states after pad(K) are left, also not for
+    // reset
+    a.reset();
+    b.reset();
+    assert_eq!(a.result().code(), b.result().code());
+}
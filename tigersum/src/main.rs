@@ -0,0 +1,316 @@
+//! Print or check Tiger checksums
+//!
+//! Behaves like the GNU coreutils `*sum` tools: every named file (or
+//! standard input for `-` or when no file is given) is hashed and printed as
+//! `<hex>  <name>`, and `--check` reads such lines back and verifies them.
+extern crate tiger;
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::process;
+
+const NAME: &str = "tigersum";
+const TAG: &str = "TIGER";
+const HEX_LEN: usize = 48;
+
+const USAGE: &str = "\
+Usage: tigersum [OPTION]... [FILE]...
+Print or check Tiger (192-bit) checksums.
+
+With no FILE, or when FILE is -, read standard input.
+
+  -b, --binary         read in binary mode (prefix names with '*')
+  -c, --check          read Tiger sums from the FILEs and check them
+      --tag            create a BSD-style checksum
+  -t, --text           read in text mode (default)
+
+The following five options are useful only when verifying checksums:
+      --ignore-missing  don't fail or report status for missing files
+      --quiet          don't print OK for each successfully verified file
+      --status         don't output anything, status code shows success
+      --strict         exit non-zero for improperly formatted checksum lines
+  -w, --warn           warn about improperly formatted checksum lines
+
+      --help     display this help and exit
+      --version  output version information and exit
+";
+
+#[derive(Default)]
+struct Options {
+    binary: bool,
+    check: bool,
+    tag: bool,
+    ignore_missing: bool,
+    quiet: bool,
+    status: bool,
+    strict: bool,
+    warn: bool,
+    files: Vec<String>,
+}
+
+fn usage_error(msg: &str) -> ! {
+    eprintln!("{}: {}", NAME, msg);
+    eprintln!("Try '{} --help' for more information.", NAME);
+    process::exit(1);
+}
+
+fn parse_args() -> Options {
+    let mut opts = Options::default();
+    let mut only_files = false;
+    for arg in env::args().skip(1) {
+        if only_files || arg == "-" || !arg.starts_with('-') {
+            opts.files.push(arg);
+            continue;
+        }
+        match arg.as_str() {
+            "--" => only_files = true,
+            "--binary" => opts.binary = true,
+            "--check" => opts.check = true,
+            "--tag" => opts.tag = true,
+            "--text" => opts.binary = false,
+            "--ignore-missing" => opts.ignore_missing = true,
+            "--quiet" => opts.quiet = true,
+            "--status" => opts.status = true,
+            "--strict" => opts.strict = true,
+            "--warn" => opts.warn = true,
+            "--help" => {
+                print!("{}", USAGE);
+                process::exit(0);
+            }
+            "--version" => {
+                println!("{} {}", NAME, env!("CARGO_PKG_VERSION"));
+                process::exit(0);
+            }
+            _ if arg.starts_with("--") => {
+                usage_error(&format!("unrecognized option '{}'", arg));
+            }
+            _ => for c in arg[1..].chars() {
+                match c {
+                    'b' => opts.binary = true,
+                    'c' => opts.check = true,
+                    't' => opts.binary = false,
+                    'w' => opts.warn = true,
+                    _ => usage_error(&format!("invalid option -- '{}'", c)),
+                }
+            },
+        }
+    }
+    if opts.files.is_empty() {
+        opts.files.push("-".to_string());
+    }
+    if opts.tag && opts.check {
+        usage_error("the --tag option is meaningless when verifying checksums");
+    }
+    if !opts.check && (opts.ignore_missing || opts.quiet || opts.status
+        || opts.strict || opts.warn)
+    {
+        usage_error("the verification options are meaningful only when \
+            verifying checksums");
+    }
+    opts
+}
+
+fn open(name: &str) -> io::Result<Box<dyn Read>> {
+    if name == "-" {
+        Ok(Box::new(io::stdin()))
+    } else {
+        Ok(Box::new(File::open(name)?))
+    }
+}
+
+fn hash_name(name: &str) -> io::Result<String> {
+    let sum = tiger::hash_reader(open(name)?)?;
+    Ok(sum.iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// Escape backslashes and newlines in `name` the way GNU tools do, the
+/// returned flag tells whether the line has to be prefixed by a backslash
+fn escape(name: &str) -> (bool, String) {
+    if !name.contains(['\\', '\n']) {
+        return (false, name.to_string());
+    }
+    (true, name.replace('\\', "\\\\").replace('\n', "\\n"))
+}
+
+fn unescape(name: &str) -> Option<String> {
+    let mut out = String::with_capacity(name.len());
+    let mut chars = name.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => out.push('\\'),
+            Some('n') => out.push('\n'),
+            _ => return None,
+        }
+    }
+    Some(out)
+}
+
+fn print_sums(opts: &Options) -> bool {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut ok = true;
+    for name in &opts.files {
+        match hash_name(name) {
+            Ok(hex) => {
+                let (escaped, shown) = escape(name);
+                let prefix = if escaped { "\\" } else { "" };
+                let line = if opts.tag {
+                    format!("{}{} ({}) = {}", prefix, TAG, shown, hex)
+                } else {
+                    let mode = if opts.binary { '*' } else { ' ' };
+                    format!("{}{} {}{}", prefix, hex, mode, shown)
+                };
+                writeln!(out, "{}", line).expect("failed to write output");
+            }
+            Err(e) => {
+                eprintln!("{}: {}: {}", NAME, name, e);
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+/// Parse a checksum line into the expected hex digest and the file name
+fn parse_line(line: &str) -> Option<(String, String)> {
+    let (escaped, line) = match line.strip_prefix('\\') {
+        Some(line) => (true, line),
+        None => (false, line),
+    };
+    let tagged = line.strip_prefix(TAG).and_then(|l| l.strip_prefix(" ("));
+    let (hex, name) = if let Some(rest) = tagged {
+        let split = rest.rfind(") = ")?;
+        (&rest[split + 4..], &rest[..split])
+    } else {
+        if !line.is_char_boundary(HEX_LEN) {
+            return None;
+        }
+        let (hex, rest) = line.split_at(HEX_LEN);
+        let name = rest.strip_prefix(" ")
+            .and_then(|r| r.strip_prefix(' ').or_else(|| r.strip_prefix('*')))?;
+        (hex, name)
+    };
+    let is_hex = hex.len() == HEX_LEN && hex.bytes().all(|b| b.is_ascii_hexdigit());
+    if !is_hex || name.is_empty() {
+        return None;
+    }
+    let name = if escaped { unescape(name)? } else { name.to_string() };
+    Some((hex.to_ascii_lowercase(), name))
+}
+
+fn plural(n: usize, one: &str, many: &str) -> String {
+    format!("{} {}", n, if n == 1 { one } else { many })
+}
+
+fn check_sums(opts: &Options) -> bool {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut ok = true;
+    for list in &opts.files {
+        let reader = match open(list) {
+            Ok(r) => BufReader::new(r),
+            Err(e) => {
+                eprintln!("{}: {}: {}", NAME, list, e);
+                ok = false;
+                continue;
+            }
+        };
+        let (mut mismatched, mut unreadable, mut malformed) = (0, 0, 0);
+        let (mut formatted, mut verified) = (0, 0);
+        for (i, line) in reader.lines().enumerate() {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => {
+                    eprintln!("{}: {}: {}", NAME, list, e);
+                    ok = false;
+                    break;
+                }
+            };
+            let line = line.trim_end_matches('\r');
+            let (expected, name) = match parse_line(line) {
+                Some(v) => v,
+                None => {
+                    malformed += 1;
+                    if opts.warn {
+                        eprintln!("{}: {}: {}: improperly formatted {} checksum line",
+                            NAME, list, i + 1, TAG);
+                    }
+                    continue;
+                }
+            };
+            formatted += 1;
+            let (escaped, shown) = escape(&name);
+            let prefix = if escaped { "\\" } else { "" };
+            let status = match hash_name(&name) {
+                Ok(ref hex) if *hex == expected => {
+                    verified += 1;
+                    if opts.quiet { None } else { Some("OK") }
+                }
+                Ok(_) => {
+                    verified += 1;
+                    mismatched += 1;
+                    Some("FAILED")
+                }
+                Err(ref e) if opts.ignore_missing
+                    && e.kind() == io::ErrorKind::NotFound => None,
+                Err(e) => {
+                    if !opts.status {
+                        eprintln!("{}: {}: {}", NAME, name, e);
+                    }
+                    unreadable += 1;
+                    Some("FAILED open or read")
+                }
+            };
+            if let (Some(status), false) = (status, opts.status) {
+                writeln!(out, "{}{}: {}", prefix, shown, status)
+                    .expect("failed to write output");
+            }
+        }
+
+        if formatted == 0 {
+            if !opts.status {
+                eprintln!("{}: {}: no properly formatted {} checksum lines found",
+                    NAME, list, TAG);
+            }
+            ok = false;
+            continue;
+        }
+        if opts.ignore_missing && verified == 0 && unreadable == 0 {
+            if !opts.status {
+                eprintln!("{}: {}: no file was verified", NAME, list);
+            }
+            ok = false;
+        }
+        if !opts.status {
+            if malformed > 0 {
+                eprintln!("{}: WARNING: {} improperly formatted", NAME,
+                    plural(malformed, "line is", "lines are"));
+            }
+            if unreadable > 0 {
+                eprintln!("{}: WARNING: {} could not be read", NAME,
+                    plural(unreadable, "listed file", "listed files"));
+            }
+            if mismatched > 0 {
+                eprintln!("{}: WARNING: {} did NOT match", NAME,
+                    plural(mismatched, "computed checksum", "computed checksums"));
+            }
+        }
+        if mismatched > 0 || unreadable > 0 || (opts.strict && malformed > 0) {
+            ok = false;
+        }
+    }
+    ok
+}
+
+fn main() {
+    let opts = parse_args();
+    let ok = if opts.check { check_sums(&opts) } else { print_sums(&opts) };
+    if !ok {
+        process::exit(1);
+    }
+}
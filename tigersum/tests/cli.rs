@@ -0,0 +1,103 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+const ABC: &str = "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93";
+const EMPTY: &str = "3293ac630c13f0245f92bbb1766e16167a4e58492dde73f3";
+
+fn tigersum(args: &[&str], stdin: &[u8], dir: &PathBuf) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tigersum"))
+        .args(args)
+        .current_dir(dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("tigersum-test-{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn print() {
+    let dir = temp_dir("print");
+    fs::write(dir.join("abc"), b"abc").unwrap();
+    fs::write(dir.join("empty"), b"").unwrap();
+
+    let out = tigersum(&["abc", "empty", "-"], b"abc", &dir);
+    assert!(out.status.success());
+    assert_eq!(stdout(&out),
+        format!("{0}  abc\n{1}  empty\n{0}  -\n", ABC, EMPTY));
+
+    let out = tigersum(&[], b"", &dir);
+    assert_eq!(stdout(&out), format!("{}  -\n", EMPTY));
+
+    let out = tigersum(&["-b", "abc"], b"", &dir);
+    assert_eq!(stdout(&out), format!("{} *abc\n", ABC));
+
+    let out = tigersum(&["--tag", "abc"], b"", &dir);
+    assert_eq!(stdout(&out), format!("TIGER (abc) = {}\n", ABC));
+
+    fs::write(dir.join("a\\b"), b"abc").unwrap();
+    let out = tigersum(&["a\\b"], b"", &dir);
+    assert_eq!(stdout(&out), format!("\\{}  a\\\\b\n", ABC));
+
+    let out = tigersum(&["missing", "abc"], b"", &dir);
+    assert!(!out.status.success());
+    assert_eq!(stdout(&out), format!("{}  abc\n", ABC));
+}
+
+#[test]
+fn check() {
+    let dir = temp_dir("check");
+    fs::write(dir.join("abc"), b"abc").unwrap();
+    fs::write(dir.join("a\\b"), b"").unwrap();
+    let list = format!("{}  abc\nTIGER (abc) = {}\n\\{}  a\\\\b\n",
+        ABC, ABC.to_uppercase(), EMPTY);
+    fs::write(dir.join("good"), &list).unwrap();
+
+    let out = tigersum(&["-c", "good"], b"", &dir);
+    assert!(out.status.success());
+    assert_eq!(stdout(&out), "abc: OK\nabc: OK\n\\a\\\\b: OK\n");
+
+    let out = tigersum(&["--check", "--quiet", "-"], list.as_bytes(), &dir);
+    assert!(out.status.success());
+    assert_eq!(stdout(&out), "");
+
+    let bad = format!("{}  abc\n{}  missing\nnot a checksum line\n", EMPTY, ABC);
+    fs::write(dir.join("bad"), &bad).unwrap();
+    let out = tigersum(&["-c", "bad"], b"", &dir);
+    assert!(!out.status.success());
+    assert_eq!(stdout(&out), "abc: FAILED\nmissing: FAILED open or read\n");
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert!(stderr.contains("WARNING: 1 line is improperly formatted"));
+    assert!(stderr.contains("WARNING: 1 listed file could not be read"));
+    assert!(stderr.contains("WARNING: 1 computed checksum did NOT match"));
+
+    let out = tigersum(&["-c", "--status", "bad"], b"", &dir);
+    assert!(!out.status.success());
+    assert!(out.stdout.is_empty() && out.stderr.is_empty());
+
+    let partial = format!("{}  abc\n{}  missing\nnot a checksum line\n", ABC, ABC);
+    let out = tigersum(&["-c", "--ignore-missing", "-"], partial.as_bytes(), &dir);
+    assert!(out.status.success());
+    assert_eq!(stdout(&out), "abc: OK\n");
+    let out = tigersum(&["-c", "--ignore-missing", "--strict", "-"],
+        partial.as_bytes(), &dir);
+    assert!(!out.status.success());
+
+    let out = tigersum(&["-c", "-"], b"garbage\n", &dir);
+    assert!(!out.status.success());
+}
@@ -0,0 +1,246 @@
+//! Print or check Tiger Tree Hash (TTH) roots
+//!
+//! The output format is the one of the classic `tthsum` tool, i.e. lines
+//! of `<Base32 root>  <name>` as used by DC++ and Gnutella clients, and
+//! `--check` verifies such lines. Optionally magnet links are printed
+//! instead and the leaf hashes of every file can be dumped.
+extern crate tiger;
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::Path;
+use std::process;
+use tiger::{encode_base32, BASE32_LEN};
+use tiger::digest::generic_array::GenericArray;
+use tiger::digest::generic_array::typenum::U24;
+use tiger::magnet::Magnet;
+use tiger::tth::{TigerTree, TigerTreeLevel};
+
+const NAME: &str = "tthsum";
+
+const USAGE: &str = "\
+Usage: tthsum [OPTION]... [FILE]...
+Print or check Tiger Tree Hash (TTH) roots in Base32.
+
+With no FILE, or when FILE is -, read standard input.
+
+  -c, --check     read TTH roots from the FILEs and check them
+  -l, --leaves    print the Base32 leaf hashes (1 KiB blocks) after each root
+  -m, --magnet    print magnet links instead of root and name
+  -w, --warn      warn about improperly formatted checksum lines
+      --help      display this help and exit
+      --version   output version information and exit
+";
+
+#[derive(Default)]
+struct Options {
+    check: bool,
+    leaves: bool,
+    magnet: bool,
+    warn: bool,
+    files: Vec<String>,
+}
+
+fn usage_error(msg: &str) -> ! {
+    eprintln!("{}: {}", NAME, msg);
+    eprintln!("Try '{} --help' for more information.", NAME);
+    process::exit(1);
+}
+
+fn parse_args() -> Options {
+    let mut opts = Options::default();
+    let mut only_files = false;
+    for arg in env::args().skip(1) {
+        if only_files || arg == "-" || !arg.starts_with('-') {
+            opts.files.push(arg);
+            continue;
+        }
+        match arg.as_str() {
+            "--" => only_files = true,
+            "--check" => opts.check = true,
+            "--leaves" => opts.leaves = true,
+            "--magnet" => opts.magnet = true,
+            "--warn" => opts.warn = true,
+            "--help" => {
+                print!("{}", USAGE);
+                process::exit(0);
+            }
+            "--version" => {
+                println!("{} {}", NAME, env!("CARGO_PKG_VERSION"));
+                process::exit(0);
+            }
+            _ if arg.starts_with("--") => {
+                usage_error(&format!("unrecognized option '{}'", arg));
+            }
+            _ => for c in arg[1..].chars() {
+                match c {
+                    'c' => opts.check = true,
+                    'l' => opts.leaves = true,
+                    'm' => opts.magnet = true,
+                    'w' => opts.warn = true,
+                    _ => usage_error(&format!("invalid option -- '{}'", c)),
+                }
+            },
+        }
+    }
+    if opts.files.is_empty() {
+        opts.files.push("-".to_string());
+    }
+    if opts.check && (opts.leaves || opts.magnet) {
+        usage_error("--leaves and --magnet can not be used when checking");
+    }
+    opts
+}
+
+fn open(name: &str) -> io::Result<Box<dyn Read>> {
+    if name == "-" {
+        Ok(Box::new(io::stdin()))
+    } else {
+        Ok(Box::new(File::open(name)?))
+    }
+}
+
+fn base32(digest: &GenericArray<u8, U24>) -> String {
+    let mut buf = [0u8; BASE32_LEN];
+    encode_base32(digest, &mut buf).to_string()
+}
+
+/// Tree hash of a file: root, leaf hashes if requested and size in bytes
+struct Tree {
+    root: GenericArray<u8, U24>,
+    leaves: Vec<GenericArray<u8, U24>>,
+    size: u64,
+}
+
+fn hash_name(name: &str, leaves: bool) -> io::Result<Tree> {
+    let mut reader = open(name)?;
+    if leaves {
+        let mut tree = TigerTreeLevel::new(0);
+        let size = io::copy(&mut reader, &mut tree)?;
+        let nodes = tree.finalize();
+        Ok(Tree {
+            root: *nodes.root(),
+            leaves: nodes.leaves().copied().collect(),
+            size,
+        })
+    } else {
+        let mut tree = TigerTree::default();
+        let size = io::copy(&mut reader, &mut tree)?;
+        Ok(Tree { root: tiger::Digest::result(tree), leaves: Vec::new(), size })
+    }
+}
+
+fn print_roots(opts: &Options) -> bool {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut ok = true;
+    for name in &opts.files {
+        let tree = match hash_name(name, opts.leaves) {
+            Ok(tree) => tree,
+            Err(e) => {
+                eprintln!("{}: {}: {}", NAME, name, e);
+                ok = false;
+                continue;
+            }
+        };
+        let line = if opts.magnet {
+            let mut magnet = Magnet::new(tree.root).size(tree.size);
+            if name != "-" {
+                let file_name = Path::new(name).file_name()
+                    .map(|n| n.to_string_lossy().into_owned());
+                if let Some(file_name) = file_name {
+                    magnet = magnet.name(file_name);
+                }
+            }
+            magnet.to_string()
+        } else {
+            format!("{}  {}", base32(&tree.root), name)
+        };
+        writeln!(out, "{}", line).expect("failed to write output");
+        for leaf in &tree.leaves {
+            writeln!(out, "  {}", base32(leaf)).expect("failed to write output");
+        }
+    }
+    ok
+}
+
+/// Parse a `<root>  <name>` line into the expected root and the file name
+fn parse_line(line: &str) -> Option<(GenericArray<u8, U24>, &str)> {
+    if !line.is_char_boundary(BASE32_LEN) {
+        return None;
+    }
+    let (root, rest) = line.split_at(BASE32_LEN);
+    let name = rest.strip_prefix("  ")?;
+    if name.is_empty() {
+        return None;
+    }
+    Some((tiger::decode_base32(root).ok()?, name))
+}
+
+fn check_roots(opts: &Options) -> bool {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut ok = true;
+    for list in &opts.files {
+        let reader = match open(list) {
+            Ok(r) => BufReader::new(r),
+            Err(e) => {
+                eprintln!("{}: {}: {}", NAME, list, e);
+                ok = false;
+                continue;
+            }
+        };
+        let (mut checked, mut failed) = (0, 0);
+        for (i, line) in reader.lines().enumerate() {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => {
+                    eprintln!("{}: {}: {}", NAME, list, e);
+                    ok = false;
+                    break;
+                }
+            };
+            let line = line.trim_end_matches('\r');
+            let (expected, name) = match parse_line(line) {
+                Some(v) => v,
+                None => {
+                    if opts.warn {
+                        eprintln!("{}: {}: {}: improperly formatted TTH line",
+                            NAME, list, i + 1);
+                    }
+                    continue;
+                }
+            };
+            checked += 1;
+            let status = match hash_name(name, false) {
+                Ok(ref tree) if tree.root == expected => "OK",
+                Ok(_) => "FAILED",
+                Err(e) => {
+                    eprintln!("{}: {}: {}", NAME, name, e);
+                    "FAILED open or read"
+                }
+            };
+            if status != "OK" {
+                failed += 1;
+            }
+            writeln!(out, "{}: {}", name, status).expect("failed to write output");
+        }
+        if checked == 0 {
+            eprintln!("{}: {}: no properly formatted TTH lines found", NAME, list);
+            ok = false;
+        } else if failed > 0 {
+            eprintln!("{}: WARNING: {} of {} roots did NOT match", NAME, failed, checked);
+            ok = false;
+        }
+    }
+    ok
+}
+
+fn main() {
+    let opts = parse_args();
+    let ok = if opts.check { check_roots(&opts) } else { print_roots(&opts) };
+    if !ok {
+        process::exit(1);
+    }
+}
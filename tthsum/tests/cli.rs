@@ -0,0 +1,79 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+const EMPTY: &str = "LWPNACQDBZRYXW3VHJVCJ64QBZNGHOHHHZWCLNQ";
+const ABC: &str = "ASD4UJSEH5M47PDYB46KBTSQTSGDKLBHYXOMUIA";
+// 2500 bytes `i % 251`, i.e. three leaves
+const DATA: &str = "C6RPS3OXVR3WW7KSBOUKZB7HXSAFUQNL4QS4LOQ";
+const DATA_LEAVES: [&str; 3] = [
+    "QPOOKITS2MUF3KGK7BS7SEUIUJ5PZ3CESIFDKDY",
+    "XOFP6CUEGGSJQH4YAP2MICUQPPKQVK46XOX3YHI",
+    "LKEXBKHNBQLIMPTDTWXYVCCBFBMPHL55IGEFDOY",
+];
+
+fn tthsum(args: &[&str], stdin: &[u8], dir: &PathBuf) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tthsum"))
+        .args(args)
+        .current_dir(dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("tthsum-test-{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let data: Vec<u8> = (0..2500u32).map(|i| (i % 251) as u8).collect();
+    fs::write(dir.join("data.bin"), data).unwrap();
+    fs::write(dir.join("abc"), b"abc").unwrap();
+    dir
+}
+
+#[test]
+fn print() {
+    let dir = temp_dir("print");
+    let out = tthsum(&["data.bin", "abc", "-"], b"", &dir);
+    assert!(out.status.success());
+    assert_eq!(stdout(&out),
+        format!("{}  data.bin\n{}  abc\n{}  -\n", DATA, ABC, EMPTY));
+
+    let out = tthsum(&["-l", "data.bin"], b"", &dir);
+    assert_eq!(stdout(&out), format!("{}  data.bin\n  {}\n  {}\n  {}\n",
+        DATA, DATA_LEAVES[0], DATA_LEAVES[1], DATA_LEAVES[2]));
+
+    let out = tthsum(&["--magnet", "data.bin", "-"], b"abc", &dir);
+    assert_eq!(stdout(&out), format!(
+        "magnet:?xt=urn:tree:tiger:{}&xl=2500&dn=data.bin\n\
+         magnet:?xt=urn:tree:tiger:{}&xl=3\n", DATA, ABC));
+
+    let out = tthsum(&["missing"], b"", &dir);
+    assert!(!out.status.success());
+}
+
+#[test]
+fn check() {
+    let dir = temp_dir("check");
+    let list = format!("{}  data.bin\n{}  abc\n", DATA, ABC.to_lowercase());
+    let out = tthsum(&["-c", "-"], list.as_bytes(), &dir);
+    assert!(out.status.success());
+    assert_eq!(stdout(&out), "data.bin: OK\nabc: OK\n");
+
+    let list = format!("{}  data.bin\n{}  missing\ngarbage\n", ABC, ABC);
+    let out = tthsum(&["-cw", "-"], list.as_bytes(), &dir);
+    assert!(!out.status.success());
+    assert_eq!(stdout(&out), "data.bin: FAILED\nmissing: FAILED open or read\n");
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert!(stderr.contains("3: improperly formatted TTH line"));
+    assert!(stderr.contains("WARNING: 2 of 2 roots did NOT match"));
+}